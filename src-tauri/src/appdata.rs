@@ -0,0 +1,230 @@
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::db;
+use crate::error::{Error, ErrorKind};
+use crate::logging::LogState;
+use crate::paths;
+use crate::projects::ProjectRegistry;
+use crate::settings::SettingsState;
+use crate::task;
+
+const BUNDLE_FORMAT: &str = "devllm-app-data";
+// Version 2 added the frontend's project list
+const BUNDLE_VERSION: u64 = 2;
+// What belongs to the user rather than this machine: settings and the project registry.
+// Logs, crashes and caches stay behind.
+const BUNDLED: [&str; 2] = ["settings.json", "projects.json"];
+const BACKUP_DIR: &str = "backups";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Bundle {
+    format: String,
+    version: u64,
+    app_version: String,
+    exported_at: String,
+    // Relative path with '/' separators -> file content
+    files: BTreeMap<String, String>,
+    // The project list the frontend keeps in local storage, passed through as it is
+    #[serde(default)]
+    projects: Option<Value>,
+}
+
+#[derive(serde::Serialize)]
+pub struct AppDataReport {
+    path: String,
+    files: Vec<String>,
+    skipped: Vec<String>,
+    // Where the data that an import replaced was saved
+    backup: Option<String>,
+    // On import, the project list for the frontend to store
+    projects: Option<Value>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path().app_data_dir().map_err(|e| e.to_string())
+}
+
+fn collect(base: &Path, path: &Path, files: &mut Vec<PathBuf>) {
+    if path.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                collect(base, &entry.path(), files);
+            }
+        }
+    } else if path.is_file() {
+        if let Ok(relative) = path.strip_prefix(base) {
+            files.push(relative.to_path_buf());
+        }
+    }
+}
+
+fn bundle_name(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Text files only; anything else is reported as skipped
+fn build_bundle(dir: &Path, projects: Option<Value>) -> (Bundle, Vec<String>) {
+    let mut paths = Vec::new();
+    for name in BUNDLED {
+        collect(dir, &dir.join(name), &mut paths);
+    }
+
+    let mut files = BTreeMap::new();
+    let mut skipped = Vec::new();
+    for relative in paths {
+        let name = bundle_name(&relative);
+        match fs::read_to_string(dir.join(&relative)) {
+            Ok(content) => {
+                files.insert(name, content);
+            }
+            Err(_) => skipped.push(name),
+        }
+    }
+
+    let bundle = Bundle {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: db::format_timestamp(now()),
+        files,
+        projects,
+    };
+    (bundle, skipped)
+}
+
+fn write_bundle(bundle: &Bundle, dest: &Path) -> Result<(), String> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let content = serde_json::to_string_pretty(bundle).map_err(|e| e.to_string())?;
+    fs::write(dest, content).map_err(|e| e.to_string())
+}
+
+// Only plain relative paths inside one of the bundled locations may be written
+fn target_path(dir: &Path, name: &str) -> Option<PathBuf> {
+    let relative = Path::new(name);
+    let plain = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)));
+    let first = relative.components().next()?.as_os_str().to_str()?;
+    (plain && BUNDLED.contains(&first)).then(|| dir.join(relative))
+}
+
+#[tauri::command]
+pub async fn export_app_data(
+    app: AppHandle,
+    path: String,
+    projects: Value,
+) -> Result<AppDataReport, Error> {
+    task::blocking(move || {
+        let path = paths::new_path(&path)?;
+        let dir = data_dir(&app)?;
+        let (bundle, skipped) = build_bundle(&dir, Some(projects));
+        write_bundle(&bundle, Path::new(&path))?;
+        tracing::info!(%path, files = bundle.files.len(), "exported app data");
+        Ok(AppDataReport {
+            path,
+            files: bundle.files.into_keys().collect(),
+            skipped,
+            backup: None,
+            projects: None,
+        })
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn import_app_data(
+    app: AppHandle,
+    path: String,
+    projects: Value,
+) -> Result<AppDataReport, Error> {
+    task::blocking(move || {
+        let path = paths::existing_file(&path)?;
+        let settings = app.state::<SettingsState>();
+        let logs = app.state::<LogState>();
+        let content = fs::read_to_string(&path)?;
+        let bundle: Bundle = serde_json::from_str(&content).map_err(|e| {
+            Error::invalid(
+                "invalid_bundle",
+                format!("Not a devLLM app data export: {}", e),
+            )
+        })?;
+        if bundle.format != BUNDLE_FORMAT {
+            return Err(Error::invalid(
+                "invalid_bundle",
+                "Not a devLLM app data export",
+            ));
+        }
+        if bundle.version > BUNDLE_VERSION {
+            return Err(Error::new(
+                ErrorKind::Conflict,
+                "bundle_too_new",
+                format!(
+                    "This export was made by a newer devLLM ({}); update before importing it",
+                    bundle.app_version
+                ),
+            )
+            .with("app_version", &bundle.app_version));
+        }
+
+        let dir = data_dir(&app)?;
+        let mut files = Vec::new();
+        let mut skipped = Vec::new();
+        let mut targets = Vec::new();
+        for (name, content) in &bundle.files {
+            match target_path(&dir, name) {
+                Some(target) => targets.push((name, target, content)),
+                None => skipped.push(name.clone()),
+            }
+        }
+
+        // Keep what is about to be replaced, in the same format, so an import can be undone
+        let (current, _) = build_bundle(&dir, Some(projects));
+        let backup = if current.files.is_empty() {
+            None
+        } else {
+            let backup = dir
+                .join(BACKUP_DIR)
+                .join(format!("app-data-{}.json", now()));
+            write_bundle(&current, &backup)?;
+            Some(backup.to_string_lossy().into_owned())
+        };
+
+        for (name, target, content) in targets {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, content)?;
+            files.push(name.clone());
+        }
+
+        settings.reload(&app, &logs)?;
+        app.state::<ProjectRegistry>().reload()?;
+        tracing::info!(%path, files = files.len(), "imported app data");
+        Ok(AppDataReport {
+            path,
+            files,
+            skipped,
+            backup,
+            projects: bundle.projects,
+        })
+    })
+    .await
+}
@@ -0,0 +1,136 @@
+use parking_lot::Mutex;
+use std::cell::RefCell;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::db;
+use crate::error::Error;
+use crate::task;
+
+const AUDIT_FILE: &str = "audit.jsonl";
+const DEFAULT_AUDIT_ENTRIES: usize = 200;
+
+static AUDIT_PATH: OnceLock<PathBuf> = OnceLock::new();
+// Serializes appends so concurrent processes never interleave a line
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    // The IPC command currently running on this thread, set by the invoke handler
+    static INITIATOR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AuditEntry {
+    timestamp: String,
+    program: String,
+    args: Vec<String>,
+    cwd: Option<String>,
+    initiator: Option<String>,
+    // "run" waits for the process, "spawn" starts a long-running service
+    kind: String,
+    exit_code: Option<i32>,
+    error: Option<String>,
+    duration_ms: Option<u128>,
+}
+
+pub fn init(app: &AppHandle) {
+    if let Ok(dir) = app.path().app_data_dir() {
+        let _ = AUDIT_PATH.set(dir.join(AUDIT_FILE));
+    }
+}
+
+pub fn set_initiator(command: Option<String>) {
+    INITIATOR.with(|current| *current.borrow_mut() = command);
+}
+
+pub fn record(
+    cmd: &Command,
+    kind: &str,
+    exit_code: Option<i32>,
+    error: Option<String>,
+    duration_ms: Option<u128>,
+) {
+    let Some(path) = AUDIT_PATH.get() else {
+        return;
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let entry = AuditEntry {
+        timestamp: db::format_timestamp(now),
+        program: cmd.get_program().to_string_lossy().into_owned(),
+        args: cmd
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect(),
+        cwd: cmd
+            .get_current_dir()
+            .map(|d| d.to_string_lossy().into_owned()),
+        initiator: INITIATOR.with(|current| current.borrow().clone()),
+        kind: kind.to_string(),
+        exit_code,
+        error,
+        duration_ms,
+    };
+    let Ok(line) = serde_json::to_string(&entry) else {
+        return;
+    };
+
+    let _lock = WRITE_LOCK.lock();
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let written = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line));
+    if let Err(e) = written {
+        tracing::warn!("Failed to write audit log: {}", e);
+    }
+}
+
+#[tauri::command]
+pub async fn get_audit_log(
+    limit: Option<usize>,
+    program: Option<String>,
+    initiator: Option<String>,
+    since: Option<String>,
+) -> Result<Vec<AuditEntry>, Error> {
+    task::blocking(move || {
+        let Some(path) = AUDIT_PATH.get() else {
+            return Ok(Vec::new());
+        };
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let limit = limit.unwrap_or(DEFAULT_AUDIT_ENTRIES);
+        let program = program.map(|p| p.to_lowercase());
+        // Newest first; timestamps compare correctly as "YYYY-MM-DD HH:MM:SS" strings
+        let entries = content
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+            .filter(|e| {
+                program.as_ref().is_none_or(|p| {
+                    e.program.to_lowercase().contains(p)
+                        || e.args.iter().any(|a| a.to_lowercase().contains(p))
+                })
+            })
+            .filter(|e| initiator.is_none() || e.initiator == initiator)
+            .filter(|e| since.as_ref().is_none_or(|s| e.timestamp >= *s))
+            .take(limit)
+            .collect();
+        Ok(entries)
+    })
+    .await
+}
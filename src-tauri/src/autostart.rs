@@ -0,0 +1,172 @@
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+use crate::error::{Error, ErrorKind};
+use crate::manifest::{self, ProjectManifest};
+use crate::paths;
+use crate::projects;
+use crate::task;
+use crate::{ProcessManager, ServiceLaunch};
+
+// Backend first, so the frontend's API calls have something to talk to
+const START_ORDER: [&str; 2] = ["backend", "frontend"];
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(serde::Serialize)]
+pub struct AutoStartResult {
+    service: String,
+    started: bool,
+    // Accepting connections on its port
+    ready: bool,
+    message: String,
+}
+
+fn wait_for_port(port: u16) -> bool {
+    let address = SocketAddr::from(([127, 0, 0, 1], port));
+    let started = Instant::now();
+    while started.elapsed() < READY_TIMEOUT {
+        if TcpStream::connect_timeout(&address, Duration::from_millis(500)).is_ok() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(500));
+    }
+    false
+}
+
+#[tauri::command]
+pub async fn set_autostart(
+    project_path: String,
+    services: Vec<String>,
+) -> Result<ProjectManifest, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let path = Path::new(&project_path);
+        if let Some(unknown) = services.iter().find(|s| !START_ORDER.contains(&s.as_str())) {
+            return Err(
+                Error::invalid("unknown_service", format!("Unknown service: {}", unknown))
+                    .with("service", unknown),
+            );
+        }
+        let manifest = manifest::update(&manifest::project_root(path), |m| {
+            m.autostart = START_ORDER
+                .iter()
+                .filter(|s| services.iter().any(|wanted| wanted == *s))
+                .map(|s| s.to_string())
+                .collect();
+        })?;
+        Ok(manifest)
+    })
+    .await
+}
+
+// Starts `wanted` in START_ORDER, holding each service until it accepts connections so
+// the next one has something to talk to. Services already running are left alone.
+// With `wait_all` the last service is waited for too.
+pub fn start_services(
+    app: &AppHandle,
+    root: &Path,
+    wanted: &[String],
+    wait_all: bool,
+) -> Result<Vec<AutoStartResult>, Error> {
+    let state = app.state::<ProcessManager>();
+    let project = crate::detect_project_blocking(root.to_string_lossy().into_owned())?;
+
+    let services: Vec<&str> = START_ORDER
+        .into_iter()
+        .filter(|s| wanted.iter().any(|w| w == s))
+        .collect();
+    let mut results = Vec::new();
+    for (i, service) in services.iter().enumerate() {
+        let (command, port) = match *service {
+            "backend" => (project.backend_command.clone(), project.backend_port),
+            _ => (project.frontend_command.clone(), project.frontend_port),
+        };
+        let Some(command) = command else {
+            results.push(AutoStartResult {
+                service: service.to_string(),
+                started: false,
+                ready: false,
+                message: format!("No {} found in this project", service),
+            });
+            continue;
+        };
+
+        let launch = ServiceLaunch {
+            service_type: service.to_string(),
+            project_path: root.join(service).to_string_lossy().into_owned(),
+            command,
+            env_vars: None,
+        };
+        let key = format!("{}:{}", paths::key(&launch.project_path), service);
+        let running = state.processes.lock().contains_key(&key);
+        let launched = if running {
+            Ok(format!("{} is already running", service))
+        } else {
+            crate::launch_service(app, &launch, true)
+        };
+        match launched {
+            Ok(message) => {
+                let last = i + 1 == services.len();
+                let ready = (last && !wait_all) || port.is_none_or(wait_for_port);
+                results.push(AutoStartResult {
+                    service: service.to_string(),
+                    started: true,
+                    ready,
+                    message: if ready {
+                        message
+                    } else {
+                        format!("{}, but it is not answering yet", message)
+                    },
+                });
+            }
+            Err(e) => results.push(AutoStartResult {
+                service: service.to_string(),
+                started: false,
+                ready: false,
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    crate::tray::refresh(app);
+    Ok(results)
+}
+
+// Brings up every service of the project, failing unless all of them answer
+pub fn ensure_running(app: &AppHandle, root: &Path) -> Result<(), Error> {
+    let wanted: Vec<String> = START_ORDER.iter().map(|s| s.to_string()).collect();
+    let results = start_services(app, root, &wanted, true)?;
+    match results.into_iter().find(|r| !r.ready) {
+        Some(down) => Err(Error::new(
+            ErrorKind::Process,
+            "service_not_ready",
+            format!(
+                "The {} could not be started: {}",
+                down.service, down.message
+            ),
+        )
+        .with("service", &down.service)),
+        None => Ok(()),
+    }
+}
+
+// Called by the UI when a project is opened
+#[tauri::command]
+pub async fn autostart_project(
+    app: AppHandle,
+    project_path: String,
+) -> Result<Vec<AutoStartResult>, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let root = manifest::project_root(Path::new(&project_path));
+        let wanted = manifest::load(&root)?.autostart;
+        if wanted.is_empty() {
+            return Ok(Vec::new());
+        }
+        start_services(&app, &root, &wanted, false)
+    })
+    .await
+}
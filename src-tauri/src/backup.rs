@@ -0,0 +1,256 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use rusqlite::Connection;
+
+use crate::db;
+use crate::error::{Error, ErrorKind};
+use crate::exec;
+use crate::paths;
+use crate::projects;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct BackupInfo {
+    id: String,
+    path: String,
+    kind: &'static str,
+    size: u64,
+    created_at: String,
+}
+
+// Stable per-project folder: readable name plus an FNV-1a hash of the full path
+fn project_key(project_path: &Path) -> String {
+    let full = project_path.to_string_lossy();
+    let hash = full.bytes().fold(0xcbf29ce484222325u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    let name: String = project_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("project")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{}-{:08x}", name.trim_matches('-'), hash as u32)
+}
+
+pub fn backup_dir(app: &AppHandle, project_path: &Path) -> Result<PathBuf, Error> {
+    let dir = app
+        .path()
+        .app_data_dir()?
+        .join("backups")
+        .join(project_key(project_path));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn backup_info(path: &Path) -> Option<BackupInfo> {
+    let meta = fs::metadata(path).ok()?;
+    let id = path.file_name()?.to_string_lossy().into_owned();
+    let kind = match path.extension()?.to_str()? {
+        "sqlite" => "sqlite",
+        "dump" => "postgres",
+        _ => return None,
+    };
+    let created = meta
+        .modified()
+        .ok()
+        .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Some(BackupInfo {
+        id,
+        path: path.to_string_lossy().into_owned(),
+        kind,
+        size: meta.len(),
+        created_at: db::format_timestamp(created),
+    })
+}
+
+fn run_pg_tool(mut cmd: Command, tool: &str) -> Result<(), Error> {
+    let output = exec::output(&mut cmd).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::missing_runtime(
+            tool,
+            format!(
+                "{} was not found; install the PostgreSQL client tools and make sure they are on PATH",
+                tool
+            ),
+        ),
+        _ => exec::run_failed(tool, e),
+    })?;
+    if !output.status.success() {
+        return Err(Error::tool_failed(
+            tool,
+            format!(
+                "{} failed: {}",
+                tool,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+pub fn create_backup(
+    app: &AppHandle,
+    project_path: &Path,
+    label: &str,
+) -> Result<BackupInfo, Error> {
+    let (backend, url) = db::database_url(project_path);
+    let dir = backup_dir(app, project_path)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let stamp = db::format_timestamp(now).replace([' ', ':'], "-");
+    let label: String = label
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+
+    let path = if let Some(db_path) = db::sqlite_path(&backend, &url) {
+        let target = dir.join(format!("{}-{}.sqlite", stamp, label));
+        // VACUUM INTO gives a consistent copy even while the backend holds the file open
+        let conn = db::open_readonly(&db_path)?;
+        conn.execute("VACUUM INTO ?1", [target.to_string_lossy()])
+            .map_err(|e| {
+                Error::new(
+                    ErrorKind::Database,
+                    "backup_failed",
+                    format!("Backup failed: {}", e),
+                )
+            })?;
+        target
+    } else if let Some(pg_url) = db::postgres_url(&url) {
+        let target = dir.join(format!("{}-{}.dump", stamp, label));
+        let mut cmd = Command::new("pg_dump");
+        cmd.args(["--format=custom", "--no-owner", "--file"])
+            .arg(&target)
+            .arg(&pg_url);
+        run_pg_tool(cmd, "pg_dump")?;
+        target
+    } else {
+        return Err(Error::invalid(
+            "unsupported_database",
+            format!("Unsupported DATABASE_URL for backups: {}", url),
+        )
+        .with("url", &url));
+    };
+
+    backup_info(&path).ok_or_else(|| Error::from("Backup file was not created"))
+}
+
+#[tauri::command]
+pub async fn backup_database(
+    app: AppHandle,
+    project_path: String,
+    label: Option<String>,
+) -> Result<BackupInfo, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let backup = create_backup(
+            &app,
+            Path::new(&project_path),
+            label.as_deref().unwrap_or("manual"),
+        )?;
+        Ok(backup)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn list_backups(app: AppHandle, project_path: String) -> Result<Vec<BackupInfo>, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let dir = backup_dir(&app, Path::new(&project_path))?;
+        let mut backups: Vec<BackupInfo> = fs::read_dir(dir)?
+            .flatten()
+            .filter_map(|entry| backup_info(&entry.path()))
+            .collect();
+        // Timestamped names sort chronologically; newest first
+        backups.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(backups)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn restore_database(
+    app: AppHandle,
+    project_path: String,
+    backup_id: String,
+) -> Result<BackupInfo, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let path = Path::new(&project_path);
+        if backup_id.contains(['/', '\\']) || backup_id.contains("..") {
+            return Err(Error::invalid("invalid_backup_id", "Invalid backup id")
+                .with("backup_id", &backup_id));
+        }
+        let source = backup_dir(&app, path)?.join(&backup_id);
+        let backup = backup_info(&source).ok_or_else(|| {
+            Error::not_found(
+                "backup_not_found",
+                format!("Backup not found: {}", backup_id),
+            )
+            .with("backup_id", &backup_id)
+        })?;
+
+        let (backend, url) = db::database_url(path);
+        match (
+            backup.kind,
+            db::sqlite_path(&backend, &url),
+            db::postgres_url(&url),
+        ) {
+            ("sqlite", Some(db_path), _) => {
+                // Keep the current state restorable too
+                if db_path.exists() {
+                    create_backup(&app, path, "pre-restore")?;
+                }
+                // Open the backup and copy it page by page over the live database
+                let source = Connection::open(&source)?;
+                let mut target = Connection::open(&db_path)?;
+                let restore_failed = |e: rusqlite::Error| {
+                    Error::new(
+                        ErrorKind::Database,
+                        "restore_failed",
+                        format!("Restore failed (stop the backend if it is running): {}", e),
+                    )
+                };
+                let copy =
+                    rusqlite::backup::Backup::new(&source, &mut target).map_err(restore_failed)?;
+                copy.run_to_completion(256, std::time::Duration::from_millis(10), None)
+                    .map_err(restore_failed)?;
+            }
+            ("postgres", _, Some(pg_url)) => {
+                create_backup(&app, path, "pre-restore")?;
+                let mut cmd = Command::new("pg_restore");
+                cmd.args(["--clean", "--if-exists", "--no-owner", "--dbname"])
+                    .arg(&pg_url)
+                    .arg(&source);
+                run_pg_tool(cmd, "pg_restore")?;
+            }
+            _ => {
+                return Err(Error::invalid(
+                    "backup_kind_mismatch",
+                    format!("{} backup can't be restored into {}", backup.kind, url),
+                )
+                .with("backup_kind", backup.kind))
+            }
+        }
+
+        Ok(backup)
+    })
+    .await
+}
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use crate::cancel;
+use crate::error::Error;
+
+const DEFAULT_CONCURRENCY: u32 = 10;
+const MAX_CONCURRENCY: u32 = 256;
+const DEFAULT_DURATION_SECS: u64 = 10;
+const MAX_DURATION_SECS: u64 = 300;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(serde::Serialize)]
+pub struct Latency {
+    min: f64,
+    mean: f64,
+    p50: f64,
+    p90: f64,
+    p95: f64,
+    p99: f64,
+    max: f64,
+}
+
+#[derive(serde::Serialize)]
+pub struct BenchmarkReport {
+    url: String,
+    concurrency: u32,
+    duration_ms: u128,
+    requests: u64,
+    // Responses below 400
+    successes: u64,
+    errors: u64,
+    requests_per_second: f64,
+    // Milliseconds, over every completed request
+    latency: Option<Latency>,
+    status_codes: BTreeMap<u16, u64>,
+    // Requests that got no response: "timeout", "connect" or "other"
+    transport_errors: BTreeMap<&'static str, u64>,
+    cancelled: bool,
+}
+
+type Sample = (Duration, Result<u16, &'static str>);
+
+// Load is only ever pointed at the user's own machine
+fn check_url(url: &str) -> Result<reqwest::Url, Error> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| Error::invalid("invalid_url", format!("Invalid URL {}: {}", url, e)))?;
+    let host = parsed.host_str().unwrap_or_default();
+    let local = host == "localhost"
+        || host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback());
+    if !matches!(parsed.scheme(), "http" | "https") || !local {
+        return Err(Error::invalid(
+            "url_not_local",
+            "Benchmarks can only target http(s) endpoints on localhost",
+        )
+        .with("url", url));
+    }
+    Ok(parsed)
+}
+
+fn classify(e: &reqwest::Error) -> &'static str {
+    if e.is_timeout() {
+        "timeout"
+    } else if e.is_connect() {
+        "connect"
+    } else {
+        "other"
+    }
+}
+
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn latency(mut millis: Vec<f64>) -> Option<Latency> {
+    if millis.is_empty() {
+        return None;
+    }
+    millis.sort_by(f64::total_cmp);
+    let round = |ms: f64| (ms * 100.0).round() / 100.0;
+    Some(Latency {
+        min: round(millis[0]),
+        mean: round(millis.iter().sum::<f64>() / millis.len() as f64),
+        p50: round(percentile(&millis, 50.0)),
+        p90: round(percentile(&millis, 90.0)),
+        p95: round(percentile(&millis, 95.0)),
+        p99: round(percentile(&millis, 99.0)),
+        max: round(millis[millis.len() - 1]),
+    })
+}
+
+// Each worker keeps one request in flight until the deadline; bodies are read in full
+// so latency covers the whole response
+#[tauri::command]
+pub async fn benchmark_endpoint(
+    url: String,
+    concurrency: Option<u32>,
+    duration: Option<u64>,
+) -> Result<BenchmarkReport, Error> {
+    let target = check_url(&url)?;
+    let concurrency = concurrency
+        .unwrap_or(DEFAULT_CONCURRENCY)
+        .clamp(1, MAX_CONCURRENCY);
+    let duration = Duration::from_secs(
+        duration
+            .unwrap_or(DEFAULT_DURATION_SECS)
+            .clamp(1, MAX_DURATION_SECS),
+    );
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .pool_max_idle_per_host(concurrency as usize)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let operation = cancel::begin(&format!("benchmark:{}", url));
+    tracing::info!(%url, concurrency, secs = duration.as_secs(), "starting benchmark");
+    let started = Instant::now();
+    let deadline = started + duration;
+    let workers: Vec<_> = (0..concurrency)
+        .map(|_| {
+            let client = client.clone();
+            let target = target.clone();
+            let token = operation.token().clone();
+            tauri::async_runtime::spawn(async move {
+                let mut samples: Vec<Sample> = Vec::new();
+                while Instant::now() < deadline && !token.is_cancelled() {
+                    let sent = Instant::now();
+                    let result = match client.get(target.clone()).send().await {
+                        Ok(response) => {
+                            let status = response.status().as_u16();
+                            response.bytes().await.map(|_| status)
+                        }
+                        Err(e) => Err(e),
+                    };
+                    samples.push((sent.elapsed(), result.map_err(|e| classify(&e))));
+                }
+                samples
+            })
+        })
+        .collect();
+
+    let mut samples = Vec::new();
+    for worker in workers {
+        samples.extend(
+            worker
+                .await
+                .map_err(|e| format!("Benchmark worker failed: {}", e))?,
+        );
+    }
+    let elapsed = started.elapsed();
+
+    let mut status_codes = BTreeMap::new();
+    let mut transport_errors = BTreeMap::new();
+    let mut successes = 0;
+    let mut millis = Vec::with_capacity(samples.len());
+    for (took, result) in &samples {
+        match result {
+            Ok(status) => {
+                *status_codes.entry(*status).or_insert(0) += 1;
+                if *status < 400 {
+                    successes += 1;
+                }
+                millis.push(took.as_secs_f64() * 1000.0);
+            }
+            Err(kind) => *transport_errors.entry(*kind).or_insert(0) += 1,
+        }
+    }
+    let requests = samples.len() as u64;
+
+    tracing::info!(%url, requests, successes, "finished benchmark");
+    Ok(BenchmarkReport {
+        url,
+        concurrency,
+        duration_ms: elapsed.as_millis(),
+        requests,
+        successes,
+        errors: requests - successes,
+        requests_per_second: (requests as f64 / elapsed.as_secs_f64() * 100.0).round() / 100.0,
+        latency: latency(millis),
+        status_codes,
+        transport_errors,
+        cancelled: operation.token().is_cancelled(),
+    })
+}
@@ -0,0 +1,209 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager};
+
+use crate::error::Error;
+use crate::exec;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct CacheUsage {
+    name: &'static str,
+    path: Option<String>,
+    bytes: u64,
+    available: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct CacheCleanup {
+    name: String,
+    bytes_reclaimed: u64,
+    error: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct CleanupReport {
+    results: Vec<CacheCleanup>,
+    total_reclaimed: u64,
+}
+
+const TOOL_CACHES: [&str; 5] = ["npm", "pnpm", "yarn", "pip", "uv"];
+// devLLM's own data directories, pruned by age rather than wiped
+const APP_CACHES: [&str; 2] = ["logs", "snapshots"];
+const DEFAULT_MAX_AGE_DAYS: u64 = 14;
+
+fn tool_output(cmd: &mut Command) -> Option<String> {
+    let output = exec::output(cmd).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn cache_dir(name: &str) -> Option<PathBuf> {
+    let mut cmd = exec::tool_command(name);
+    match name {
+        "npm" => cmd.args(["config", "get", "cache"]),
+        "pnpm" => cmd.args(["store", "path"]),
+        "yarn" => cmd.args(["cache", "dir"]),
+        "pip" => cmd.args(["cache", "dir"]),
+        "uv" => cmd.args(["cache", "dir"]),
+        _ => return None,
+    };
+    // Tools may print warnings before the path; the path is the last line
+    tool_output(&mut cmd)?
+        .lines()
+        .last()
+        .map(|l| PathBuf::from(l.trim()))
+}
+
+fn clean_command(name: &str) -> Option<Command> {
+    let mut cmd = exec::tool_command(name);
+    match name {
+        "npm" => cmd.args(["cache", "clean", "--force"]),
+        // Only drops packages no project references any more
+        "pnpm" => cmd.args(["store", "prune"]),
+        "yarn" => cmd.args(["cache", "clean"]),
+        "pip" => cmd.args(["cache", "purge"]),
+        "uv" => cmd.args(["cache", "clean"]),
+        _ => return None,
+    };
+    Some(cmd)
+}
+
+fn app_cache_dir(app: &AppHandle, name: &str) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|dir| dir.join(name))
+}
+
+// Symlinks are not followed, so pnpm's linked node_modules aren't counted twice
+fn dir_size(path: &Path) -> u64 {
+    let Ok(meta) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| entries.flatten().map(|e| dir_size(&e.path())).sum())
+        .unwrap_or(0)
+}
+
+#[tauri::command]
+pub async fn get_cache_usage(app: AppHandle) -> Result<Vec<CacheUsage>, Error> {
+    task::blocking(move || {
+        let mut usage = Vec::new();
+
+        for name in TOOL_CACHES {
+            let path = cache_dir(name);
+            usage.push(CacheUsage {
+                name,
+                bytes: path.as_deref().map(dir_size).unwrap_or(0),
+                available: path.is_some(),
+                path: path.map(|p| p.to_string_lossy().into_owned()),
+            });
+        }
+
+        for name in APP_CACHES {
+            let path = app_cache_dir(&app, name);
+            usage.push(CacheUsage {
+                name,
+                bytes: path.as_deref().map(dir_size).unwrap_or(0),
+                available: path.as_deref().is_some_and(Path::exists),
+                path: path.map(|p| p.to_string_lossy().into_owned()),
+            });
+        }
+
+        Ok(usage)
+    })
+    .await
+}
+
+fn prune_older_than(dir: &Path, cutoff: SystemTime) -> Result<u64, String> {
+    let mut reclaimed = 0;
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())?.flatten() {
+        let path = entry.path();
+        let Ok(modified) = entry.metadata().and_then(|m| m.modified()) else {
+            continue;
+        };
+        if modified >= cutoff {
+            continue;
+        }
+        let size = dir_size(&path);
+        let removed = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        if removed.is_ok() {
+            reclaimed += size;
+        }
+    }
+    Ok(reclaimed)
+}
+
+fn clear_tool_cache(name: &str) -> Result<u64, String> {
+    let dir = cache_dir(name).ok_or_else(|| format!("{} is not installed", name))?;
+    let before = dir_size(&dir);
+
+    let mut cmd = clean_command(name).ok_or_else(|| format!("Unknown cache: {}", name))?;
+    let output = exec::output(&mut cmd).map_err(|e| format!("Failed to run {}: {}", name, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} cache clean failed: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(before.saturating_sub(dir_size(&dir)))
+}
+
+#[tauri::command]
+pub async fn clear_caches(
+    app: AppHandle,
+    caches: Vec<String>,
+    older_than_days: Option<u64>,
+) -> Result<CleanupReport, Error> {
+    task::blocking(move || {
+        let max_age = Duration::from_secs(older_than_days.unwrap_or(DEFAULT_MAX_AGE_DAYS) * 86_400);
+        let cutoff = SystemTime::now()
+            .checked_sub(max_age)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+
+        let mut results = Vec::new();
+        for name in caches {
+            let result = if TOOL_CACHES.contains(&name.as_str()) {
+                clear_tool_cache(&name)
+            } else if APP_CACHES.contains(&name.as_str()) {
+                match app_cache_dir(&app, &name) {
+                    Some(dir) if dir.exists() => prune_older_than(&dir, cutoff),
+                    _ => Ok(0),
+                }
+            } else {
+                Err(format!("Unknown cache: {}", name))
+            };
+
+            results.push(match result {
+                Ok(bytes_reclaimed) => CacheCleanup {
+                    name,
+                    bytes_reclaimed,
+                    error: None,
+                },
+                Err(e) => CacheCleanup {
+                    name,
+                    bytes_reclaimed: 0,
+                    error: Some(e),
+                },
+            });
+        }
+
+        Ok(CleanupReport {
+            total_reclaimed: results.iter().map(|r| r.bytes_reclaimed).sum(),
+            results,
+        })
+    })
+    .await
+}
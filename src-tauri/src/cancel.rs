@@ -0,0 +1,70 @@
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::{Error, ErrorKind};
+
+// Running cancellable operations by id. Streamed processes use the operation name
+// their output events carry; other commands take an id from the caller.
+static OPERATIONS: Mutex<BTreeMap<String, CancelToken>> = Mutex::new(BTreeMap::new());
+
+#[derive(Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// Keeps the operation cancellable for as long as it is alive
+pub struct Operation {
+    id: String,
+    token: CancelToken,
+}
+
+impl Operation {
+    pub fn token(&self) -> &CancelToken {
+        &self.token
+    }
+}
+
+impl Drop for Operation {
+    fn drop(&mut self) {
+        let mut operations = OPERATIONS.lock();
+        // A newer operation may have reused the id
+        if operations
+            .get(&self.id)
+            .is_some_and(|token| Arc::ptr_eq(&token.0, &self.token.0))
+        {
+            operations.remove(&self.id);
+        }
+    }
+}
+
+pub fn begin(id: &str) -> Operation {
+    let token = CancelToken::default();
+    OPERATIONS.lock().insert(id.to_string(), token.clone());
+    Operation {
+        id: id.to_string(),
+        token,
+    }
+}
+
+pub fn cancelled() -> Error {
+    Error::new(ErrorKind::Cancelled, "cancelled", "Operation was cancelled")
+}
+
+// False when nothing with that id is running (any more)
+#[tauri::command]
+pub fn cancel_operation(operation_id: String) -> bool {
+    match OPERATIONS.lock().get(&operation_id) {
+        Some(token) => {
+            token.0.store(true, Ordering::Relaxed);
+            tracing::info!(operation = %operation_id, "cancelling operation");
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeSet;
+use tauri::{AppHandle, Manager};
+
+use crate::compose;
+use crate::docker::{self, Engine};
+use crate::error::Error;
+use crate::exec;
+use crate::images;
+use crate::paths;
+use crate::resources;
+use crate::task;
+use crate::ProcessManager;
+
+const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
+
+#[derive(serde::Serialize)]
+pub struct CleanupItem {
+    // "image:<id>", "container:<id>" or "volume:<name>"; what the selection refers to
+    id: String,
+    kind: &'static str,
+    name: String,
+    // Volumes report no size without a slow `system df -v`
+    size_bytes: Option<u64>,
+}
+
+#[derive(serde::Serialize)]
+pub struct CleanupFailure {
+    id: String,
+    error: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct CleanupReport {
+    // What is left to clean up after this call
+    candidates: Vec<CleanupItem>,
+    removed: Vec<String>,
+    failed: Vec<CleanupFailure>,
+    reclaimed_bytes: u64,
+}
+
+// Lines of tab-separated fields from a `--format` listing
+fn list(engine: Engine, args: &[String]) -> Result<Vec<Vec<String>>, Error> {
+    let mut cmd = engine.command();
+    cmd.args(args);
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed(engine.program(), e))?;
+    if !output.status.success() {
+        return Err(Error::tool_failed(
+            engine.program(),
+            format!(
+                "{} {} failed: {}",
+                engine.program(),
+                args.first().map(String::as_str).unwrap_or_default(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.split('\t').map(String::from).collect())
+        .collect())
+}
+
+fn args(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+// Images built by build_image and containers run by devLLM carry the devllm.project
+// label; compose containers and volumes are found by their compose project name
+fn candidates(
+    engine: Engine,
+    project: Option<&str>,
+    compose_projects: &BTreeSet<String>,
+) -> Result<Vec<CleanupItem>, Error> {
+    let devllm_label = match project {
+        Some(project) => format!("label={}={}", images::PROJECT_LABEL, paths::key(project)),
+        None => format!("label={}", images::PROJECT_LABEL),
+    };
+    let compose_labels: Vec<String> = compose_projects
+        .iter()
+        .map(|name| format!("label={}={}", COMPOSE_PROJECT_LABEL, name))
+        .collect();
+    let mut items = Vec::new();
+
+    let mut listing = args(&["images", "--filter", "dangling=true", "--filter"]);
+    listing.push(devllm_label.clone());
+    listing.extend(args(&["--format", "{{.ID}}\t{{.Size}}"]));
+    for fields in list(engine, &listing)? {
+        let [id, size] = &fields[..] else {
+            continue;
+        };
+        items.push(CleanupItem {
+            id: format!("image:{}", id),
+            kind: "image",
+            name: id.clone(),
+            size_bytes: Some(resources::parse_size(size)),
+        });
+    }
+
+    // Label filters with different values are ANDed, so each label is its own listing
+    let mut seen = BTreeSet::new();
+    for label in [&devllm_label].into_iter().chain(&compose_labels) {
+        let mut listing = args(&["ps", "--all", "--size", "--filter", "status=exited"]);
+        listing.extend(["--filter".to_string(), label.clone()]);
+        listing.extend(args(&["--format", "{{.ID}}\t{{.Names}}\t{{.Size}}"]));
+        for fields in list(engine, &listing)? {
+            let [id, name, size] = &fields[..] else {
+                continue;
+            };
+            if !seen.insert(id.clone()) {
+                continue;
+            }
+            // "2.1kB (virtual 180MB)": only the writable layer is freed
+            let writable = size.split(" (").next().unwrap_or_default();
+            items.push(CleanupItem {
+                id: format!("container:{}", id),
+                kind: "container",
+                name: name.clone(),
+                size_bytes: Some(resources::parse_size(writable)),
+            });
+        }
+    }
+
+    for label in &compose_labels {
+        let mut listing = args(&["volume", "ls", "--filter", "dangling=true", "--filter"]);
+        listing.push(label.clone());
+        listing.extend(args(&["--format", "{{.Name}}"]));
+        for fields in list(engine, &listing)? {
+            let Some(name) = fields.first() else {
+                continue;
+            };
+            items.push(CleanupItem {
+                id: format!("volume:{}", name),
+                kind: "volume",
+                name: name.clone(),
+                size_bytes: None,
+            });
+        }
+    }
+    Ok(items)
+}
+
+fn remove_item(engine: Engine, item: &CleanupItem) -> Result<(), String> {
+    let mut cmd = engine.command();
+    match item.kind {
+        "image" => cmd.args(["image", "rm", &item.name]),
+        "container" => cmd.args(["rm", &item.name]),
+        _ => cmd.args(["volume", "rm", &item.name]),
+    };
+    let output = exec::output(&mut cmd).map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+// Without `remove` this only lists what could go; with it, the listed ids among them are
+// removed. Anything not in the listing is ignored, so a stale selection can't reach
+// beyond devLLM's own resources.
+#[tauri::command]
+pub async fn docker_cleanup(
+    app: AppHandle,
+    project_path: Option<String>,
+    remove: Option<Vec<String>>,
+) -> Result<CleanupReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::optional_dir(project_path)?;
+        let engine = docker::require()?;
+        let compose_projects: BTreeSet<String> = match &project_path {
+            Some(project) => BTreeSet::from([compose::project_name(project)]),
+            None => app
+                .state::<ProcessManager>()
+                .stacks
+                .lock()
+                .values()
+                .map(|stack| compose::project_name(&stack.project_path))
+                .collect(),
+        };
+        let found = candidates(engine, project_path.as_deref(), &compose_projects)?;
+
+        let selected: BTreeSet<String> = remove.into_iter().flatten().collect();
+        let mut report = CleanupReport {
+            candidates: Vec::new(),
+            removed: Vec::new(),
+            failed: Vec::new(),
+            reclaimed_bytes: 0,
+        };
+        // Containers go first, since they can be what keeps an image or volume in use
+        let (mut chosen, rest): (Vec<CleanupItem>, Vec<CleanupItem>) = found
+            .into_iter()
+            .partition(|item| selected.contains(&item.id));
+        chosen.sort_by_key(|item| item.kind != "container");
+        report.candidates = rest;
+        for item in chosen {
+            match remove_item(engine, &item) {
+                Ok(()) => {
+                    report.reclaimed_bytes += item.size_bytes.unwrap_or(0);
+                    report.removed.push(item.id);
+                }
+                Err(error) => {
+                    report.failed.push(CleanupFailure {
+                        id: item.id.clone(),
+                        error,
+                    });
+                    report.candidates.push(item);
+                }
+            }
+        }
+
+        tracing::info!(
+            removed = report.removed.len(),
+            reclaimed = report.reclaimed_bytes,
+            "docker cleanup"
+        );
+        Ok(report)
+    })
+    .await
+}
@@ -0,0 +1,385 @@
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::openapi;
+use crate::paths;
+use crate::scaffold;
+use crate::task;
+
+const DEFAULT_BACKEND_URL: &str = "http://127.0.0.1:8000";
+const COLLECTION_DIR: &str = "collections";
+const POSTMAN_SCHEMA: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+#[derive(serde::Serialize)]
+pub struct CollectionExport {
+    format: String,
+    // A file for Postman and Hoppscotch, a directory for Bruno
+    path: String,
+    requests: usize,
+    base_url: String,
+    spec_source: &'static str,
+}
+
+struct Request {
+    folder: String,
+    name: String,
+    method: String,
+    // As in the spec, e.g. "/items/{item_id}"
+    path: String,
+    path_params: Vec<(String, String)>,
+    // (name, example value, required)
+    query: Vec<(String, String, bool)>,
+    body: Option<String>,
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn requests(spec: &Value) -> Vec<Request> {
+    openapi::operations(spec)
+        .into_iter()
+        .map(|operation| {
+            let op = operation.op;
+            let mut path_params = Vec::new();
+            let mut query = Vec::new();
+            for param in openapi::parameters(spec, &operation) {
+                let name = param["name"].as_str().unwrap_or_default().to_string();
+                let value = text(&openapi::example(spec, &param["schema"], 0));
+                match param["in"].as_str() {
+                    Some("path") => path_params.push((name, value)),
+                    Some("query") => query.push((name, value, param["required"] == true)),
+                    _ => {}
+                }
+            }
+            let body = openapi::json_schema(spec, &op["requestBody"]).map(|schema| {
+                serde_json::to_string_pretty(&openapi::example(spec, schema, 0)).unwrap_or_default()
+            });
+            Request {
+                folder: op["tags"][0].as_str().unwrap_or("default").to_string(),
+                name: op["summary"]
+                    .as_str()
+                    .or(op["operationId"].as_str())
+                    .map(String::from)
+                    .unwrap_or_else(|| operation.label()),
+                method: operation.method.to_uppercase(),
+                path: operation.path.to_string(),
+                path_params,
+                query,
+                body,
+            }
+        })
+        .collect()
+}
+
+// "/items/{item_id}" -> "/items/:item_id", the path variable syntax of Postman and Bruno
+fn colon_path(path: &str) -> String {
+    path.split('/')
+        .map(
+            |segment| match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                Some(name) => format!(":{}", name),
+                None => segment.to_string(),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn folders(requests: &[Request]) -> Vec<(&str, Vec<&Request>)> {
+    let mut folders: Vec<(&str, Vec<&Request>)> = Vec::new();
+    for request in requests {
+        match folders.iter_mut().find(|(name, _)| *name == request.folder) {
+            Some((_, items)) => items.push(request),
+            None => folders.push((&request.folder, vec![request])),
+        }
+    }
+    folders
+}
+
+fn postman(name: &str, base_url: &str, requests: &[Request]) -> Value {
+    let item = |request: &Request| {
+        let path = colon_path(&request.path);
+        let raw = format!("{{{{baseUrl}}}}{}", path);
+        let mut value = json!({
+            "name": request.name,
+            "request": {
+                "method": request.method,
+                "header": [],
+                "url": {
+                    "raw": raw,
+                    "host": ["{{baseUrl}}"],
+                    "path": path.trim_start_matches('/').split('/').collect::<Vec<_>>(),
+                    "query": request.query.iter().map(|(key, value, required)| json!({
+                        "key": key,
+                        "value": value,
+                        "disabled": !required,
+                    })).collect::<Vec<_>>(),
+                    "variable": request.path_params.iter().map(|(key, value)| json!({
+                        "key": key,
+                        "value": value,
+                    })).collect::<Vec<_>>(),
+                },
+            },
+        });
+        if let Some(body) = &request.body {
+            value["request"]["header"] = json!([
+                { "key": "Content-Type", "value": "application/json" }
+            ]);
+            value["request"]["body"] = json!({
+                "mode": "raw",
+                "raw": body,
+                "options": { "raw": { "language": "json" } },
+            });
+        }
+        value
+    };
+    json!({
+        "info": { "name": name, "schema": POSTMAN_SCHEMA },
+        "variable": [{ "key": "baseUrl", "value": base_url }],
+        "item": folders(requests).into_iter().map(|(folder, items)| json!({
+            "name": folder,
+            "item": items.into_iter().map(item).collect::<Vec<_>>(),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+// Hoppscotch collections carry no variables, so the base URL and path parameter
+// examples are written into each endpoint
+fn hoppscotch(name: &str, base_url: &str, requests: &[Request]) -> Value {
+    let no_auth = json!({ "authType": "none", "authActive": true });
+    let request = |request: &Request| {
+        let mut path = request.path.clone();
+        for (key, value) in &request.path_params {
+            path = path.replace(&format!("{{{}}}", key), value);
+        }
+        json!({
+            "v": "1",
+            "name": request.name,
+            "method": request.method,
+            "endpoint": format!("{}{}", base_url, path),
+            "params": request.query.iter().map(|(key, value, required)| json!({
+                "key": key,
+                "value": value,
+                "active": required,
+            })).collect::<Vec<_>>(),
+            "headers": [],
+            "preRequestScript": "",
+            "testScript": "",
+            "auth": no_auth,
+            "body": match &request.body {
+                Some(body) => json!({ "contentType": "application/json", "body": body }),
+                None => json!({ "contentType": null, "body": null }),
+            },
+        })
+    };
+    json!({
+        "v": 2,
+        "name": name,
+        "folders": folders(requests).into_iter().map(|(folder, items)| json!({
+            "v": 2,
+            "name": folder,
+            "folders": [],
+            "requests": items.into_iter().map(request).collect::<Vec<_>>(),
+            "auth": no_auth,
+            "headers": [],
+        })).collect::<Vec<_>>(),
+        "requests": [],
+        "auth": no_auth,
+        "headers": [],
+    })
+}
+
+fn file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| {
+            if "\\/:*?\"<>|".contains(c) || c.is_control() {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect();
+    cleaned.trim().trim_end_matches('.').to_string()
+}
+
+fn bru_block(name: &str, entries: &[(String, String)]) -> String {
+    let mut block = format!("{} {{\n", name);
+    for (key, value) in entries {
+        block.push_str(&format!("  {}: {}\n", key, value));
+    }
+    block.push_str("}\n");
+    block
+}
+
+// Bruno collections are a directory of .bru files with a bruno.json at the root
+fn bruno(dir: &Path, name: &str, base_url: &str, requests: &[Request]) -> Result<(), String> {
+    let write = |path: PathBuf, content: String| {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    };
+    let manifest = json!({
+        "version": "1",
+        "name": name,
+        "type": "collection",
+        "ignore": ["node_modules", ".git"],
+    });
+    write(
+        dir.join("bruno.json"),
+        serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?,
+    )?;
+    write(
+        dir.join("environments").join("Local.bru"),
+        bru_block("vars", &[("baseUrl".to_string(), base_url.to_string())]),
+    )?;
+
+    for (folder, items) in folders(requests) {
+        for (seq, request) in items.into_iter().enumerate() {
+            let method = request.method.to_lowercase();
+            let mut content = bru_block(
+                "meta",
+                &[
+                    ("name".to_string(), request.name.clone()),
+                    ("type".to_string(), "http".to_string()),
+                    ("seq".to_string(), (seq + 1).to_string()),
+                ],
+            );
+            content.push('\n');
+            content.push_str(&bru_block(
+                &method,
+                &[
+                    (
+                        "url".to_string(),
+                        format!("{{{{baseUrl}}}}{}", colon_path(&request.path)),
+                    ),
+                    (
+                        "body".to_string(),
+                        if request.body.is_some() {
+                            "json"
+                        } else {
+                            "none"
+                        }
+                        .to_string(),
+                    ),
+                    ("auth".to_string(), "none".to_string()),
+                ],
+            ));
+            if !request.query.is_empty() {
+                content.push('\n');
+                let entries: Vec<(String, String)> = request
+                    .query
+                    .iter()
+                    .map(|(key, value, required)| {
+                        // A ~ prefix leaves the parameter disabled
+                        let key = if *required {
+                            key.clone()
+                        } else {
+                            format!("~{}", key)
+                        };
+                        (key, value.clone())
+                    })
+                    .collect();
+                content.push_str(&bru_block("params:query", &entries));
+            }
+            if !request.path_params.is_empty() {
+                content.push('\n');
+                content.push_str(&bru_block("params:path", &request.path_params));
+            }
+            if let Some(body) = &request.body {
+                content.push_str("\nbody:json {\n");
+                for line in body.lines() {
+                    content.push_str(&format!("  {}\n", line));
+                }
+                content.push_str("}\n");
+            }
+            write(
+                dir.join(file_name(folder))
+                    .join(format!("{}.bru", file_name(&request.name))),
+                content,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn export_api_collection(
+    project_path: String,
+    format: String,
+    // A directory; defaults to collections/ in the project
+    dest: Option<String>,
+) -> Result<CollectionExport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let format = format.to_lowercase();
+        if !["postman", "bruno", "hoppscotch"].contains(&format.as_str()) {
+            return Err(Error::invalid(
+                "unsupported_format",
+                format!("Unsupported collection format: {}", format),
+            )
+            .with("format", &format));
+        }
+
+        let (spec_source, spec) = openapi::current_spec(root)?;
+        let base_url =
+            openapi::backend_url(root).unwrap_or_else(|_| DEFAULT_BACKEND_URL.to_string());
+        let name = spec["info"]["title"]
+            .as_str()
+            .filter(|t| !t.trim().is_empty())
+            .map(String::from)
+            .unwrap_or_else(|| {
+                root.file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "API".to_string())
+            });
+        let requests = requests(&spec);
+
+        let dir = match dest {
+            Some(dest) => PathBuf::from(paths::new_path(&dest)?),
+            None => root.join(COLLECTION_DIR),
+        };
+        fs::create_dir_all(&dir)?;
+        let slug = scaffold::package_slug(&name);
+        let path = match format.as_str() {
+            "postman" => {
+                let path = dir.join(format!("{}.postman_collection.json", slug));
+                let collection = postman(&name, &base_url, &requests);
+                let content = serde_json::to_string_pretty(&collection).map_err(|e| e.to_string())?;
+                fs::write(&path, content)?;
+                path
+            }
+            "hoppscotch" => {
+                let path = dir.join(format!("{}.hoppscotch.json", slug));
+                let collection = hoppscotch(&name, &base_url, &requests);
+                let content = serde_json::to_string_pretty(&collection).map_err(|e| e.to_string())?;
+                fs::write(&path, content)?;
+                path
+            }
+            _ => {
+                let path = dir.join(format!("{}-bruno", slug));
+                bruno(&path, &name, &base_url, &requests)?;
+                path
+            }
+        };
+
+        tracing::info!(%project_path, %format, requests = requests.len(), "exported API collection");
+        Ok(CollectionExport {
+            format,
+            path: path.to_string_lossy().into_owned(),
+            requests: requests.len(),
+            base_url,
+            spec_source,
+        })
+    })
+    .await
+}
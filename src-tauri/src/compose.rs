@@ -0,0 +1,355 @@
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use tauri::{AppHandle, Manager};
+
+use crate::docker::{self, Engine};
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::projects;
+use crate::servicelog;
+use crate::task;
+use crate::ProcessManager;
+
+const COMPOSE_EVENT: &str = "compose-output";
+// The service type compose log lines are shown and kept under
+const COMPOSE_SERVICE: &str = "compose";
+// What `docker compose` itself looks for, plus the file the Postgres migration writes
+const COMPOSE_FILES: [&str; 5] = [
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yaml",
+    "docker-compose.yml",
+    "docker-compose.db.yml",
+];
+
+// A `compose logs --follow` per started stack, feeding container output into the same
+// log channel as native services
+static FOLLOWERS: Mutex<BTreeMap<String, Child>> = Mutex::new(BTreeMap::new());
+
+// A compose stack started from devLLM. Containers run detached, so unlike processes
+// there is no handle; their state is asked of the engine each time.
+#[derive(Clone)]
+pub struct Stack {
+    pub project_path: String,
+    pub file: PathBuf,
+}
+
+#[derive(serde::Serialize)]
+pub struct Container {
+    pub name: String,
+    pub service: String,
+    // "running", "exited", "restarting", "paused", "created", ...
+    pub state: String,
+    health: Option<String>,
+    status: String,
+    ports: Vec<String>,
+    exit_code: Option<i64>,
+}
+
+#[derive(serde::Serialize)]
+pub struct StackStatus {
+    key: String,
+    file: String,
+    pub running: bool,
+    containers: Vec<Container>,
+    // Set when the engine couldn't be asked, e.g. Docker isn't running
+    error: Option<String>,
+}
+
+pub fn key(project_path: &str, file: &Path) -> String {
+    let name = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    format!("{}:compose:{}", paths::key(project_path), name)
+}
+
+// What compose names the project when the file doesn't: the directory name, lowercased,
+// with anything but letters, digits, '-' and '_' dropped. Its resources are labelled with it.
+pub fn project_name(project_path: &str) -> String {
+    Path::new(project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect()
+}
+
+// The named compose file, or the first one compose would pick up by itself
+fn compose_file(project: &Path, file: Option<&str>) -> Result<PathBuf, Error> {
+    if let Some(file) = file {
+        let path = paths::existing_file(&project.join(file).to_string_lossy())?;
+        return Ok(PathBuf::from(path));
+    }
+    COMPOSE_FILES
+        .iter()
+        .map(|name| project.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            Error::not_found(
+                "no_compose_file",
+                "The project has no compose.yaml or docker-compose.yml",
+            )
+        })
+}
+
+pub fn command(engine: Engine, stack: &Stack) -> Command {
+    let mut cmd = engine.command();
+    cmd.arg("compose")
+        .arg("-f")
+        .arg(&stack.file)
+        .current_dir(&stack.project_path);
+    cmd
+}
+
+// Compose 2.21+ prints one JSON object per line; older releases print a single array
+fn parse_ps(stdout: &str) -> Vec<Container> {
+    let entries: Vec<serde_json::Value> = match serde_json::from_str(stdout.trim()) {
+        Ok(serde_json::Value::Array(entries)) => entries,
+        _ => stdout
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect(),
+    };
+    entries
+        .iter()
+        .map(|c| {
+            let text = |field: &str| c[field].as_str().unwrap_or_default().to_string();
+            Container {
+                name: text("Name"),
+                service: text("Service"),
+                state: text("State"),
+                health: c["Health"]
+                    .as_str()
+                    .filter(|h| !h.is_empty())
+                    .map(String::from),
+                status: text("Status"),
+                ports: c["Publishers"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|p| p["PublishedPort"].as_u64().is_some_and(|port| port > 0))
+                    .map(|p| {
+                        format!(
+                            "{}:{}->{}/{}",
+                            p["URL"].as_str().unwrap_or("0.0.0.0"),
+                            p["PublishedPort"],
+                            p["TargetPort"],
+                            p["Protocol"].as_str().unwrap_or("tcp")
+                        )
+                    })
+                    .collect(),
+                exit_code: c["ExitCode"].as_i64(),
+            }
+        })
+        .collect()
+}
+
+pub fn containers(engine: Engine, stack: &Stack) -> Result<Vec<Container>, String> {
+    let mut cmd = command(engine, stack);
+    cmd.args(["ps", "--all", "--format", "json"]);
+    let output = exec::output(&mut cmd).map_err(|e| format!("Failed to run compose ps: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "compose ps failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(parse_ps(&String::from_utf8_lossy(&output.stdout)))
+}
+
+pub fn status(engine: Option<Engine>, key: String, stack: &Stack) -> StackStatus {
+    let found = match engine {
+        Some(engine) => containers(engine, stack),
+        None => Err("Docker is not available".to_string()),
+    };
+    let (containers, error) = match found {
+        Ok(containers) => (containers, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+    StackStatus {
+        key,
+        file: stack.file.to_string_lossy().into_owned(),
+        running: containers.iter().any(|c| c.state == "running"),
+        containers,
+        error,
+    }
+}
+
+// All of a project's compose output goes to its "compose" service, the key the log panel
+// asks for; each line keeps the "db-1  | " prefix naming its container
+fn log_key(project_path: &str) -> String {
+    format!("{}:{}", paths::key(project_path), COMPOSE_SERVICE)
+}
+
+fn log_line(project_path: String) -> impl Fn(&AppHandle, &'static str, String) + Send + 'static {
+    let key = log_key(&project_path);
+    move |app, stream, line| {
+        servicelog::push(app, &key, &project_path, COMPOSE_SERVICE, stream, line);
+    }
+}
+
+fn follow_logs(app: &AppHandle, engine: Engine, stack: &Stack, key: &str) -> io::Result<()> {
+    let mut cmd = command(engine, stack);
+    cmd.args(["logs", "--follow", "--no-color", "--tail", "100"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    exec::hide_window(&mut cmd);
+    let mut child = exec::spawn(&mut cmd)?;
+    // `--tail` replays recent lines, so the previous follower's copy would repeat them
+    servicelog::reset(app, &log_key(&stack.project_path));
+    if let Some(stdout) = child.stdout.take() {
+        let on_line = log_line(stack.project_path.clone());
+        servicelog::follow(app, stdout, "stdout", on_line);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let on_line = log_line(stack.project_path.clone());
+        servicelog::follow(app, stderr, "stderr", on_line);
+    }
+    if let Some(mut previous) = FOLLOWERS.lock().insert(key.to_string(), child) {
+        let _ = previous.kill();
+        let _ = previous.wait();
+    }
+    Ok(())
+}
+
+fn stop_following(key: &str) {
+    let follower = FOLLOWERS.lock().remove(key);
+    if let Some(mut child) = follower {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+#[tauri::command]
+pub async fn start_stack(
+    app: AppHandle,
+    project_path: String,
+    file: Option<String>,
+    services: Option<Vec<String>>,
+) -> Result<StackStatus, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let engine = docker::require()?;
+        let stack = Stack {
+            file: compose_file(Path::new(&project_path), file.as_deref())?,
+            project_path,
+        };
+        let key = key(&stack.project_path, &stack.file);
+
+        // Image pulls and builds can take minutes, so progress is streamed
+        let mut cmd = command(engine, &stack);
+        cmd.args(["up", "-d", "--remove-orphans"])
+            .args(services.iter().flatten());
+        let output = exec::run_streamed(&app, COMPOSE_EVENT, &key, &mut cmd)
+            .map_err(|e| exec::run_failed("docker compose", e))?;
+        if !output.status.success() {
+            let tail: Vec<&str> = output
+                .lines
+                .iter()
+                .rev()
+                .take(5)
+                .map(String::as_str)
+                .collect();
+            return Err(Error::tool_failed(
+                "docker",
+                format!(
+                    "docker compose up failed: {}",
+                    tail.into_iter().rev().collect::<Vec<_>>().join("\n")
+                ),
+            ));
+        }
+
+        if let Err(e) = follow_logs(&app, engine, &stack, &key) {
+            tracing::warn!(stack = %key, "Failed to follow compose logs: {}", e);
+        }
+        let state = app.state::<ProcessManager>();
+        state.stacks.lock().insert(key.clone(), stack.clone());
+        tracing::info!(stack = %key, "compose stack started");
+        Ok(status(Some(engine), key, &stack))
+    })
+    .await
+}
+
+// Volumes are kept, so databases survive a stop
+fn down(engine: Engine, stack: &Stack) -> Result<(), Error> {
+    let mut cmd = command(engine, stack);
+    cmd.arg("down");
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed("docker compose", e))?;
+    if !output.status.success() {
+        return Err(Error::tool_failed(
+            "docker",
+            format!(
+                "docker compose down failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+// Takes down every stack started this session
+pub fn stop_all(state: &ProcessManager) {
+    let stacks: Vec<(String, Stack)> = state.stacks.lock().drain().collect();
+    if stacks.is_empty() {
+        return;
+    }
+    let Some(engine) = docker::engine() else {
+        return;
+    };
+    for (key, stack) in stacks {
+        stop_following(&key);
+        match down(engine, &stack) {
+            Ok(()) => tracing::info!(stack = %key, "compose stack stopped"),
+            Err(e) => tracing::warn!(stack = %key, "{}", e),
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn stop_stack(
+    app: AppHandle,
+    project_path: String,
+    file: Option<String>,
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let engine = docker::require()?;
+        let stack = Stack {
+            file: compose_file(Path::new(&project_path), file.as_deref())?,
+            project_path,
+        };
+        let key = key(&stack.project_path, &stack.file);
+
+        down(engine, &stack)?;
+        stop_following(&key);
+        app.state::<ProcessManager>().stacks.lock().remove(&key);
+        tracing::info!(stack = %key, "compose stack stopped");
+        Ok(format!("{} stopped", key))
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn get_stack_status(
+    project_path: String,
+    file: Option<String>,
+) -> Result<StackStatus, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let engine = docker::require()?;
+        let stack = Stack {
+            file: compose_file(Path::new(&project_path), file.as_deref())?,
+            project_path,
+        };
+        let key = key(&stack.project_path, &stack.file);
+        Ok(status(Some(engine), key, &stack))
+    })
+    .await
+}
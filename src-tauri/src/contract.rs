@@ -0,0 +1,454 @@
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::deps;
+use crate::error::Error;
+use crate::openapi;
+use crate::paths;
+use crate::task;
+
+const SOURCE_EXTENSIONS: [&str; 4] = ["ts", "tsx", "js", "jsx"];
+// Suffixes FastAPI projects put on schema names that a frontend type usually drops
+const SCHEMA_NOISE: [&str; 5] = ["response", "read", "out", "schema", "model"];
+
+#[derive(serde::Serialize)]
+pub struct ContractIssue {
+    // "field_missing_in_backend", "field_missing_in_frontend", "type_mismatch" or
+    // "missing_endpoint"
+    kind: &'static str,
+    file: String,
+    line: usize,
+    // Interface or "METHOD /path" the issue is about
+    name: String,
+    field: Option<String>,
+    message: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct ContractReport {
+    // "backend" when the running backend answered, else "last_sync"
+    spec_source: &'static str,
+    interfaces_checked: usize,
+    endpoints_checked: usize,
+    issues: Vec<ContractIssue>,
+    // Frontend interfaces no backend schema corresponds to
+    unmatched: Vec<String>,
+}
+
+struct TsField {
+    name: String,
+    // None for inline object types, which aren't compared
+    ty: Option<String>,
+    line: usize,
+}
+
+struct TsInterface {
+    name: String,
+    file: String,
+    line: usize,
+    fields: Vec<TsField>,
+}
+
+fn source_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if entry.file_name() != "node_modules" {
+                source_files(&path, out);
+            }
+        } else if path
+            .extension()
+            .is_some_and(|ext| SOURCE_EXTENSIONS.iter().any(|e| ext == *e))
+        {
+            out.push(path);
+        }
+    }
+}
+
+// `export interface Item {`, `interface Item extends Base {`, `export type Item = {`
+fn declaration_name(line: &str) -> Option<String> {
+    let line = line.trim().strip_prefix("export ").unwrap_or(line.trim());
+    let rest = line.strip_prefix("interface ").or_else(|| {
+        line.strip_prefix("type ")
+            .filter(|r| r.trim_end().ends_with("= {"))
+    })?;
+    if !rest.trim_end().ends_with('{') {
+        return None;
+    }
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '$')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+fn parse_field(line: &str) -> Option<(String, String)> {
+    let line = line.split("//").next().unwrap_or(line).trim();
+    let line = line.trim_end_matches([';', ',']);
+    let (name, ty) = line.split_once(':')?;
+    let name = name
+        .trim()
+        .trim_start_matches("readonly ")
+        .trim_end_matches('?')
+        .trim_matches(['"', '\''])
+        .to_string();
+    if name.is_empty() || name.contains(['(', '[', ' ']) {
+        return None;
+    }
+    Some((name, ty.trim().to_string()))
+}
+
+// One field per line, which is how every interface in a scaffolded project is written
+fn parse_interfaces(file: &str, content: &str) -> Vec<TsInterface> {
+    let mut interfaces = Vec::new();
+    let mut current: Option<TsInterface> = None;
+    let mut depth = 0usize;
+    for (i, line) in content.lines().enumerate() {
+        let opens = line.matches('{').count();
+        let closes = line.matches('}').count();
+        match current.as_mut() {
+            None => {
+                if let Some(name) = declaration_name(line) {
+                    current = Some(TsInterface {
+                        name,
+                        file: file.to_string(),
+                        line: i + 1,
+                        fields: Vec::new(),
+                    });
+                    depth = opens.saturating_sub(closes);
+                    if depth == 0 {
+                        interfaces.extend(current.take());
+                    }
+                }
+                continue;
+            }
+            Some(interface) if depth == 1 => {
+                if let Some((name, ty)) = parse_field(line) {
+                    interface.fields.push(TsField {
+                        name,
+                        ty: (opens == closes).then_some(ty),
+                        line: i + 1,
+                    });
+                }
+            }
+            Some(_) => {}
+        }
+        depth = (depth + opens).saturating_sub(closes);
+        if depth == 0 {
+            interfaces.extend(current.take());
+        }
+    }
+    interfaces
+}
+
+// "ItemCreate" and "CreateItem" -> {"create", "item"}; "ItemResponse" -> {"item"}
+fn name_words(name: &str) -> BTreeSet<String> {
+    let mut words = BTreeSet::new();
+    let mut word = String::new();
+    for c in name.chars() {
+        if (c.is_ascii_uppercase() || c == '_') && !word.is_empty() {
+            words.insert(std::mem::take(&mut word).to_lowercase());
+        }
+        if c != '_' {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {
+        words.insert(word.to_lowercase());
+    }
+    words.retain(|w| !SCHEMA_NOISE.contains(&w.as_str()));
+    words
+}
+
+fn matching_schema<'a>(
+    interface: &str,
+    schemas: &'a serde_json::Map<String, Value>,
+) -> Option<&'a str> {
+    if schemas.contains_key(interface) {
+        return schemas.get_key_value(interface).map(|(k, _)| k.as_str());
+    }
+    let words = name_words(interface);
+    schemas
+        .keys()
+        .find(|name| name_words(name) == words)
+        .map(String::as_str)
+}
+
+// Splits a type on top-level '|' so member order doesn't matter
+fn members(ty: &str, renames: &BTreeMap<String, String>) -> BTreeSet<String> {
+    let compact: String = ty.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+    for c in compact.chars() {
+        match c {
+            '<' | '(' | '{' | '[' => depth += 1,
+            '>' | ')' | '}' | ']' => depth -= 1,
+            _ => {}
+        }
+        if c == '|' && depth == 0 {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    parts.push(current);
+    parts
+        .into_iter()
+        .filter(|p| !p.is_empty() && p != "undefined")
+        .map(|p| {
+            let p = match p.strip_prefix("Array<").and_then(|p| p.strip_suffix('>')) {
+                Some(item) => format!("{}[]", item),
+                None => p,
+            };
+            let base = p.trim_end_matches("[]");
+            match renames.get(base) {
+                Some(renamed) => p.replacen(base, renamed, 1),
+                None => p,
+            }
+        })
+        .collect()
+}
+
+fn compare_fields(
+    interface: &TsInterface,
+    schema_name: &str,
+    schema: &Value,
+    renames: &BTreeMap<String, String>,
+    issues: &mut Vec<ContractIssue>,
+) {
+    let properties = schema["properties"]
+        .as_object()
+        .cloned()
+        .unwrap_or_default();
+    let required: BTreeSet<&str> = schema["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let issue = |kind, line, field: &str, message| ContractIssue {
+        kind,
+        file: interface.file.clone(),
+        line,
+        name: interface.name.clone(),
+        field: Some(field.to_string()),
+        message,
+    };
+
+    for field in &interface.fields {
+        let Some(property) = properties.get(&field.name) else {
+            issues.push(issue(
+                "field_missing_in_backend",
+                field.line,
+                &field.name,
+                format!(
+                    "{}.{} has no counterpart in {}",
+                    interface.name, field.name, schema_name
+                ),
+            ));
+            continue;
+        };
+        let backend = openapi::ts_type(property, "");
+        let Some(frontend) = &field.ty else {
+            continue;
+        };
+        if backend.contains("unknown") {
+            continue;
+        }
+        if members(frontend, renames) != members(&backend, renames) {
+            issues.push(issue(
+                "type_mismatch",
+                field.line,
+                &field.name,
+                format!(
+                    "{}.{} is {} in the frontend but {} in {}",
+                    interface.name, field.name, frontend, backend, schema_name
+                ),
+            ));
+        }
+    }
+    for name in required {
+        if !interface.fields.iter().any(|f| f.name == name) {
+            issues.push(issue(
+                "field_missing_in_frontend",
+                interface.line,
+                name,
+                format!(
+                    "{} requires {}, which {} doesn't declare",
+                    schema_name, name, interface.name
+                ),
+            ));
+        }
+    }
+}
+
+// `api.get<Item[]>('/items')`, `api.delete(`/items/${id}`)` and `fetch(`${API_URL}/items`)`
+fn api_calls(content: &str) -> Vec<(Option<&'static str>, String, usize)> {
+    let mut calls = Vec::new();
+    let patterns: [(&str, Option<&'static str>); 6] = [
+        ("api.get", Some("get")),
+        ("api.post", Some("post")),
+        ("api.put", Some("put")),
+        ("api.patch", Some("patch")),
+        ("api.delete", Some("delete")),
+        ("fetch(", None),
+    ];
+    for (pattern, method) in patterns {
+        for (offset, _) in content.match_indices(pattern) {
+            let mut rest = &content[offset + pattern.len()..];
+            if method.is_some() {
+                if rest.starts_with('<') {
+                    let mut depth = 0;
+                    let Some(end) = rest.find(|c| {
+                        match c {
+                            '<' => depth += 1,
+                            '>' => depth -= 1,
+                            _ => {}
+                        }
+                        depth == 0
+                    }) else {
+                        continue;
+                    };
+                    rest = &rest[end + 1..];
+                }
+                let Some(after) = rest.strip_prefix('(') else {
+                    continue;
+                };
+                rest = after;
+            }
+            let rest = rest.trim_start();
+            let Some(quote) = rest.chars().next().filter(|c| "'\"`".contains(*c)) else {
+                continue;
+            };
+            let Some(end) = rest[1..].find(quote) else {
+                continue;
+            };
+            let literal = &rest[1..end + 1];
+            // A leading ${API_URL} is the base URL, any other ${...} a path parameter
+            let literal = match literal.strip_prefix("${") {
+                Some(after) => after.split_once('}').map_or("", |(_, path)| path),
+                None => literal,
+            };
+            if !literal.starts_with('/') {
+                continue;
+            }
+            let mut path = String::new();
+            let mut chars = literal.split('?').next().unwrap_or("").chars().peekable();
+            while let Some(c) = chars.next() {
+                if c == '$' && chars.peek() == Some(&'{') {
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            break;
+                        }
+                    }
+                    path.push_str("{param}");
+                } else {
+                    path.push(c);
+                }
+            }
+            let line = content[..offset].matches('\n').count() + 1;
+            calls.push((method, path, line));
+        }
+    }
+    calls
+}
+
+fn path_matches(template: &str, called: &str) -> bool {
+    let a: Vec<&str> = template.trim_end_matches('/').split('/').collect();
+    let b: Vec<&str> = called.trim_end_matches('/').split('/').collect();
+    a.len() == b.len()
+        && a.iter()
+            .zip(&b)
+            .all(|(t, c)| t == c || (t.starts_with('{') && t.ends_with('}')) || *c == "{param}")
+}
+
+#[tauri::command]
+pub async fn check_contract(project_path: String) -> Result<ContractReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let frontend = deps::service_dir(root, "frontend")?;
+        let (spec_source, spec) = openapi::current_spec(root)?;
+        let schemas = spec["components"]["schemas"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut files = Vec::new();
+        source_files(&frontend.join("src"), &mut files);
+        let mut sources = Vec::new();
+        for file in files {
+            let Ok(content) = fs::read_to_string(&file) else {
+                continue;
+            };
+            // Generated from this very spec, so it can't drift
+            if content.starts_with(openapi::GENERATED_HEADER) {
+                continue;
+            }
+            sources.push((paths::relative(&frontend, &file.to_string_lossy()), content));
+        }
+
+        let interfaces: Vec<TsInterface> = sources
+            .iter()
+            .filter(|(file, _)| file.starts_with("src/types/"))
+            .flat_map(|(file, content)| parse_interfaces(file, content))
+            .collect();
+        let matched: Vec<(&TsInterface, &str)> = interfaces
+            .iter()
+            .filter_map(|i| matching_schema(&i.name, &schemas).map(|s| (i, s)))
+            .collect();
+        // A field typed `Item` in the frontend is right when the schema says `ItemResponse`
+        let renames: BTreeMap<String, String> = matched
+            .iter()
+            .map(|(interface, schema)| (openapi::type_name(schema), interface.name.clone()))
+            .collect();
+
+        let mut issues = Vec::new();
+        for (interface, schema) in &matched {
+            compare_fields(interface, schema, &schemas[*schema], &renames, &mut issues);
+        }
+        let unmatched = interfaces
+            .iter()
+            .filter(|i| !matched.iter().any(|(m, _)| m.name == i.name))
+            .map(|i| i.name.clone())
+            .collect();
+
+        let operations = openapi::operations(&spec);
+        let mut endpoints_checked = 0;
+        for (file, content) in &sources {
+            for (method, path, line) in api_calls(content) {
+                endpoints_checked += 1;
+                let found = operations.iter().any(|op| {
+                    method.is_none_or(|m| m == op.method) && path_matches(op.path, &path)
+                });
+                if !found {
+                    let label = format!("{} {}", method.unwrap_or("fetch").to_uppercase(), path);
+                    issues.push(ContractIssue {
+                        kind: "missing_endpoint",
+                        file: file.clone(),
+                        line,
+                        message: format!("The backend has no endpoint for {}", label),
+                        name: label,
+                        field: None,
+                    });
+                }
+            }
+        }
+
+        tracing::info!(%project_path, issues = issues.len(), "checked API contract");
+        Ok(ContractReport {
+            spec_source,
+            interfaces_checked: matched.len(),
+            endpoints_checked,
+            issues,
+            unmatched,
+        })
+    })
+    .await
+}
@@ -0,0 +1,216 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::paths;
+
+#[derive(serde::Serialize)]
+pub struct LineRange {
+    start: u64,
+    end: u64,
+}
+
+#[derive(serde::Serialize)]
+pub struct FileCoverage {
+    file: String,
+    percent: f64,
+    covered: u64,
+    total: u64,
+    uncovered: Vec<LineRange>,
+}
+
+#[derive(serde::Serialize)]
+pub struct CoverageReport {
+    percent: f64,
+    // Least covered first, which is where new tests help most
+    files: Vec<FileCoverage>,
+}
+
+fn percent(covered: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 100.0;
+    }
+    (covered as f64 * 10000.0 / total as f64).round() / 100.0
+}
+
+// Sorted line numbers folded into start..=end runs
+fn ranges(mut lines: Vec<u64>) -> Vec<LineRange> {
+    lines.sort_unstable();
+    lines.dedup();
+    let mut out: Vec<LineRange> = Vec::new();
+    for line in lines {
+        match out.last_mut() {
+            Some(range) if range.end + 1 == line => range.end = line,
+            _ => out.push(LineRange {
+                start: line,
+                end: line,
+            }),
+        }
+    }
+    out
+}
+
+fn report(mut files: Vec<FileCoverage>) -> CoverageReport {
+    let covered = files.iter().map(|f| f.covered).sum();
+    let total = files.iter().map(|f| f.total).sum();
+    files.sort_by(|a, b| a.percent.total_cmp(&b.percent).then(a.file.cmp(&b.file)));
+    CoverageReport {
+        percent: percent(covered, total),
+        files,
+    }
+}
+
+// `coverage json` output: per-file summary plus the missing line numbers
+pub fn from_coverage_py(dir: &Path, json: &serde_json::Value) -> CoverageReport {
+    let files = json["files"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(file, data)| {
+            let summary = &data["summary"];
+            let covered = summary["covered_lines"].as_u64().unwrap_or(0);
+            let total = summary["num_statements"].as_u64().unwrap_or(0);
+            let missing = data["missing_lines"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|l| l.as_u64())
+                .collect();
+            FileCoverage {
+                file: paths::relative(dir, file),
+                percent: percent(covered, total),
+                covered,
+                total,
+                uncovered: ranges(missing),
+            }
+        })
+        .collect();
+    report(files)
+}
+
+// Istanbul's coverage-final.json (c8 --reporter=json): statement locations and hit counts.
+// A line counts as covered when any statement starting on it ran.
+pub fn from_istanbul(dir: &Path, json: &serde_json::Value) -> CoverageReport {
+    let files = json
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(file, data)| {
+            let mut lines: BTreeMap<u64, bool> = BTreeMap::new();
+            for (id, location) in data["statementMap"].as_object().into_iter().flatten() {
+                let Some(line) = location["start"]["line"].as_u64() else {
+                    continue;
+                };
+                let hit = data["s"][id].as_u64().unwrap_or(0) > 0;
+                *lines.entry(line).or_insert(false) |= hit;
+            }
+            let covered = lines.values().filter(|hit| **hit).count() as u64;
+            let total = lines.len() as u64;
+            let missing = lines
+                .into_iter()
+                .filter(|(_, hit)| !hit)
+                .map(|(line, _)| line)
+                .collect();
+            FileCoverage {
+                file: paths::relative(dir, data["path"].as_str().unwrap_or(file)),
+                percent: percent(covered, total),
+                covered,
+                total,
+                uncovered: ranges(missing),
+            }
+        })
+        .collect();
+    report(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // file, percent, covered, total, uncovered ranges
+    type Row<'a> = (&'a str, f64, u64, u64, Vec<(u64, u64)>);
+
+    fn summary(report: &CoverageReport) -> Vec<Row<'_>> {
+        report
+            .files
+            .iter()
+            .map(|f| {
+                let uncovered = f.uncovered.iter().map(|r| (r.start, r.end)).collect();
+                (f.file.as_str(), f.percent, f.covered, f.total, uncovered)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_coverage_py_json() {
+        let json = json!({
+            "meta": { "version": "7.4.0" },
+            "files": {
+                "/app/backend/app/main.py": {
+                    "summary": { "covered_lines": 9, "num_statements": 10 },
+                    "missing_lines": [42]
+                },
+                "/app/backend/app/crud.py": {
+                    "summary": { "covered_lines": 4, "num_statements": 12 },
+                    "missing_lines": [12, 10, 11, 20, 21, 30, 31, 11]
+                },
+                "/app/backend/app/__init__.py": {
+                    "summary": { "covered_lines": 0, "num_statements": 0 },
+                    "missing_lines": []
+                }
+            },
+            "totals": {}
+        });
+
+        let report = from_coverage_py(Path::new("/app/backend"), &json);
+        assert_eq!(report.percent, 59.09);
+        assert_eq!(
+            summary(&report),
+            [
+                (
+                    "app/crud.py",
+                    33.33,
+                    4,
+                    12,
+                    vec![(10, 12), (20, 21), (30, 31)]
+                ),
+                ("app/main.py", 90.0, 9, 10, vec![(42, 42)]),
+                ("app/__init__.py", 100.0, 0, 0, vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_istanbul_json() {
+        let json = json!({
+            "/app/frontend/src/api.ts": {
+                "path": "/app/frontend/src/api.ts",
+                "statementMap": {
+                    "0": { "start": { "line": 1, "column": 0 }, "end": { "line": 1, "column": 30 } },
+                    "1": { "start": { "line": 3, "column": 2 }, "end": { "line": 3, "column": 10 } },
+                    "2": { "start": { "line": 3, "column": 12 }, "end": { "line": 3, "column": 20 } },
+                    "3": { "start": { "line": 4, "column": 2 }, "end": { "line": 4, "column": 9 } },
+                    "4": { "start": { "line": 5, "column": 2 }, "end": { "line": 5, "column": 9 } }
+                },
+                "s": { "0": 1, "1": 0, "2": 3, "3": 0, "4": 0 }
+            },
+            "/app/frontend/src/util.ts": {
+                "statementMap": {
+                    "0": { "start": { "line": 2, "column": 0 }, "end": { "line": 2, "column": 5 } }
+                },
+                "s": { "0": 7 }
+            }
+        });
+
+        let report = from_istanbul(Path::new("/app/frontend"), &json);
+        assert_eq!(report.percent, 60.0);
+        assert_eq!(
+            summary(&report),
+            [
+                // Line 3 counts as covered: one of its two statements ran
+                ("src/api.ts", 50.0, 2, 4, vec![(4, 5)]),
+                ("src/util.ts", 100.0, 1, 1, vec![]),
+            ]
+        );
+    }
+}
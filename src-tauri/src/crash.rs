@@ -0,0 +1,156 @@
+use parking_lot::Mutex;
+use std::backtrace::Backtrace;
+use std::collections::VecDeque;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::db;
+use crate::error::Error;
+use crate::task;
+
+const HISTORY_SIZE: usize = 20;
+
+// Recent IPC commands, so a crash report shows what the user was doing
+static HISTORY: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+#[derive(serde::Serialize)]
+pub struct CrashReport {
+    id: String,
+    path: String,
+    created_at: String,
+    message: String,
+}
+
+pub fn record_command(command: &str) {
+    let mut history = HISTORY.lock();
+    if history.len() == HISTORY_SIZE {
+        history.pop_front();
+    }
+    history.push_back(format!("{} {}", db::format_timestamp(now_secs()), command));
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn crash_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("crashes"))
+        .map_err(|e| e.to_string())
+}
+
+pub fn install(app: &AppHandle) {
+    let Ok(dir) = crash_dir(app) else {
+        return;
+    };
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let message = match info.payload().downcast_ref::<&str>() {
+            Some(s) => s.to_string(),
+            None => info
+                .payload()
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_else(|| "unknown panic".to_string()),
+        };
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+            .unwrap_or_default();
+        let thread = std::thread::current()
+            .name()
+            .unwrap_or("unnamed")
+            .to_string();
+        // try_lock: the panicking thread may be the one holding the history
+        let history: Vec<String> = HISTORY
+            .try_lock()
+            .map(|history| history.iter().cloned().collect())
+            .unwrap_or_default();
+
+        let now = now_secs();
+        let report = format!(
+            "devLLM {} crash report\ntime: {}\nthread: {}\nlocation: {}\nmessage: {}\n\nrecent commands:\n{}\n\nbacktrace:\n{}\n",
+            env!("CARGO_PKG_VERSION"),
+            db::format_timestamp(now),
+            thread,
+            location,
+            message,
+            history.join("\n"),
+            Backtrace::force_capture()
+        );
+        let name = format!(
+            "crash-{}.txt",
+            db::format_timestamp(now).replace([' ', ':'], "-")
+        );
+        if fs::create_dir_all(&dir).is_ok() {
+            let _ = fs::write(dir.join(name), report);
+        }
+        tracing::error!(%location, "panic: {}", message);
+        previous(info);
+    }));
+}
+
+fn report_info(path: &Path) -> Option<CrashReport> {
+    let id = path.file_name()?.to_str()?.to_string();
+    if !id.starts_with("crash-") {
+        return None;
+    }
+    let content = fs::read_to_string(path).ok()?;
+    let field = |name: &str| {
+        content
+            .lines()
+            .find_map(|l| l.strip_prefix(name))
+            .unwrap_or("")
+            .to_string()
+    };
+    Some(CrashReport {
+        id,
+        path: path.to_string_lossy().into_owned(),
+        created_at: field("time: "),
+        message: field("message: "),
+    })
+}
+
+#[tauri::command]
+pub async fn list_crash_reports(app: AppHandle) -> Result<Vec<CrashReport>, Error> {
+    task::blocking(move || {
+        let dir = crash_dir(&app)?;
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut reports: Vec<CrashReport> = fs::read_dir(dir)?
+            .flatten()
+            .filter_map(|entry| report_info(&entry.path()))
+            .collect();
+        // Timestamped names sort chronologically; newest first
+        reports.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(reports)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn read_crash_report(app: AppHandle, id: String) -> Result<String, Error> {
+    task::blocking(move || {
+        if id.contains(['/', '\\']) || id.contains("..") {
+            return Err(
+                Error::invalid("invalid_crash_id", "Invalid crash report id").with("id", &id),
+            );
+        }
+        fs::read_to_string(crash_dir(&app)?.join(&id)).map_err(|e| {
+            Error::not_found(
+                "crash_report_not_found",
+                format!("Crash report not found: {}: {}", id, e),
+            )
+            .with("id", &id)
+        })
+    })
+    .await
+}
@@ -0,0 +1,100 @@
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, Manager, State, Url};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+use crate::instance;
+use crate::paths;
+use crate::projects;
+
+const CREATE_PROJECT_EVENT: &str = "create-project-request";
+
+#[derive(Clone, serde::Serialize)]
+pub struct CreateRequest {
+    template: Option<String>,
+    name: Option<String>,
+    path: Option<String>,
+    frontend_port: Option<u16>,
+    backend_port: Option<u16>,
+    python_manager: Option<String>,
+}
+
+#[derive(Default)]
+pub struct PendingCreate(Mutex<Option<CreateRequest>>);
+
+// devllm://open?path=C:/code/app and devllm://create?template=fullstack&name=app
+fn handle_url(app: &AppHandle, url: &Url, startup: bool) -> Result<(), String> {
+    if url.scheme() != "devllm" {
+        return Err(format!("Not a devllm:// link: {}", url));
+    }
+    let action = url.host_str().unwrap_or_default();
+    let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+    match action {
+        "open" => {
+            let path = params
+                .get("path")
+                .ok_or_else(|| "devllm://open needs a path parameter".to_string())?;
+            // Any web page can send this link, so it only opens projects already added,
+            // and a network share is refused before Windows tries to log on to it
+            let path = projects::project_dir(app, path).map_err(|e| e.to_string())?;
+            if startup {
+                instance::queue_project(app, path);
+            } else {
+                instance::open_project(app, path);
+            }
+        }
+        // Links can come from any web page, so creation is only prefilled for the user to confirm
+        "create" => {
+            let request = CreateRequest {
+                template: params.get("template").cloned(),
+                name: params.get("name").cloned(),
+                // Only shown in the form, but a share is still dropped rather than displayed
+                path: params
+                    .get("path")
+                    .filter(|path| paths::new_path(path).is_ok())
+                    .cloned(),
+                frontend_port: params.get("frontend_port").and_then(|p| p.parse().ok()),
+                backend_port: params.get("backend_port").and_then(|p| p.parse().ok()),
+                python_manager: params.get("python_manager").cloned(),
+            };
+            if startup {
+                *app.state::<PendingCreate>().0.lock() = Some(request);
+            } else {
+                let _ = app.emit(CREATE_PROJECT_EVENT, request);
+            }
+        }
+        other => return Err(format!("Unknown devllm:// action: {}", other)),
+    }
+    instance::focus_main(app);
+    Ok(())
+}
+
+pub fn init(app: &AppHandle) -> Result<(), String> {
+    // Installers register the scheme; this covers dev builds and portable copies
+    #[cfg(any(windows, target_os = "linux"))]
+    app.deep_link().register_all().map_err(|e| e.to_string())?;
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            if let Err(e) = handle_url(&handle, &url, false) {
+                tracing::warn!("Ignoring deep link: {}", e);
+            }
+        }
+    });
+
+    if let Some(urls) = app.deep_link().get_current().map_err(|e| e.to_string())? {
+        for url in urls {
+            if let Err(e) = handle_url(app, &url, true) {
+                tracing::warn!("Ignoring deep link: {}", e);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn take_pending_create(state: State<PendingCreate>) -> Option<CreateRequest> {
+    state.0.lock().take()
+}
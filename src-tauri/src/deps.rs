@@ -0,0 +1,884 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+use tauri::AppHandle;
+
+use crate::error::Error;
+use crate::exec;
+use crate::node::{self, PackageManager};
+use crate::paths;
+use crate::projects;
+use crate::python::{self, PythonPackageManager};
+use crate::task;
+
+pub const INSTALL_EVENT: &str = "install-progress";
+
+#[derive(serde::Serialize)]
+pub struct InstallIssue {
+    pub kind: &'static str,
+    pub package: Option<String>,
+    pub message: String,
+    pub hint: String,
+}
+
+#[derive(serde::Serialize, Default)]
+pub struct PackageCounts {
+    pub added: u32,
+    pub removed: u32,
+    pub changed: u32,
+}
+
+#[derive(serde::Serialize)]
+pub struct InstallReport {
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u128,
+    pub packages: Option<PackageCounts>,
+    pub issues: Vec<InstallIssue>,
+}
+
+#[derive(serde::Serialize)]
+pub struct OutdatedPackage {
+    service: &'static str,
+    ecosystem: &'static str,
+    name: String,
+    current: Option<String>,
+    wanted: Option<String>,
+    latest: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct OutdatedReport {
+    packages: Vec<OutdatedPackage>,
+    errors: Vec<String>,
+}
+
+// Services follow the scaffold layout; a flat project is treated as a single service
+pub fn service_dirs(project_path: &Path) -> (Option<PathBuf>, Option<PathBuf>) {
+    let frontend = [project_path.join("frontend"), project_path.to_path_buf()]
+        .into_iter()
+        .find(|p| p.join("package.json").exists());
+
+    let backend = [project_path.join("backend"), project_path.to_path_buf()]
+        .into_iter()
+        .find(|p| {
+            p.join("requirements.txt").exists()
+                || p.join("pyproject.toml").exists()
+                || p.join("main.py").exists()
+        });
+
+    (frontend, backend)
+}
+
+// The directory of one service ("frontend" or "backend") of a project
+pub fn service_dir(project_path: &Path, service: &str) -> Result<PathBuf, Error> {
+    let (frontend, backend) = service_dirs(project_path);
+    let dir = match service {
+        "frontend" => frontend,
+        "backend" => backend,
+        _ => {
+            return Err(
+                Error::invalid("invalid_service", format!("Unknown service: {}", service))
+                    .with("service", service),
+            )
+        }
+    };
+    dir.ok_or_else(|| {
+        Error::not_found(
+            "service_not_found",
+            format!("This project has no {}", service),
+        )
+        .with("service", service)
+    })
+}
+
+#[tauri::command]
+pub async fn check_outdated(project_path: String) -> Result<OutdatedReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let path = Path::new(&project_path);
+
+        let (frontend, backend) = service_dirs(path);
+        let mut report = OutdatedReport {
+            packages: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        if let Some(frontend) = frontend {
+            match node_outdated(&frontend) {
+                Ok(packages) => report.packages.extend(packages),
+                Err(e) => report.errors.push(e),
+            }
+        }
+
+        if let Some(backend) = backend {
+            match python_outdated(&backend) {
+                Ok(packages) => report.packages.extend(packages),
+                Err(e) => report.errors.push(e),
+            }
+        }
+
+        Ok(report)
+    })
+    .await
+}
+
+fn node_outdated(path: &Path) -> Result<Vec<OutdatedPackage>, String> {
+    let pm = match node::detect_package_manager(path) {
+        PackageManager::Pnpm => PackageManager::Pnpm,
+        _ => PackageManager::Npm,
+    };
+
+    let mut cmd = node::manager_command(path, pm).map_err(|e| e.to_string())?;
+    cmd.arg("outdated").current_dir(path);
+    if pm == PackageManager::Pnpm {
+        cmd.args(["--format", "json"]);
+    } else {
+        cmd.arg("--json");
+    }
+
+    // Both exit non-zero when anything is outdated, so only the JSON matters
+    let output = exec::output(&mut cmd)
+        .map_err(|e| format!("Failed to run {} outdated: {}", pm.program(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let json: serde_json::Value = serde_json::from_str(&stdout)
+        .map_err(|e| format!("Unexpected {} outdated output: {}", pm.program(), e))?;
+    let entries = json.as_object().cloned().unwrap_or_default();
+
+    Ok(entries
+        .into_iter()
+        .map(|(name, info)| OutdatedPackage {
+            service: "frontend",
+            ecosystem: "npm",
+            name,
+            current: info["current"].as_str().map(String::from),
+            wanted: info["wanted"].as_str().map(String::from),
+            latest: info["latest"].as_str().unwrap_or_default().to_string(),
+        })
+        .collect())
+}
+
+fn python_outdated(path: &Path) -> Result<Vec<OutdatedPackage>, String> {
+    let python = python::venv_python(path)
+        .ok_or_else(|| "Backend has no virtual environment".to_string())?;
+
+    let mut cmd = Command::new(python);
+    cmd.args([
+        "-m",
+        "pip",
+        "list",
+        "--outdated",
+        "--format=json",
+        "--disable-pip-version-check",
+    ])
+    .current_dir(path);
+
+    let output = exec::output(&mut cmd).map_err(|e| format!("Failed to run pip list: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "pip list failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Unexpected pip list output: {}", e))?;
+
+    Ok(entries
+        .into_iter()
+        .map(|info| OutdatedPackage {
+            service: "backend",
+            ecosystem: "pypi",
+            name: info["name"].as_str().unwrap_or_default().to_string(),
+            current: info["version"].as_str().map(String::from),
+            wanted: None,
+            latest: info["latest_version"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        })
+        .collect())
+}
+
+#[derive(serde::Serialize)]
+pub struct DependencyChange {
+    name: String,
+    manager: &'static str,
+    dev: bool,
+    resolved_version: Option<String>,
+}
+
+fn run_checked(cmd: &mut Command, what: &str) -> Result<(), Error> {
+    let output = exec::output(cmd).map_err(|e| exec::run_failed(what, e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let detail = if stderr.trim().is_empty() {
+        stdout
+    } else {
+        stderr
+    };
+    let tail: Vec<&str> = detail.trim().lines().rev().take(5).collect();
+    Err(Error::tool_failed(
+        what,
+        format!(
+            "{} failed: {}",
+            what,
+            tail.into_iter().rev().collect::<Vec<_>>().join("\n")
+        ),
+    ))
+}
+
+fn python_manager_name(pm: PythonPackageManager) -> &'static str {
+    match pm {
+        PythonPackageManager::Pip => "pip",
+        PythonPackageManager::Uv => "uv",
+        PythonPackageManager::Poetry => "poetry",
+    }
+}
+
+fn is_node_service(path: &Path) -> bool {
+    path.join("package.json").exists()
+}
+
+// npm: `name` or `@scope/name`, lowercase letters, digits and -._~, starting with a
+// letter or digit. Legacy packages may have capitals, so those pass too.
+fn valid_npm_name(name: &str) -> bool {
+    let part = |s: &str| {
+        s.chars().next().is_some_and(|c| c.is_ascii_alphanumeric())
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-._~".contains(c))
+    };
+    let valid = match name.strip_prefix('@') {
+        Some(scoped) => scoped
+            .split_once('/')
+            .is_some_and(|(scope, package)| part(scope) && part(package)),
+        None => part(name),
+    };
+    valid && name.len() <= 214
+}
+
+// PEP 508: letters, digits and -._ starting and ending with a letter or digit, with
+// optional extras such as `fastapi[standard]`
+fn valid_python_name(name: &str) -> bool {
+    let part = |s: &str| {
+        s.chars().next().is_some_and(|c| c.is_ascii_alphanumeric())
+            && s.chars().last().is_some_and(|c| c.is_ascii_alphanumeric())
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-._".contains(c))
+    };
+    match name.split_once('[') {
+        Some((base, extras)) => {
+            part(base)
+                && extras
+                    .strip_suffix(']')
+                    .is_some_and(|extras| extras.split(',').all(|e| part(e.trim())))
+        }
+        None => part(name),
+    }
+}
+
+// `1`, `1.2.3`, `1.x`, `*`, `1.2.3-beta.1+build`
+fn valid_semver(version: &str) -> bool {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let (core, tail) = version.split_once(['-', '+']).unwrap_or((version, ""));
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() <= 3
+        && parts.iter().all(|part| {
+            matches!(*part, "x" | "X" | "*")
+                || (!part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        })
+        && tail
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".-+".contains(c))
+}
+
+// A dist-tag (`latest`, `next`) or a semver range: `^1.2`, `~1.2.3`, `>=1.2 <2`,
+// `1.2.3 - 2.0.0`, `^1 || ^2`
+fn valid_npm_version(version: &str) -> bool {
+    let tag = version.starts_with(|c: char| c.is_ascii_alphabetic())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-._".contains(c));
+    tag || version.split("||").all(|set| {
+        match set.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [] => false,
+            [from, "-", to] => valid_semver(from) && valid_semver(to),
+            comparators => comparators.iter().all(|comparator| {
+                let version = ["^", "~", ">=", "<=", ">", "<", "="]
+                    .iter()
+                    .find_map(|op| comparator.strip_prefix(op))
+                    .unwrap_or(comparator);
+                valid_semver(version)
+            }),
+        }
+    })
+}
+
+// `1.2.3`, `2!1.0`, `2.0.0rc1`, `1.0.post1`, `1.0+local`, and `1.2.*` after == or !=
+fn valid_pep440(version: &str, wildcard: bool) -> bool {
+    let version = match version.strip_suffix(".*") {
+        Some(prefix) if wildcard => prefix,
+        _ => version,
+    };
+    version.starts_with(|c: char| c.is_ascii_digit())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".!+-_".contains(c))
+}
+
+// A bare version or a PEP 440 specifier set: `==1.2.*`, `~=1.2`, `>=1.2,<2`, `!=1.3`
+fn valid_python_version(version: &str) -> bool {
+    if valid_pep440(version, false) {
+        return true;
+    }
+    version.split(',').all(|clause| {
+        let clause = clause.trim();
+        ["===", "~=", "==", "!=", "<=", ">=", "<", ">"]
+            .iter()
+            .find_map(|op| clause.strip_prefix(op).map(|rest| (*op, rest.trim())))
+            .is_some_and(|(op, rest)| valid_pep440(rest, op == "==" || op == "!="))
+    })
+}
+
+// `name==1.2.3` for a bare version, `name>=1.2,<2` when it already has an operator
+fn python_requirement(name: &str, version: &str) -> String {
+    if version.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}=={}", name, version)
+    } else {
+        format!("{}{}", name, version)
+    }
+}
+
+// Names and versions end up on a package manager's command line (through cmd on
+// Windows), so anything outside the ecosystem's grammar is refused before running it
+fn check_package(name: &str, version: Option<&str>, node: bool) -> Result<(), Error> {
+    let valid = if node {
+        valid_npm_name(name)
+    } else {
+        valid_python_name(name)
+    };
+    if !valid {
+        return Err(Error::invalid(
+            "invalid_package_name",
+            format!("{} is not a valid package name", name),
+        )
+        .with("name", name));
+    }
+    let valid_version = |v: &str| {
+        v.len() <= 100
+            && if node {
+                valid_npm_version(v)
+            } else {
+                valid_python_version(v)
+            }
+    };
+    if let Some(version) = version.filter(|v| !valid_version(v)) {
+        let example = if node {
+            "1.2.3, ^1.2 or >=1.2 <2"
+        } else {
+            "1.2.3, ~=1.2 or >=1.2,<2"
+        };
+        return Err(Error::invalid(
+            "invalid_package_version",
+            format!(
+                "{} is not a supported version; use a version or range such as {}",
+                version, example
+            ),
+        )
+        .with("version", version));
+    }
+    Ok(())
+}
+
+pub fn add_dependency_blocking(
+    service_path: String,
+    name: String,
+    version: Option<String>,
+    dev: Option<bool>,
+) -> Result<DependencyChange, Error> {
+    let path = Path::new(&service_path);
+    if !path.exists() {
+        return Err(Error::path_not_found(&service_path));
+    }
+    let dev = dev.unwrap_or(false);
+    check_package(&name, version.as_deref(), is_node_service(path))?;
+
+    if is_node_service(path) {
+        let pm = node::detect_package_manager(path);
+        let spec = match &version {
+            Some(v) => format!("{}@{}", name, v),
+            None => name.clone(),
+        };
+        let mut cmd = node::manager_command(path, pm).map_err(|e| e.to_string())?;
+        cmd.arg(if pm == PackageManager::Npm {
+            "install"
+        } else {
+            "add"
+        })
+        .current_dir(path);
+        if dev {
+            cmd.arg(if pm == PackageManager::Bun {
+                "-d"
+            } else {
+                "-D"
+            });
+        }
+        cmd.args(["--", &spec]);
+        run_checked(&mut cmd, &format!("{} add", pm.program()))?;
+
+        return Ok(DependencyChange {
+            resolved_version: installed_node_version(path, &name),
+            name,
+            manager: pm.program(),
+            dev,
+        });
+    }
+
+    let pm = python::detect_python_manager(path);
+    match pm {
+        PythonPackageManager::Uv => {
+            let spec = match &version {
+                Some(v) => python_requirement(&name, v),
+                None => name.clone(),
+            };
+            let mut cmd = exec::tool_command("uv");
+            cmd.arg("add").current_dir(path);
+            if dev {
+                cmd.arg("--dev");
+            }
+            cmd.args(["--", &spec]);
+            run_checked(&mut cmd, "uv add")?;
+        }
+        PythonPackageManager::Poetry => {
+            // Poetry reads `name@1.2.3` as an exact version and takes specifiers as they are
+            let spec = match &version {
+                Some(v) if v.starts_with(|c: char| c.is_ascii_digit()) => {
+                    format!("{}@{}", name, v)
+                }
+                Some(v) => format!("{}{}", name, v),
+                None => name.clone(),
+            };
+            let mut cmd = exec::tool_command("poetry");
+            cmd.arg("add").current_dir(path);
+            if dev {
+                cmd.args(["--group", "dev"]);
+            }
+            cmd.args(["--", &spec]);
+            run_checked(&mut cmd, "poetry add")?;
+        }
+        PythonPackageManager::Pip => {
+            let python = python::venv_python(path)
+                .ok_or_else(|| Error::not_found("no_venv", "Backend has no virtual environment"))?;
+            let spec = match &version {
+                Some(v) => python_requirement(&name, v),
+                None => name.clone(),
+            };
+            let mut cmd = Command::new(python);
+            cmd.args([
+                "-m",
+                "pip",
+                "install",
+                "--disable-pip-version-check",
+                "--",
+                &spec,
+            ])
+            .current_dir(path);
+            run_checked(&mut cmd, "pip install")?;
+        }
+    }
+
+    let resolved_version = installed_python_version(path, pm, &name);
+
+    // pip has no manifest of its own, so pin the resolved version ourselves
+    if pm == PythonPackageManager::Pip {
+        let pin = match &resolved_version {
+            Some(v) => format!("{}=={}", name, v),
+            None => name.clone(),
+        };
+        write_requirement(&path.join(requirements_file(dev)), &name, Some(&pin))?;
+    }
+
+    Ok(DependencyChange {
+        name,
+        manager: python_manager_name(pm),
+        dev,
+        resolved_version,
+    })
+}
+
+#[tauri::command]
+pub async fn add_dependency(
+    app: AppHandle,
+    service_path: String,
+    name: String,
+    version: Option<String>,
+    dev: Option<bool>,
+) -> Result<DependencyChange, Error> {
+    task::blocking(move || {
+        let service_path = projects::project_dir(&app, &service_path)?;
+        add_dependency_blocking(service_path, name, version, dev)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn remove_dependency(
+    app: AppHandle,
+    service_path: String,
+    name: String,
+    dev: Option<bool>,
+) -> Result<DependencyChange, Error> {
+    task::blocking(move || {
+        let service_path = projects::project_dir(&app, &service_path)?;
+        let path = Path::new(&service_path);
+        let dev = dev.unwrap_or(false);
+        check_package(&name, None, is_node_service(path))?;
+
+        if is_node_service(path) {
+            let pm = node::detect_package_manager(path);
+            let mut cmd = node::manager_command(path, pm).map_err(|e| e.to_string())?;
+            cmd.arg(if pm == PackageManager::Npm {
+                "uninstall"
+            } else {
+                "remove"
+            })
+            .args(["--", &name])
+            .current_dir(path);
+            run_checked(&mut cmd, &format!("{} remove", pm.program()))?;
+
+            return Ok(DependencyChange {
+                name,
+                manager: pm.program(),
+                dev,
+                resolved_version: None,
+            });
+        }
+
+        let pm = python::detect_python_manager(path);
+        match pm {
+            PythonPackageManager::Uv => {
+                let mut cmd = exec::tool_command("uv");
+                cmd.arg("remove").current_dir(path);
+                if dev {
+                    cmd.arg("--dev");
+                }
+                cmd.args(["--", &name]);
+                run_checked(&mut cmd, "uv remove")?;
+            }
+            PythonPackageManager::Poetry => {
+                let mut cmd = exec::tool_command("poetry");
+                cmd.arg("remove").current_dir(path);
+                if dev {
+                    cmd.args(["--group", "dev"]);
+                }
+                cmd.args(["--", &name]);
+                run_checked(&mut cmd, "poetry remove")?;
+            }
+            PythonPackageManager::Pip => {
+                if let Some(python) = python::venv_python(path) {
+                    let mut cmd = Command::new(python);
+                    cmd.args(["-m", "pip", "uninstall", "-y", "--", &name])
+                        .current_dir(path);
+                    run_checked(&mut cmd, "pip uninstall")?;
+                }
+                write_requirement(&path.join(requirements_file(dev)), &name, None)?;
+            }
+        }
+
+        Ok(DependencyChange {
+            name,
+            manager: python_manager_name(pm),
+            dev,
+            resolved_version: None,
+        })
+    })
+    .await
+}
+
+fn requirements_file(dev: bool) -> &'static str {
+    if dev {
+        "requirements-dev.txt"
+    } else {
+        "requirements.txt"
+    }
+}
+
+// Replaces (or removes, when `line` is None) the entry for `name`, appending if absent
+fn write_requirement(file: &Path, name: &str, line: Option<&str>) -> Result<(), Error> {
+    let content = fs::read_to_string(file).unwrap_or_default();
+    let target = python::normalize_name(name);
+    let mut replaced = false;
+
+    let mut lines: Vec<String> = Vec::new();
+    for existing in content.lines() {
+        if python::requirement_name(existing).as_deref() == Some(target.as_str()) {
+            if let Some(line) = line {
+                if !replaced {
+                    lines.push(line.to_string());
+                }
+            }
+            replaced = true;
+        } else {
+            lines.push(existing.to_string());
+        }
+    }
+
+    match (line, replaced) {
+        (Some(line), false) => lines.push(line.to_string()),
+        (None, false) => return Ok(()),
+        _ => {}
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    fs::write(file, output)?;
+    Ok(())
+}
+
+pub fn installed_node_version(path: &Path, package: &str) -> Option<String> {
+    let manifest = path.join("node_modules").join(package).join("package.json");
+    let content = fs::read_to_string(manifest).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    json["version"].as_str().map(String::from)
+}
+
+fn installed_python_version(
+    path: &Path,
+    pm: PythonPackageManager,
+    package: &str,
+) -> Option<String> {
+    const SCRIPT: &str = "import importlib.metadata as m, sys; print(m.version(sys.argv[1]))";
+
+    // Poetry keeps its environment outside the project, so ask it to run the probe
+    let mut cmd = if pm == PythonPackageManager::Poetry {
+        let mut cmd = exec::tool_command("poetry");
+        cmd.args(["run", "python"]);
+        cmd
+    } else {
+        Command::new(python::venv_python(path)?)
+    };
+    cmd.args(["-c", SCRIPT, package]).current_dir(path);
+
+    let output = exec::output(&mut cmd).ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+#[derive(serde::Serialize)]
+pub struct DriftIssue {
+    service: &'static str,
+    kind: &'static str,
+    message: String,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn older(a: &Path, b: &Path) -> bool {
+    matches!((modified(a), modified(b)), (Some(a), Some(b)) if a < b)
+}
+
+pub fn lockfile_drift(service_path: &Path) -> Vec<DriftIssue> {
+    if is_node_service(service_path) {
+        node_drift(service_path)
+    } else {
+        python_drift(service_path)
+    }
+}
+
+fn node_drift(path: &Path) -> Vec<DriftIssue> {
+    let mut issues = Vec::new();
+    let pm = node::detect_package_manager(path);
+    let manifest = path.join("package.json");
+    let lockfile = path.join(pm.lockfile());
+    let node_modules = path.join("node_modules");
+
+    if !lockfile.exists() {
+        issues.push(DriftIssue {
+            service: "frontend",
+            kind: "lockfile_missing",
+            message: format!("No {} yet; run {} install", pm.lockfile(), pm.program()),
+        });
+    } else if older(&lockfile, &manifest) {
+        issues.push(DriftIssue {
+            service: "frontend",
+            kind: "lockfile_outdated",
+            message: format!("{} is older than package.json", pm.lockfile()),
+        });
+    }
+
+    // Each manager leaves a marker inside node_modules recording the last install
+    let marker = match pm {
+        PackageManager::Npm => node_modules.join(".package-lock.json"),
+        PackageManager::Pnpm => node_modules.join(".modules.yaml"),
+        PackageManager::Yarn => node_modules.join(".yarn-integrity"),
+        PackageManager::Bun => node_modules.clone(),
+    };
+
+    if !node_modules.exists() {
+        issues.push(DriftIssue {
+            service: "frontend",
+            kind: "not_installed",
+            message: "Dependencies are not installed (node_modules is missing)".to_string(),
+        });
+    } else if older(&marker, &lockfile) || older(&marker, &manifest) {
+        issues.push(DriftIssue {
+            service: "frontend",
+            kind: "install_outdated",
+            message: "node_modules is older than the manifest or lockfile; reinstall dependencies"
+                .to_string(),
+        });
+    }
+
+    issues
+}
+
+fn python_drift(path: &Path) -> Vec<DriftIssue> {
+    let mut issues = Vec::new();
+    let pyproject = path.join("pyproject.toml");
+
+    let lockfile = match python::detect_python_manager(path) {
+        PythonPackageManager::Uv => Some(path.join("uv.lock")),
+        PythonPackageManager::Poetry => Some(path.join("poetry.lock")),
+        PythonPackageManager::Pip => None,
+    };
+    if let Some(lockfile) = lockfile {
+        let name = lockfile
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if !lockfile.exists() {
+            issues.push(DriftIssue {
+                service: "backend",
+                kind: "lockfile_missing",
+                message: format!("No {} yet; lock the project dependencies", name),
+            });
+        } else if older(&lockfile, &pyproject) {
+            issues.push(DriftIssue {
+                service: "backend",
+                kind: "lockfile_outdated",
+                message: format!("{} is older than pyproject.toml", name),
+            });
+        }
+    }
+
+    if python::venv_python(path).is_none() {
+        issues.push(DriftIssue {
+            service: "backend",
+            kind: "not_installed",
+            message: "No virtual environment found; create one and install dependencies"
+                .to_string(),
+        });
+        return issues;
+    }
+
+    let installed = python::installed_distributions(path);
+    let missing: Vec<String> = python::declared_requirements(path)
+        .iter()
+        .filter_map(|r| python::requirement_name(r))
+        .filter(|name| !installed.contains_key(name))
+        .collect();
+    if !missing.is_empty() {
+        issues.push(DriftIssue {
+            service: "backend",
+            kind: "not_installed",
+            message: format!("Declared but not installed: {}", missing.join(", ")),
+        });
+    }
+
+    issues
+}
+
+#[tauri::command]
+pub async fn check_lockfile_drift(service_path: String) -> Result<Vec<DriftIssue>, Error> {
+    task::blocking(move || {
+        let service_path = paths::existing_dir(&service_path)?;
+        let path = Path::new(&service_path);
+        Ok(lockfile_drift(path))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_npm_versions_and_ranges() {
+        for version in [
+            "1.2.3",
+            "v1.2.3",
+            "1",
+            "1.x",
+            "*",
+            "^1.2",
+            "~1.2.3",
+            ">=1.2 <2",
+            ">=1.2.0-rc.1",
+            "1.2.3 - 2.0.0",
+            "^1 || ^2.1",
+            "2.0.0-beta.1+build.5",
+            "latest",
+            "next",
+        ] {
+            assert!(valid_npm_version(version), "{}", version);
+        }
+        for version in [
+            "",
+            "^",
+            "~>1.2",
+            "1.2.3.4",
+            "1.2; rm -rf /",
+            "$(id)",
+            "1 ||",
+            "!1",
+        ] {
+            assert!(!valid_npm_version(version), "{}", version);
+        }
+    }
+
+    #[test]
+    fn accepts_pep440_versions_and_specifiers() {
+        for version in [
+            "1.2.3",
+            "2.0.0rc1",
+            "1.0.post1",
+            "1!2.0",
+            "1.0+local.1",
+            "==1.2.*",
+            "~=1.2",
+            ">=1.2,<2",
+            ">= 1.2, != 1.3.*",
+            "===1.0",
+        ] {
+            assert!(valid_python_version(version), "{}", version);
+        }
+        for version in [
+            "", "~1.2", "^1.2", "*", "!", "1.2.*", ">=1.*", "latest", ">=1.2;rm", ">=1.2,",
+        ] {
+            assert!(!valid_python_version(version), "{}", version);
+        }
+    }
+
+    #[test]
+    fn pins_only_bare_python_versions() {
+        assert_eq!(python_requirement("fastapi", "0.110.0"), "fastapi==0.110.0");
+        assert_eq!(python_requirement("fastapi", "~=0.110"), "fastapi~=0.110");
+        assert_eq!(
+            python_requirement("fastapi", ">=0.100,<1"),
+            "fastapi>=0.100,<1"
+        );
+    }
+}
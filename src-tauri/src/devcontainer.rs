@@ -0,0 +1,209 @@
+use serde_json::json;
+use std::fs;
+use std::path::Path;
+
+use crate::deps;
+use crate::error::{Error, ErrorKind};
+use crate::node::{self, PackageManager};
+use crate::paths;
+use crate::python::{self, PythonPackageManager};
+use crate::task;
+use crate::toolchain;
+
+const BASE_IMAGE: &str = "mcr.microsoft.com/devcontainers/base:bookworm";
+const NODE_FEATURE: &str = "ghcr.io/devcontainers/features/node:1";
+const PYTHON_FEATURE: &str = "ghcr.io/devcontainers/features/python:1";
+const DEFAULT_PYTHON: &str = "3.12";
+
+pub struct Frontend {
+    // Relative to the project root; "." when the project is the frontend
+    pub dir: String,
+    pub port: Option<u16>,
+    // A version requirement as written in package.json or .nvmrc
+    pub node: Option<String>,
+    pub manager: PackageManager,
+}
+
+pub struct Backend {
+    pub dir: String,
+    pub port: Option<u16>,
+    pub python: Option<String>,
+    pub manager: PythonPackageManager,
+}
+
+// ">=18.17" -> "18"; the node feature takes a major version or "lts"
+fn node_version(requirement: Option<&str>) -> String {
+    requirement
+        .and_then(toolchain::parse_version)
+        .and_then(|v| v.split('.').next().map(String::from))
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| "lts".to_string())
+}
+
+// ">=3.11,<4" -> "3.11"
+fn python_version(requirement: Option<&str>) -> String {
+    requirement
+        .and_then(toolchain::parse_version)
+        .map(|v| v.split('.').take(2).collect::<Vec<_>>().join("."))
+        .filter(|v| v.contains('.'))
+        .unwrap_or_else(|| DEFAULT_PYTHON.to_string())
+}
+
+fn in_dir(dir: &str, command: &str) -> String {
+    if dir == "." {
+        command.to_string()
+    } else {
+        format!("cd {} && {}", dir, command)
+    }
+}
+
+fn node_install(manager: PackageManager) -> &'static str {
+    match manager {
+        PackageManager::Npm => "npm install",
+        PackageManager::Pnpm => "corepack enable && pnpm install",
+        PackageManager::Yarn => "corepack enable && yarn install",
+        PackageManager::Bun => "npm install -g bun && bun install",
+    }
+}
+
+// The python feature installs pipx, which keeps uv and poetry out of the project venv
+fn python_install(manager: PythonPackageManager) -> &'static str {
+    match manager {
+        PythonPackageManager::Uv => "pipx install uv && uv sync",
+        PythonPackageManager::Poetry => {
+            "pipx install poetry && poetry config virtualenvs.in-project true && poetry install"
+        }
+        PythonPackageManager::Pip => {
+            "python -m venv .venv && .venv/bin/pip install -r requirements.txt"
+        }
+    }
+}
+
+pub fn render(name: &str, frontend: Option<&Frontend>, backend: Option<&Backend>) -> String {
+    let mut features = serde_json::Map::new();
+    let mut ports = Vec::new();
+    let mut attributes = serde_json::Map::new();
+    let mut post_create = serde_json::Map::new();
+    let mut extensions = Vec::new();
+    let mut settings = serde_json::Map::new();
+
+    if let Some(frontend) = frontend {
+        features.insert(
+            NODE_FEATURE.to_string(),
+            json!({ "version": node_version(frontend.node.as_deref()) }),
+        );
+        if let Some(port) = frontend.port {
+            ports.push(port);
+            attributes.insert(
+                port.to_string(),
+                json!({ "label": "frontend", "onAutoForward": "openBrowser" }),
+            );
+        }
+        post_create.insert(
+            "frontend".to_string(),
+            json!(in_dir(&frontend.dir, node_install(frontend.manager))),
+        );
+        extensions.extend(["dbaeumer.vscode-eslint", "esbenp.prettier-vscode"]);
+    }
+    if let Some(backend) = backend {
+        features.insert(
+            PYTHON_FEATURE.to_string(),
+            json!({ "version": python_version(backend.python.as_deref()) }),
+        );
+        if let Some(port) = backend.port {
+            ports.push(port);
+            attributes.insert(
+                port.to_string(),
+                json!({ "label": "backend", "onAutoForward": "notify" }),
+            );
+        }
+        post_create.insert(
+            "backend".to_string(),
+            json!(in_dir(&backend.dir, python_install(backend.manager))),
+        );
+        extensions.extend([
+            "ms-python.python",
+            "ms-python.vscode-pylance",
+            "charliermarsh.ruff",
+        ]);
+        settings.insert(
+            "python.defaultInterpreterPath".to_string(),
+            json!(format!(
+                "${{containerWorkspaceFolder}}/{}/.venv/bin/python",
+                backend.dir
+            )
+            .replace("/./", "/")),
+        );
+    }
+
+    let config = json!({
+        "name": name,
+        "image": BASE_IMAGE,
+        "features": features,
+        "forwardPorts": ports,
+        "portsAttributes": attributes,
+        "postCreateCommand": post_create,
+        "customizations": {
+            "vscode": {
+                "extensions": extensions,
+                "settings": settings,
+            }
+        },
+    });
+    let mut content = serde_json::to_string_pretty(&config).unwrap_or_default();
+    content.push('\n');
+    content
+}
+
+#[tauri::command]
+pub async fn generate_devcontainer(
+    project_path: String,
+    overwrite: Option<bool>,
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let target = root.join(".devcontainer").join("devcontainer.json");
+        if target.exists() && !overwrite.unwrap_or(false) {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                "devcontainer_exists",
+                ".devcontainer/devcontainer.json already exists",
+            )
+            .with("path", target.to_string_lossy()));
+        }
+
+        let project = crate::detect_project_blocking(project_path.clone())?;
+        let (frontend_dir, backend_dir) = deps::service_dirs(root);
+        let dir = |path: &Path| {
+            Some(paths::relative(root, &path.to_string_lossy()))
+                .filter(|d| !d.is_empty())
+                .unwrap_or_else(|| ".".to_string())
+        };
+        let frontend = frontend_dir.map(|path| Frontend {
+            dir: dir(&path),
+            port: project.frontend_port,
+            node: toolchain::node_requirement(&path),
+            manager: node::detect_package_manager(&path),
+        });
+        let backend = backend_dir.map(|path| Backend {
+            dir: dir(&path),
+            port: project.backend_port,
+            python: toolchain::python_requirement(&path),
+            manager: python::detect_python_manager(&path),
+        });
+        if frontend.is_none() && backend.is_none() {
+            return Err(Error::not_found(
+                "no_services",
+                "The project has neither a frontend nor a backend",
+            ));
+        }
+
+        let content = render(&project.project_name, frontend.as_ref(), backend.as_ref());
+        fs::create_dir_all(root.join(".devcontainer"))?;
+        fs::write(&target, content)?;
+        tracing::info!(%project_path, "wrote devcontainer.json");
+        Ok(target.to_string_lossy().into_owned())
+    })
+    .await
+}
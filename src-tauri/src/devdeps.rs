@@ -0,0 +1,423 @@
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::fs;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+use crate::deps;
+use crate::docker::{self, Engine};
+use crate::error::{Error, ErrorKind};
+use crate::exec;
+use crate::images;
+use crate::paths;
+use crate::task;
+use crate::versioned::{self, Migration};
+use crate::ProcessManager;
+
+const DEPENDENCY_DIR: &str = "dev-dependencies";
+// Credentials and port of a dependency, kept next to its data so a restart reuses them
+const SAVED_FILE: &str = "devllm.json";
+const SAVED_MIGRATIONS: &[Migration] = &[versioned::initial];
+const DB_USER: &str = "app";
+const READY_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Postgres,
+    Redis,
+    Minio,
+}
+
+impl Kind {
+    fn parse(kind: &str) -> Result<Kind, Error> {
+        match kind {
+            "postgres" => Ok(Kind::Postgres),
+            "redis" => Ok(Kind::Redis),
+            "minio" => Ok(Kind::Minio),
+            _ => Err(Error::invalid(
+                "unsupported_dependency",
+                format!("Unsupported dev dependency: {}", kind),
+            )
+            .with("kind", kind)),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Postgres => "postgres",
+            Kind::Redis => "redis",
+            Kind::Minio => "minio",
+        }
+    }
+
+    fn image(self) -> &'static str {
+        match self {
+            Kind::Postgres => "postgres:16-alpine",
+            Kind::Redis => "redis:7-alpine",
+            Kind::Minio => "minio/minio:latest",
+        }
+    }
+
+    fn container_port(self) -> u16 {
+        match self {
+            Kind::Postgres => 5432,
+            Kind::Redis => 6379,
+            Kind::Minio => 9000,
+        }
+    }
+
+    fn data_path(self) -> &'static str {
+        match self {
+            Kind::Postgres => "/var/lib/postgresql/data",
+            Kind::Redis | Kind::Minio => "/data",
+        }
+    }
+
+    fn container_env(self, password: &str) -> Vec<(&'static str, String)> {
+        match self {
+            Kind::Postgres => vec![
+                ("POSTGRES_USER", DB_USER.to_string()),
+                ("POSTGRES_PASSWORD", password.to_string()),
+                ("POSTGRES_DB", DB_USER.to_string()),
+                // A subdirectory, since initdb refuses a mount point that isn't empty
+                ("PGDATA", "/var/lib/postgresql/data/pgdata".to_string()),
+            ],
+            Kind::Redis => Vec::new(),
+            Kind::Minio => vec![
+                ("MINIO_ROOT_USER", DB_USER.to_string()),
+                ("MINIO_ROOT_PASSWORD", password.to_string()),
+            ],
+        }
+    }
+
+    fn args(self) -> &'static [&'static str] {
+        match self {
+            Kind::Postgres => &[],
+            Kind::Redis => &["redis-server", "--appendonly", "yes"],
+            Kind::Minio => &["server", "/data"],
+        }
+    }
+
+    // What the project's .env gets to reach the dependency
+    fn project_env(self, port: u16, password: &str) -> Vec<(&'static str, String)> {
+        match self {
+            Kind::Postgres => vec![(
+                "DATABASE_URL",
+                format!(
+                    "postgresql://{}:{}@localhost:{}/{}",
+                    DB_USER, password, port, DB_USER
+                ),
+            )],
+            Kind::Redis => vec![("REDIS_URL", format!("redis://localhost:{}/0", port))],
+            Kind::Minio => vec![
+                ("S3_ENDPOINT_URL", format!("http://localhost:{}", port)),
+                ("AWS_ACCESS_KEY_ID", DB_USER.to_string()),
+                ("AWS_SECRET_ACCESS_KEY", password.to_string()),
+            ],
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Saved {
+    port: u16,
+    password: String,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct DevDependency {
+    key: String,
+    pub kind: &'static str,
+    pub container: String,
+    pub project_path: String,
+    port: u16,
+    // The main connection variable written to .env, e.g. DATABASE_URL
+    url: String,
+    env_file: String,
+    data_dir: String,
+    ready: bool,
+}
+
+pub fn key(project_path: &str, kind: &str) -> String {
+    format!("{}:dependency:{}", paths::key(project_path), kind)
+}
+
+// Stable per project, so a restart finds the container and data it used before
+fn container_name(project_path: &str, kind: Kind) -> String {
+    let mut hasher = DefaultHasher::new();
+    paths::key(project_path).hash(&mut hasher);
+    format!("devllm-{}-{:08x}", kind.name(), hasher.finish() as u32)
+}
+
+// Dev-only credentials; not cryptographic, but different for every dependency
+fn new_password() -> String {
+    let random = RandomState::new();
+    format!("{:016x}{:016x}", random.hash_one(1u8), random.hash_one(2u8))
+}
+
+fn free_port() -> Result<u16, String> {
+    TcpListener::bind(("127.0.0.1", 0))
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| format!("Failed to find a free port: {}", e))
+}
+
+fn port_free(port: u16) -> bool {
+    TcpListener::bind(("127.0.0.1", port)).is_ok()
+}
+
+// Sets each variable, commenting out the value it replaces so it's easy to switch back
+pub fn set_env_vars(env_path: &Path, vars: &[(&str, String)]) -> Result<(), String> {
+    let content = fs::read_to_string(env_path).unwrap_or_default();
+    let mut lines: Vec<String> = Vec::new();
+    let mut replaced = vec![false; vars.len()];
+    for line in content.lines() {
+        let name = line.trim_start().split('=').next().unwrap_or("").trim();
+        match vars.iter().position(|(key, _)| *key == name) {
+            Some(i) if !replaced[i] => {
+                let (key, value) = &vars[i];
+                if line.trim() != format!("{}={}", key, value) {
+                    lines.push(format!("# {}", line.trim()));
+                }
+                lines.push(format!("{}={}", key, value));
+                replaced[i] = true;
+            }
+            _ => lines.push(line.to_string()),
+        }
+    }
+    for ((key, value), replaced) in vars.iter().zip(replaced) {
+        if !replaced {
+            lines.push(format!("{}={}", key, value));
+        }
+    }
+    fs::write(env_path, lines.join("\n") + "\n")
+        .map_err(|e| format!("Failed to write {}: {}", env_path.display(), e))
+}
+
+fn remove_container(engine: Engine, name: &str) {
+    let mut cmd = engine.command();
+    cmd.args(["rm", "-f", name]);
+    let _ = exec::output(&mut cmd);
+}
+
+pub fn is_running(engine: Engine, name: &str) -> bool {
+    let mut cmd = engine.command();
+    cmd.args(["inspect", "--format", "{{.State.Running}}", name]);
+    exec::output(&mut cmd)
+        .is_ok_and(|o| o.status.success() && String::from_utf8_lossy(&o.stdout).trim() == "true")
+}
+
+// The engine publishes the port before the server inside listens, so this only
+// narrows the window; Postgres is asked directly
+fn wait_ready(engine: Engine, kind: Kind, name: &str, port: u16) -> bool {
+    let started = Instant::now();
+    while started.elapsed() < READY_TIMEOUT {
+        let listening =
+            TcpStream::connect_timeout(&([127, 0, 0, 1], port).into(), Duration::from_secs(1))
+                .is_ok();
+        let ready = listening
+            && match kind {
+                Kind::Postgres => {
+                    let mut cmd = engine.command();
+                    cmd.args(["exec", name, "pg_isready", "-U", DB_USER]);
+                    exec::output(&mut cmd).is_ok_and(|o| o.status.success())
+                }
+                Kind::Redis | Kind::Minio => true,
+            };
+        if ready {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(500));
+    }
+    false
+}
+
+fn stop_container(engine: Engine, name: &str) -> Result<(), Error> {
+    let mut cmd = engine.command();
+    cmd.args(["stop", "--time", "5", name]);
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed(engine.program(), e))?;
+    if !output.status.success() {
+        return Err(Error::tool_failed(
+            engine.program(),
+            format!(
+                "Stopping {} failed: {}",
+                name,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    remove_container(engine, name);
+    Ok(())
+}
+
+// Stops every dependency started this session; data stays on disk
+pub fn stop_all(state: &ProcessManager) {
+    let dependencies: Vec<DevDependency> =
+        state.dependencies.lock().drain().map(|(_, d)| d).collect();
+    if dependencies.is_empty() {
+        return;
+    }
+    let Some(engine) = docker::engine() else {
+        return;
+    };
+    for dependency in dependencies {
+        if let Err(e) = stop_container(engine, &dependency.container) {
+            tracing::warn!(container = %dependency.container, "{}", e);
+        }
+    }
+}
+
+fn data_root(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(DEPENDENCY_DIR))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn start_dev_dependency(
+    app: AppHandle,
+    project_path: String,
+    kind: String,
+) -> Result<DevDependency, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let kind = Kind::parse(&kind)?;
+        let key = key(&project_path, kind.name());
+        let name = container_name(&project_path, kind);
+        let engine = docker::require()?;
+
+        let dir = data_root(&app)?.join(&name);
+        let data_dir = dir.join("data");
+        fs::create_dir_all(&data_dir)?;
+        let saved_path = dir.join(SAVED_FILE);
+        let saved: Option<Saved> =
+            match versioned::load(&saved_path, SAVED_MIGRATIONS, "saved dependency settings") {
+                Ok(saved) => saved,
+                Err(e) => {
+                    tracing::warn!(container = %name, "{}", e);
+                    None
+                }
+            };
+
+        if is_running(engine, &name) {
+            return Err(Error::new(
+                ErrorKind::Conflict,
+                "dependency_running",
+                format!("{} is already running for this project", kind.name()),
+            )
+            .with("container", &name));
+        }
+        // A stopped container from an earlier session is replaced; its data isn't
+        remove_container(engine, &name);
+
+        let password = saved
+            .as_ref()
+            .map(|s| s.password.clone())
+            .unwrap_or_else(new_password);
+        let port = match saved.as_ref().map(|s| s.port).filter(|p| port_free(*p)) {
+            Some(port) => port,
+            None => free_port()?,
+        };
+        let saved = Saved {
+            port,
+            password: password.clone(),
+        };
+        versioned::save(
+            &saved_path,
+            &saved,
+            SAVED_MIGRATIONS,
+            "saved dependency settings",
+        )?;
+
+        let mut cmd = engine.command();
+        cmd.args(["run", "-d", "--name", &name])
+            .arg("--label")
+            .arg(format!(
+                "{}={}",
+                images::PROJECT_LABEL,
+                paths::key(&project_path)
+            ))
+            .arg("--label")
+            .arg(format!("devllm.dependency={}", kind.name()))
+            .arg("-p")
+            .arg(format!("127.0.0.1:{}:{}", port, kind.container_port()))
+            .arg("-v")
+            .arg(format!("{}:{}", data_dir.display(), kind.data_path()));
+        for (var, value) in kind.container_env(&password) {
+            cmd.arg("-e").arg(format!("{}={}", var, value));
+        }
+        cmd.arg(kind.image()).args(kind.args());
+        let output = exec::output(&mut cmd).map_err(|e| exec::run_failed(engine.program(), e))?;
+        if !output.status.success() {
+            return Err(Error::tool_failed(
+                engine.program(),
+                format!(
+                    "Starting {} failed: {}",
+                    kind.name(),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            )
+            .with("image", kind.image()));
+        }
+
+        let ready = wait_ready(engine, kind, &name, port);
+        let env = kind.project_env(port, &password);
+        let (_, backend) = deps::service_dirs(Path::new(&project_path));
+        let env_path = backend
+            .unwrap_or_else(|| PathBuf::from(&project_path))
+            .join(".env");
+        set_env_vars(&env_path, &env)?;
+
+        let dependency = DevDependency {
+            key: key.clone(),
+            kind: kind.name(),
+            container: name,
+            project_path: project_path.clone(),
+            port,
+            url: env[0].1.clone(),
+            env_file: env_path.to_string_lossy().into_owned(),
+            data_dir: data_dir.to_string_lossy().into_owned(),
+            ready,
+        };
+        app.state::<ProcessManager>()
+            .dependencies
+            .lock()
+            .insert(key, dependency.clone());
+        tracing::info!(%project_path, kind = kind.name(), port, ready, "started dev dependency");
+        Ok(dependency)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn stop_dev_dependency(
+    app: AppHandle,
+    project_path: String,
+    kind: String,
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let kind = Kind::parse(&kind)?;
+        let name = container_name(&project_path, kind);
+        let engine = docker::require()?;
+        if !is_running(engine, &name) {
+            return Err(Error::new(
+                ErrorKind::Conflict,
+                "dependency_not_running",
+                format!("{} is not running", kind.name()),
+            )
+            .with("container", &name));
+        }
+        stop_container(engine, &name)?;
+        app.state::<ProcessManager>()
+            .dependencies
+            .lock()
+            .remove(&key(&project_path, kind.name()));
+        Ok(format!("{} stopped", kind.name()))
+    })
+    .await
+}
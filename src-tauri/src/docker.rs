@@ -0,0 +1,170 @@
+use std::process::Command;
+
+use crate::error::{Error, ErrorKind};
+use crate::exec;
+use crate::task;
+use crate::toolchain;
+
+const NOT_INSTALLED: &str = "Docker is not installed (neither docker nor podman is on PATH)";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Engine {
+    Docker,
+    Podman,
+}
+
+impl Engine {
+    pub fn program(self) -> &'static str {
+        match self {
+            Engine::Docker => "docker",
+            Engine::Podman => "podman",
+        }
+    }
+
+    // Both CLIs ship as real executables, so no cmd shim is needed on Windows
+    pub fn command(self) -> Command {
+        Command::new(self.program())
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct DockerStatus {
+    installed: bool,
+    running: bool,
+    engine: Option<&'static str>,
+    path: Option<String>,
+    client_version: Option<String>,
+    server_version: Option<String>,
+    compose_version: Option<String>,
+    // Why Docker features are unavailable, when they are
+    message: Option<String>,
+}
+
+// Docker when both are installed, since that's what compose files are written for
+pub fn engine() -> Option<Engine> {
+    [Engine::Docker, Engine::Podman]
+        .into_iter()
+        .find(|e| !toolchain::which_all(e.program()).is_empty())
+}
+
+fn compose_version(engine: Engine) -> Option<String> {
+    let mut plugin = engine.command();
+    plugin.args(["compose", "version", "--short"]);
+    let mut standalone = Command::new("docker-compose");
+    standalone.args(["version", "--short"]);
+
+    [plugin, standalone].into_iter().find_map(|mut cmd| {
+        let output = exec::output(&mut cmd).ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let version = stdout.lines().last()?.trim().trim_start_matches('v');
+        (output.status.success() && !version.is_empty()).then(|| version.to_string())
+    })
+}
+
+struct Probe {
+    client_version: Option<String>,
+    server_version: Option<String>,
+    error: Option<String>,
+}
+
+// Prints the client half even when the daemon is unreachable, then exits non-zero
+fn probe(engine: Engine) -> Probe {
+    let mut cmd = engine.command();
+    cmd.args(["version", "--format", "{{json .}}"]);
+    let output = exec::output(&mut cmd);
+    let json: serde_json::Value = output
+        .as_ref()
+        .ok()
+        .and_then(|o| serde_json::from_slice(&o.stdout).ok())
+        .unwrap_or_default();
+    let version = |side: &str| json[side]["Version"].as_str().map(String::from);
+    // Podman on Linux is daemonless: a successful call means the engine works
+    let succeeded = output.as_ref().is_ok_and(|o| o.status.success());
+    let server_version = match engine {
+        Engine::Podman if succeeded => version("Server").or_else(|| version("Client")),
+        _ => version("Server"),
+    };
+    Probe {
+        client_version: version("Client"),
+        server_version,
+        error: match &output {
+            Ok(o) => Some(String::from_utf8_lossy(&o.stderr).trim().to_string())
+                .filter(|e| !e.is_empty()),
+            Err(e) => Some(e.to_string()),
+        },
+    }
+}
+
+fn not_running(engine: Engine, error: Option<&str>) -> String {
+    let hint = match engine {
+        Engine::Docker => "start Docker Desktop or the docker service",
+        Engine::Podman => "start it with `podman machine start`",
+    };
+    match error {
+        Some(error) => format!(
+            "{} is installed but not running; {} ({})",
+            engine.program(),
+            hint,
+            error
+        ),
+        None => format!(
+            "{} is installed but not running; {}",
+            engine.program(),
+            hint
+        ),
+    }
+}
+
+pub fn status() -> DockerStatus {
+    let Some(engine) = engine() else {
+        return DockerStatus {
+            installed: false,
+            running: false,
+            engine: None,
+            path: None,
+            client_version: None,
+            server_version: None,
+            compose_version: None,
+            message: Some(NOT_INSTALLED.to_string()),
+        };
+    };
+
+    let probe = probe(engine);
+    let running = probe.server_version.is_some();
+    DockerStatus {
+        installed: true,
+        running,
+        engine: Some(engine.program()),
+        path: toolchain::which_all(engine.program())
+            .first()
+            .map(|p| p.to_string_lossy().into_owned()),
+        client_version: probe.client_version,
+        server_version: probe.server_version,
+        compose_version: if running {
+            compose_version(engine)
+        } else {
+            None
+        },
+        message: (!running).then(|| not_running(engine, probe.error.as_deref())),
+    }
+}
+
+// For Docker-dependent commands: the engine to use, or an error saying what's missing
+pub fn require() -> Result<Engine, Error> {
+    let engine = engine().ok_or_else(|| Error::not_found("docker_not_installed", NOT_INSTALLED))?;
+    let probe = probe(engine);
+    if probe.server_version.is_none() {
+        return Err(Error::new(
+            ErrorKind::Process,
+            "docker_not_running",
+            not_running(engine, probe.error.as_deref()),
+        )
+        .with("engine", engine.program()));
+    }
+    Ok(engine)
+}
+
+#[tauri::command]
+pub async fn detect_docker() -> Result<DockerStatus, Error> {
+    task::blocking(|| Ok(status())).await
+}
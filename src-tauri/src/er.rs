@@ -0,0 +1,179 @@
+use std::path::Path;
+
+use crate::db::{self, TableSchema};
+use crate::error::Error;
+use crate::paths;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct ErColumn {
+    name: String,
+    data_type: String,
+    nullable: bool,
+    primary_key: bool,
+    foreign_key: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct ErTable {
+    name: String,
+    columns: Vec<ErColumn>,
+}
+
+#[derive(serde::Serialize)]
+pub struct ErRelation {
+    from_table: String,
+    from_column: String,
+    to_table: String,
+    to_column: String,
+    // "many-to-one", or "one-to-one" when the referencing column is itself unique
+    cardinality: &'static str,
+    optional: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct ErDiagram {
+    tables: Vec<ErTable>,
+    relations: Vec<ErRelation>,
+    // Mermaid erDiagram source, ready to paste into a README
+    mermaid: String,
+}
+
+fn is_unique(schema: &TableSchema, column: &str) -> bool {
+    let single_pk = schema.columns.iter().filter(|c| c.primary_key).count() == 1;
+    schema
+        .columns
+        .iter()
+        .any(|c| c.name == column && c.primary_key && single_pk)
+        || schema
+            .indexes
+            .iter()
+            .any(|i| i.unique && i.columns.len() == 1 && i.columns[0] == column)
+}
+
+// Mermaid identifiers and types can't contain spaces or most punctuation
+fn mermaid_word(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "_-()".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn mermaid(tables: &[ErTable], relations: &[ErRelation]) -> String {
+    let mut out = String::from("erDiagram\n");
+    for table in tables {
+        out.push_str(&format!("    {} {{\n", mermaid_word(&table.name)));
+        for column in &table.columns {
+            let mut keys = Vec::new();
+            if column.primary_key {
+                keys.push("PK");
+            }
+            if column.foreign_key {
+                keys.push("FK");
+            }
+            let data_type = if column.data_type.is_empty() {
+                "TEXT".to_string()
+            } else {
+                mermaid_word(&column.data_type)
+            };
+            out.push_str(&format!(
+                "        {} {}{}\n",
+                data_type,
+                mermaid_word(&column.name),
+                if keys.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", keys.join(","))
+                }
+            ));
+        }
+        out.push_str("    }\n");
+    }
+    for relation in relations {
+        // The referencing side is "many" (or one), the referenced side is exactly one
+        // unless the foreign key is nullable
+        let left = if relation.cardinality == "one-to-one" {
+            "|o"
+        } else {
+            "}o"
+        };
+        let right = if relation.optional { "o|" } else { "||" };
+        out.push_str(&format!(
+            "    {} {}--{} {} : \"{}\"\n",
+            mermaid_word(&relation.from_table),
+            left,
+            right,
+            mermaid_word(&relation.to_table),
+            relation.from_column
+        ));
+    }
+    out
+}
+
+#[tauri::command]
+pub async fn get_er_diagram(project_path: String) -> Result<ErDiagram, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let (backend, url) = db::database_url(Path::new(&project_path));
+        let schemas = db::introspect(&backend, &url)?;
+
+        let mut relations = Vec::new();
+        for schema in &schemas {
+            for fk in &schema.foreign_keys {
+                let optional = schema
+                    .columns
+                    .iter()
+                    .find(|c| c.name == fk.column)
+                    .is_some_and(|c| c.nullable);
+                relations.push(ErRelation {
+                    from_table: schema.name.clone(),
+                    from_column: fk.column.clone(),
+                    to_table: fk.references_table.clone(),
+                    to_column: fk
+                        .references_column
+                        .clone()
+                        .unwrap_or_else(|| "id".to_string()),
+                    cardinality: if is_unique(schema, &fk.column) {
+                        "one-to-one"
+                    } else {
+                        "many-to-one"
+                    },
+                    optional,
+                });
+            }
+        }
+
+        let tables: Vec<ErTable> = schemas
+            .iter()
+            // Migration bookkeeping isn't part of the data model
+            .filter(|s| s.name != "alembic_version")
+            .map(|schema| ErTable {
+                name: schema.name.clone(),
+                columns: schema
+                    .columns
+                    .iter()
+                    .map(|c| ErColumn {
+                        name: c.name.clone(),
+                        data_type: c.data_type.clone(),
+                        nullable: c.nullable,
+                        primary_key: c.primary_key,
+                        foreign_key: schema.foreign_keys.iter().any(|fk| fk.column == c.name),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let mermaid = mermaid(&tables, &relations);
+        Ok(ErDiagram {
+            tables,
+            relations,
+            mermaid,
+        })
+    })
+    .await
+}
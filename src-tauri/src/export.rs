@@ -0,0 +1,126 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rusqlite::types::ValueRef;
+
+use crate::db;
+use crate::error::Error;
+use crate::paths;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct ExportReport {
+    path: String,
+    format: String,
+    rows: usize,
+    bytes: u64,
+}
+
+fn csv_field(value: ValueRef) -> String {
+    let text = match value {
+        ValueRef::Null => return String::new(),
+        ValueRef::Integer(i) => return i.to_string(),
+        ValueRef::Real(f) => return f.to_string(),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned(),
+        ValueRef::Blob(b) => b.iter().map(|byte| format!("{:02x}", byte)).collect(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+// A directory (or a path without extension) gets "<table>.<format>" appended
+fn export_path(dest: &str, table: &str, format: &str) -> PathBuf {
+    let dest = PathBuf::from(dest);
+    if dest.is_dir() || dest.extension().is_none() {
+        dest.join(format!("{}.{}", table, format))
+    } else {
+        dest
+    }
+}
+
+#[tauri::command]
+pub async fn export_table(
+    project_path: String,
+    table: String,
+    format: String,
+    dest: String,
+) -> Result<ExportReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let format = format.to_lowercase();
+        if format != "csv" && format != "json" {
+            return Err(Error::invalid(
+                "unsupported_format",
+                format!("Unsupported export format: {}", format),
+            )
+            .with("format", &format));
+        }
+
+        let db_path = db::project_sqlite(Path::new(&project_path))?;
+        let conn = db::open_readonly(&db_path)?;
+        db::ensure_table(&conn, &table)?;
+
+        let path = export_path(&paths::new_path(&dest)?, &table, &format);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out = BufWriter::new(File::create(&path)?);
+
+        let mut stmt = conn.prepare(&format!("SELECT * FROM {}", db::quote_ident(&table)))?;
+        let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+        // Rows are written as they are read so large tables never sit in memory
+        let mut rows = 0;
+        let mut result = stmt.query([])?;
+        if format == "csv" {
+            let header: Vec<String> = columns
+                .iter()
+                .map(|c| csv_field(ValueRef::Text(c.as_bytes())))
+                .collect();
+            writeln!(out, "{}", header.join(","))?;
+            while let Some(row) = result.next()? {
+                let fields = (0..columns.len())
+                    .map(|i| row.get_ref(i).map(csv_field))
+                    .collect::<Result<Vec<_>, _>>()?;
+                writeln!(out, "{}", fields.join(","))?;
+                rows += 1;
+            }
+        } else {
+            out.write_all(b"[")?;
+            while let Some(row) = result.next()? {
+                let mut object = serde_json::Map::new();
+                for (i, column) in columns.iter().enumerate() {
+                    let value = row.get_ref(i)?;
+                    // Exports keep blob contents (hex) instead of the grid's size placeholder
+                    let value = match value {
+                        ValueRef::Blob(b) => b
+                            .iter()
+                            .map(|byte| format!("{:02x}", byte))
+                            .collect::<String>()
+                            .into(),
+                        value => db::to_json_value(value),
+                    };
+                    object.insert(column.clone(), value);
+                }
+                out.write_all(if rows == 0 { b"\n  " } else { b",\n  " })?;
+                serde_json::to_writer(&mut out, &object)?;
+                rows += 1;
+            }
+            out.write_all(b"\n]\n")?;
+        }
+        out.flush()?;
+
+        let bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Ok(ExportReport {
+            path: path.to_string_lossy().into_owned(),
+            format,
+            rows,
+            bytes,
+        })
+    })
+    .await
+}
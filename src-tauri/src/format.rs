@@ -0,0 +1,197 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use tauri::AppHandle;
+
+use crate::deps;
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::projects;
+use crate::task;
+use crate::tools;
+
+#[derive(serde::Serialize)]
+pub struct ServiceFormat {
+    service: &'static str,
+    formatter: &'static str,
+    // Service-relative paths of the files the formatter rewrote
+    changed: Vec<String>,
+    error: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct FormatReport {
+    services: Vec<ServiceFormat>,
+    // Requested paths that belong to neither service
+    skipped: Vec<String>,
+}
+
+fn failed(tool: &str, output: &Output) -> Option<String> {
+    if output.status.success() {
+        return None;
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let tail: Vec<&str> = stderr.trim().lines().rev().take(5).collect();
+    Some(format!(
+        "{} failed: {}",
+        tool,
+        tail.into_iter().rev().collect::<Vec<_>>().join("\n")
+    ))
+}
+
+// With --write, --list-different prints only the files prettier actually changed
+fn prettier(dir: &Path, targets: &[String]) -> Result<(Vec<String>, Option<String>), Error> {
+    let bin = tools::node_bin(dir, "prettier")
+        .ok_or_else(|| tools::not_installed("prettier", "frontend"))?;
+    let mut cmd = exec::tool_command(&bin.to_string_lossy());
+    cmd.args(["--write", "--list-different", "--ignore-unknown"])
+        .args(targets)
+        .current_dir(dir);
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed("prettier", e))?;
+    let changed = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|l| paths::relative(dir, l.trim()))
+        .filter(|l| !l.is_empty())
+        .collect();
+    Ok((changed, failed("prettier", &output)))
+}
+
+// `ruff format` only prints totals, so a --check pass lists what is about to change
+fn ruff_format(
+    dir: &Path,
+    bin: PathBuf,
+    targets: &[String],
+) -> Result<(Vec<String>, Option<String>), Error> {
+    let mut check = Command::new(&bin);
+    check
+        .args(["format", "--check", "--no-cache"])
+        .args(targets)
+        .current_dir(dir);
+    let output = exec::output(&mut check).map_err(|e| exec::run_failed("ruff", e))?;
+    let changed: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|l| l.strip_prefix("Would reformat: "))
+        .map(|file| paths::relative(dir, file.trim()))
+        .collect();
+    if changed.is_empty() {
+        // Exit 1 just means files would change; anything else is a real failure
+        let error = (output.status.code() != Some(1))
+            .then(|| failed("ruff format", &output))
+            .flatten();
+        return Ok((changed, error));
+    }
+
+    let mut write = Command::new(bin);
+    write
+        .args(["format", "--no-cache"])
+        .args(targets)
+        .current_dir(dir);
+    let output = exec::output(&mut write).map_err(|e| exec::run_failed("ruff", e))?;
+    Ok((changed, failed("ruff format", &output)))
+}
+
+// black reports "reformatted <path>" on stderr for every file it rewrites
+fn black(
+    dir: &Path,
+    bin: PathBuf,
+    targets: &[String],
+) -> Result<(Vec<String>, Option<String>), Error> {
+    let mut cmd = Command::new(bin);
+    cmd.args(targets).current_dir(dir);
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed("black", e))?;
+    let changed = String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter_map(|l| l.strip_prefix("reformatted "))
+        .map(|file| paths::relative(dir, file.trim()))
+        .collect();
+    Ok((changed, failed("black", &output)))
+}
+
+fn format_service(
+    service: &'static str,
+    dir: &Path,
+    targets: &[String],
+) -> Result<ServiceFormat, Error> {
+    let (formatter, (changed, error)) = if service == "frontend" {
+        ("prettier", prettier(dir, targets)?)
+    } else if let Some(ruff) = tools::python_tool(dir, "ruff") {
+        ("ruff", ruff_format(dir, ruff, targets)?)
+    } else if let Some(bin) = tools::python_tool(dir, "black") {
+        ("black", black(dir, bin, targets)?)
+    } else {
+        return Err(tools::not_installed("ruff", "backend"));
+    };
+    Ok(ServiceFormat {
+        service,
+        formatter,
+        changed,
+        error,
+    })
+}
+
+#[tauri::command]
+pub async fn format_project(
+    app: AppHandle,
+    project_path: String,
+    paths: Option<Vec<String>>,
+) -> Result<FormatReport, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let root = Path::new(&project_path);
+        let (frontend, backend) = deps::service_dirs(root);
+        let services = [("frontend", frontend), ("backend", backend)];
+
+        // Without paths each service is formatted as a whole
+        let mut targets: Vec<Vec<String>> = vec![Vec::new(); services.len()];
+        let mut skipped = Vec::new();
+        for path in paths.iter().flatten() {
+            let file = paths::existing_file(&root.join(path).to_string_lossy()).ok();
+            // Python files go to the backend formatter, everything else to prettier
+            let owner = file.as_ref().and_then(|file| {
+                let python = file.ends_with(".py") || file.ends_with(".pyi");
+                services
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (service, _))| (*service == "backend") == python)
+                    .find_map(|(i, (_, dir))| {
+                        let dir = dir.as_ref()?;
+                        Path::new(file)
+                            .starts_with(dir)
+                            .then(|| (i, paths::relative(dir, file)))
+                    })
+            });
+            match owner {
+                Some((i, relative)) => targets[i].push(relative),
+                None => skipped.push(path.clone()),
+            }
+        }
+
+        let mut report = FormatReport {
+            services: Vec::new(),
+            skipped,
+        };
+        for ((service, dir), targets) in services.iter().zip(targets) {
+            let Some(dir) = dir else {
+                continue;
+            };
+            let targets = match (&paths, targets.is_empty()) {
+                (None, _) => vec![".".to_string()],
+                (Some(_), true) => continue,
+                (Some(_), false) => targets,
+            };
+            let formatted =
+                format_service(service, dir, &targets).unwrap_or_else(|e| ServiceFormat {
+                    service,
+                    formatter: "",
+                    changed: Vec::new(),
+                    error: Some(format!("{}", e)),
+                });
+            report.services.push(formatted);
+        }
+
+        let changed: usize = report.services.iter().map(|s| s.changed.len()).sum();
+        tracing::info!(%project_path, changed, "formatted project");
+        Ok(report)
+    })
+    .await
+}
@@ -0,0 +1,287 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::paths;
+use crate::python;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct GraphNode {
+    id: String,
+    name: String,
+    version: String,
+    direct: bool,
+    dev: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct GraphEdge {
+    from: String,
+    to: String,
+    range: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct DependencyGraph {
+    ecosystem: &'static str,
+    root: String,
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    // Declared dependencies that are not installed
+    missing: Vec<String>,
+}
+
+const ROOT_ID: &str = "root";
+
+#[tauri::command]
+pub async fn get_dependency_graph(service_path: String) -> Result<DependencyGraph, Error> {
+    task::blocking(move || {
+        let service_path = paths::existing_dir(&service_path)?;
+        let path = Path::new(&service_path);
+        if path.join("package.json").exists() {
+            Ok(node_graph(path)?)
+        } else if path.join("requirements.txt").exists() || path.join("pyproject.toml").exists() {
+            Ok(python_graph(path)?)
+        } else {
+            Err(Error::not_found(
+                "no_manifest",
+                format!(
+                    "No package.json, requirements.txt or pyproject.toml found in {}",
+                    service_path
+                ),
+            )
+            .with("path", &service_path))
+        }
+    })
+    .await
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn dependency_ranges(json: &serde_json::Value, fields: &[&str]) -> Vec<(String, String)> {
+    fields
+        .iter()
+        .filter_map(|field| json[*field].as_object())
+        .flatten()
+        .map(|(name, range)| (name.clone(), range.as_str().unwrap_or_default().to_string()))
+        .collect()
+}
+
+// Node's resolution: the nearest node_modules/<name> walking up from the requiring
+// package. Resolving from the real path also follows pnpm's symlinked store layout.
+fn resolve_node_package(from: &Path, name: &str) -> Option<PathBuf> {
+    from.ancestors()
+        .map(|dir| dir.join("node_modules").join(name))
+        .find(|candidate| candidate.join("package.json").exists())
+        .and_then(|found| fs::canonicalize(found).ok())
+}
+
+fn node_graph(path: &Path) -> Result<DependencyGraph, String> {
+    let manifest = read_json(&path.join("package.json"))
+        .ok_or_else(|| "package.json is not valid JSON".to_string())?;
+    let root = manifest["name"].as_str().unwrap_or("frontend").to_string();
+    if !path.join("node_modules").is_dir() {
+        return Err("Frontend dependencies are not installed".to_string());
+    }
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut missing = Vec::new();
+    let mut ids: HashMap<PathBuf, String> = HashMap::new();
+    let mut queue: VecDeque<(PathBuf, String)> = VecDeque::new();
+
+    let dev: HashSet<String> = dependency_ranges(&manifest, &["devDependencies"])
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    let direct = dependency_ranges(
+        &manifest,
+        &["dependencies", "optionalDependencies", "devDependencies"],
+    );
+
+    let mut visit = |from_dir: &Path,
+                     from_id: &str,
+                     name: &str,
+                     range: String,
+                     is_direct: bool,
+                     queue: &mut VecDeque<(PathBuf, String)>|
+     -> bool {
+        let Some(dir) = resolve_node_package(from_dir, name) else {
+            return false;
+        };
+        let id = match ids.get(&dir) {
+            Some(id) => id.clone(),
+            None => {
+                let version = read_json(&dir.join("package.json"))
+                    .and_then(|j| j["version"].as_str().map(String::from))
+                    .unwrap_or_default();
+                let id = format!("{}@{}", name, version);
+                // Hoisted and nested copies of the same version collapse into one node
+                if !nodes.iter().any(|n: &GraphNode| n.id == id) {
+                    nodes.push(GraphNode {
+                        id: id.clone(),
+                        name: name.to_string(),
+                        version,
+                        direct: is_direct,
+                        dev: is_direct && dev.contains(name),
+                    });
+                }
+                ids.insert(dir.clone(), id.clone());
+                queue.push_back((dir, id.clone()));
+                id
+            }
+        };
+        edges.push(GraphEdge {
+            from: from_id.to_string(),
+            to: id,
+            range,
+        });
+        true
+    };
+
+    let root_dir = fs::canonicalize(path).map_err(|e| e.to_string())?;
+    for (name, range) in direct {
+        if !visit(&root_dir, ROOT_ID, &name, range, true, &mut queue) {
+            missing.push(name);
+        }
+    }
+
+    while let Some((dir, id)) = queue.pop_front() {
+        let Some(json) = read_json(&dir.join("package.json")) else {
+            continue;
+        };
+        // Unresolved optional dependencies are usually platform-specific binaries
+        for (name, range) in dependency_ranges(&json, &["dependencies", "optionalDependencies"]) {
+            visit(&dir, &id, &name, range, false, &mut queue);
+        }
+    }
+
+    dedup_edges(&mut edges);
+    Ok(DependencyGraph {
+        ecosystem: "npm",
+        root,
+        nodes,
+        edges,
+        missing,
+    })
+}
+
+// Requires-Dist entries that apply without optional extras
+fn requires_dist(dist_info: &Path) -> Vec<(String, String)> {
+    let Ok(metadata) = fs::read_to_string(dist_info.join("METADATA")) else {
+        return Vec::new();
+    };
+
+    metadata
+        .lines()
+        .take_while(|l| !l.is_empty())
+        .filter_map(|l| l.strip_prefix("Requires-Dist:"))
+        .filter(|r| !r.contains("extra =="))
+        .filter_map(|r| {
+            let spec = r.split(';').next().unwrap_or("").trim();
+            Some((python::requirement_name(spec)?, spec.to_string()))
+        })
+        .collect()
+}
+
+fn python_graph(path: &Path) -> Result<DependencyGraph, String> {
+    let distributions = python::installed_distributions(path);
+    if distributions.is_empty() {
+        return Err("Backend has no installed packages".to_string());
+    }
+
+    let mut direct: Vec<(String, String, bool)> = python::declared_requirements(path)
+        .into_iter()
+        .filter_map(|r| Some((python::requirement_name(&r)?, r, false)))
+        .collect();
+    if let Ok(content) = fs::read_to_string(path.join("requirements-dev.txt")) {
+        direct.extend(
+            content
+                .lines()
+                .filter_map(|l| Some((python::requirement_name(l)?, l.trim().to_string(), true))),
+        );
+    }
+
+    let id_of = |name: &str| {
+        distributions
+            .get(name)
+            .map(|(_, version)| format!("{}@{}", name, version))
+    };
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut missing = Vec::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    for (name, spec, dev) in direct {
+        let Some(id) = id_of(&name) else {
+            missing.push(name);
+            continue;
+        };
+        edges.push(GraphEdge {
+            from: ROOT_ID.to_string(),
+            to: id.clone(),
+            range: spec,
+        });
+        if seen.insert(name.clone()) {
+            nodes.push(GraphNode {
+                id,
+                version: distributions[&name].1.clone(),
+                name: name.clone(),
+                direct: true,
+                dev,
+            });
+            queue.push_back(name);
+        }
+    }
+
+    while let Some(name) = queue.pop_front() {
+        let from = id_of(&name).unwrap_or_default();
+        for (dep, spec) in requires_dist(&distributions[&name].0) {
+            let Some(id) = id_of(&dep) else {
+                continue;
+            };
+            edges.push(GraphEdge {
+                from: from.clone(),
+                to: id.clone(),
+                range: spec,
+            });
+            if seen.insert(dep.clone()) {
+                nodes.push(GraphNode {
+                    id,
+                    version: distributions[&dep].1.clone(),
+                    name: dep.clone(),
+                    direct: false,
+                    dev: false,
+                });
+                queue.push_back(dep);
+            }
+        }
+    }
+
+    dedup_edges(&mut edges);
+    Ok(DependencyGraph {
+        ecosystem: "pypi",
+        root: service_name(path),
+        nodes,
+        edges,
+        missing,
+    })
+}
+
+fn service_name(path: &Path) -> String {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("backend")
+        .to_string()
+}
+
+fn dedup_edges(edges: &mut Vec<GraphEdge>) {
+    let mut seen = HashSet::new();
+    edges.retain(|e| seen.insert((e.from.clone(), e.to.clone())));
+}
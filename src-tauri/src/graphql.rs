@@ -0,0 +1,383 @@
+use serde_json::{json, Value};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::openapi;
+use crate::paths;
+use crate::task;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// Where Strawberry, Ariadne and Graphene apps usually mount their endpoint
+const ENDPOINT_PATHS: [&str; 3] = ["/graphql", "/api/graphql", "/gql"];
+const BUILTIN_SCALARS: [&str; 5] = ["String", "Int", "Float", "Boolean", "ID"];
+
+const INTROSPECTION_QUERY: &str = r#"query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      description
+      fields(includeDeprecated: true) {
+        name
+        description
+        args { name description type { ...TypeRef } defaultValue }
+        type { ...TypeRef }
+        isDeprecated
+        deprecationReason
+      }
+      inputFields { name description type { ...TypeRef } defaultValue }
+      interfaces { ...TypeRef }
+      enumValues(includeDeprecated: true) { name description isDeprecated deprecationReason }
+      possibleTypes { ...TypeRef }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+          ofType { kind name ofType { kind name ofType { kind name } } }
+        }
+      }
+    }
+  }
+}"#;
+
+#[derive(serde::Serialize)]
+pub struct GraphqlType {
+    name: String,
+    // "OBJECT", "INPUT_OBJECT", "ENUM", "INTERFACE", "UNION" or "SCALAR"
+    kind: String,
+    description: Option<String>,
+    // Field signatures like "items(limit: Int): [Item!]!", enum values, or union members
+    fields: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct GraphqlSchema {
+    endpoint: String,
+    sdl: String,
+    query_type: Option<String>,
+    mutation_type: Option<String>,
+    subscription_type: Option<String>,
+    types: Vec<GraphqlType>,
+}
+
+// NON_NULL and LIST wrappers -> "[Item!]!"
+fn type_ref(ty: &Value) -> String {
+    match ty["kind"].as_str() {
+        Some("NON_NULL") => format!("{}!", type_ref(&ty["ofType"])),
+        Some("LIST") => format!("[{}]", type_ref(&ty["ofType"])),
+        _ => ty["name"].as_str().unwrap_or("Unknown").to_string(),
+    }
+}
+
+fn description(value: &Value, indent: &str) -> String {
+    match value["description"]
+        .as_str()
+        .filter(|d| !d.trim().is_empty())
+    {
+        Some(text) => format!("{}\"\"\"{}\"\"\"\n", indent, text.trim()),
+        None => String::new(),
+    }
+}
+
+fn deprecated(value: &Value) -> String {
+    if value["isDeprecated"] != true {
+        return String::new();
+    }
+    match value["deprecationReason"].as_str() {
+        Some(reason) => format!(" @deprecated(reason: {})", Value::from(reason)),
+        None => " @deprecated".to_string(),
+    }
+}
+
+fn input_value(value: &Value) -> String {
+    let mut out = format!(
+        "{}: {}",
+        value["name"].as_str().unwrap_or_default(),
+        type_ref(&value["type"])
+    );
+    if let Some(default) = value["defaultValue"].as_str() {
+        out.push_str(&format!(" = {}", default));
+    }
+    out
+}
+
+fn field_signature(field: &Value) -> String {
+    let args: Vec<String> = field["args"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(input_value)
+        .collect();
+    let name = field["name"].as_str().unwrap_or_default();
+    if args.is_empty() {
+        format!("{}: {}", name, type_ref(&field["type"]))
+    } else {
+        format!(
+            "{}({}): {}",
+            name,
+            args.join(", "),
+            type_ref(&field["type"])
+        )
+    }
+}
+
+fn names(values: &Value) -> Vec<String> {
+    values
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v["name"].as_str().map(String::from))
+        .collect()
+}
+
+fn type_listing(ty: &Value) -> GraphqlType {
+    let kind = ty["kind"].as_str().unwrap_or_default();
+    let fields = match kind {
+        "OBJECT" | "INTERFACE" => ty["fields"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(field_signature)
+            .collect(),
+        "INPUT_OBJECT" => ty["inputFields"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(input_value)
+            .collect(),
+        "ENUM" => names(&ty["enumValues"]),
+        "UNION" => names(&ty["possibleTypes"]),
+        _ => Vec::new(),
+    };
+    GraphqlType {
+        name: ty["name"].as_str().unwrap_or_default().to_string(),
+        kind: kind.to_string(),
+        description: ty["description"]
+            .as_str()
+            .filter(|d| !d.trim().is_empty())
+            .map(String::from),
+        fields,
+    }
+}
+
+fn type_sdl(ty: &Value) -> String {
+    let name = ty["name"].as_str().unwrap_or_default();
+    let mut out = description(ty, "");
+    let body = |items: Vec<String>| format!(" {{\n{}}}\n", items.concat());
+    match ty["kind"].as_str().unwrap_or_default() {
+        "SCALAR" => out.push_str(&format!("scalar {}\n", name)),
+        "UNION" => out.push_str(&format!(
+            "union {} = {}\n",
+            name,
+            names(&ty["possibleTypes"]).join(" | ")
+        )),
+        "ENUM" => {
+            out.push_str(&format!("enum {}", name));
+            out.push_str(&body(
+                ty["enumValues"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|v| {
+                        format!(
+                            "{}  {}{}\n",
+                            description(v, "  "),
+                            v["name"].as_str().unwrap_or_default(),
+                            deprecated(v)
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+        "INPUT_OBJECT" => {
+            out.push_str(&format!("input {}", name));
+            out.push_str(&body(
+                ty["inputFields"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|f| format!("{}  {}\n", description(f, "  "), input_value(f)))
+                    .collect(),
+            ));
+        }
+        kind => {
+            let keyword = if kind == "INTERFACE" {
+                "interface"
+            } else {
+                "type"
+            };
+            out.push_str(&format!("{} {}", keyword, name));
+            let interfaces = names(&ty["interfaces"]);
+            if !interfaces.is_empty() {
+                out.push_str(&format!(" implements {}", interfaces.join(" & ")));
+            }
+            out.push_str(&body(
+                ty["fields"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|f| {
+                        format!(
+                            "{}  {}{}\n",
+                            description(f, "  "),
+                            field_signature(f),
+                            deprecated(f)
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+    }
+    out
+}
+
+fn introspect(client: &reqwest::blocking::Client, endpoint: &str) -> Result<Value, String> {
+    let response = client
+        .post(endpoint)
+        .json(&json!({ "query": INTROSPECTION_QUERY, "operationName": "IntrospectionQuery" }))
+        .send()
+        .map_err(|e| format!("Could not reach {}: {}", endpoint, e))?;
+    let status = response.status();
+    let body: Value = response
+        .json()
+        .map_err(|_| format!("{} did not answer with JSON ({})", endpoint, status))?;
+    if let Some(message) = body["errors"][0]["message"].as_str() {
+        return Err(format!("Introspection failed: {}", message));
+    }
+    let schema = &body["data"]["__schema"];
+    if !schema.is_object() {
+        return Err(format!(
+            "{} is not a GraphQL endpoint ({})",
+            endpoint, status
+        ));
+    }
+    Ok(schema.clone())
+}
+
+#[tauri::command]
+pub async fn introspect_graphql(
+    project_path: String,
+    // Tried instead of the usual paths on the project's backend
+    endpoint: Option<String>,
+) -> Result<GraphqlSchema, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let client = reqwest::blocking::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let candidates = match endpoint {
+            Some(endpoint) => {
+                let valid = reqwest::Url::parse(&endpoint)
+                    .is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+                if !valid {
+                    return Err(Error::invalid(
+                        "invalid_url",
+                        format!("Invalid GraphQL endpoint: {}", endpoint),
+                    )
+                    .with("url", &endpoint));
+                }
+                vec![endpoint]
+            }
+            None => {
+                let base = openapi::backend_url(Path::new(&project_path))?;
+                ENDPOINT_PATHS
+                    .iter()
+                    .map(|path| format!("{}{}", base, path))
+                    .collect()
+            }
+        };
+        let mut failures = Vec::new();
+        let found =
+            candidates
+                .into_iter()
+                .find_map(|endpoint| match introspect(&client, &endpoint) {
+                    Ok(schema) => Some((endpoint, schema)),
+                    Err(e) => {
+                        failures.push(e);
+                        None
+                    }
+                });
+        let Some((endpoint, schema)) = found else {
+            return Err(Error::not_found(
+                "graphql_not_found",
+                format!("No GraphQL endpoint answered: {}", failures.join("; ")),
+            ));
+        };
+
+        let root = |key: &str| schema[key]["name"].as_str().map(String::from);
+        let types: Vec<&Value> = schema["types"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|ty| {
+                let name = ty["name"].as_str().unwrap_or_default();
+                !name.starts_with("__") && !BUILTIN_SCALARS.contains(&name)
+            })
+            .collect();
+
+        // Root types first, the way schemas are usually read
+        let roots = [
+            root("queryType"),
+            root("mutationType"),
+            root("subscriptionType"),
+        ];
+        let mut ordered: Vec<&Value> = roots
+            .iter()
+            .flatten()
+            .filter_map(|name| types.iter().find(|ty| ty["name"] == name.as_str()).copied())
+            .collect();
+        let mut rest: Vec<&Value> = types
+            .iter()
+            .filter(|ty| !ordered.iter().any(|o| o["name"] == ty["name"]))
+            .copied()
+            .collect();
+        rest.sort_by_key(|ty| ty["name"].as_str().unwrap_or_default().to_string());
+        ordered.extend(rest);
+
+        let mut blocks: Vec<String> = ordered.iter().map(|ty| type_sdl(ty)).collect();
+        // Only needed when the root types aren't named Query, Mutation and Subscription
+        let operations: Vec<String> = ["query", "mutation", "subscription"]
+            .iter()
+            .zip(&roots)
+            .filter_map(|(op, name)| Some((op, name.as_deref()?)))
+            .filter(|(op, name)| !name.eq_ignore_ascii_case(op))
+            .map(|(op, name)| format!("  {}: {}\n", op, name))
+            .collect();
+        if !operations.is_empty() {
+            blocks.insert(0, format!("schema {{\n{}}}\n", operations.concat()));
+        }
+        let sdl = blocks.join("\n");
+        let [query_type, mutation_type, subscription_type] = roots;
+        tracing::info!(%endpoint, types = ordered.len(), "introspected GraphQL schema");
+        Ok(GraphqlSchema {
+            endpoint,
+            sdl,
+            query_type,
+            mutation_type,
+            subscription_type,
+            types: ordered.into_iter().map(type_listing).collect(),
+        })
+    })
+    .await
+}
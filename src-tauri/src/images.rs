@@ -0,0 +1,205 @@
+use std::path::Path;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use crate::deps;
+use crate::docker::{self, Engine};
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::task;
+
+const BUILD_EVENT: &str = "image-build-output";
+// Every image built from devLLM carries these, which is how a project's images are found
+pub const PROJECT_LABEL: &str = "devllm.project";
+const SERVICE_LABEL: &str = "devllm.service";
+const SERVICES: [&str; 2] = ["frontend", "backend"];
+
+#[derive(serde::Serialize)]
+pub struct ImageBuild {
+    service: String,
+    tag: String,
+    id: String,
+    size_bytes: u64,
+    duration_ms: u128,
+}
+
+#[derive(serde::Serialize)]
+pub struct ProjectImage {
+    service: &'static str,
+    repository: String,
+    tag: String,
+    id: String,
+    created: String,
+    // As the engine prints it, e.g. "182MB"
+    size: String,
+}
+
+// "My App" + "backend" -> "my-app-backend:latest"
+fn default_tag(project_path: &str, service: &str) -> String {
+    let name: String = Path::new(project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+    if name.is_empty() {
+        format!("{}:latest", service)
+    } else {
+        format!("{}-{}:latest", name, service)
+    }
+}
+
+fn check_tag(tag: &str) -> Result<(), Error> {
+    let valid = !tag.is_empty()
+        && !tag.starts_with(['-', '.', ':'])
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.:/@".contains(c));
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::invalid("invalid_tag", format!("Invalid image tag: {}", tag)).with("tag", tag))
+    }
+}
+
+// Image ID and size in bytes, straight from the engine once the build is done
+fn inspect(engine: Engine, tag: &str) -> Result<(String, u64), String> {
+    let mut cmd = engine.command();
+    cmd.args(["image", "inspect", "--format", "{{.Id}} {{.Size}}", tag]);
+    let output = exec::output(&mut cmd).map_err(|e| format!("Failed to inspect {}: {}", tag, e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (id, size) = stdout
+        .trim()
+        .split_once(' ')
+        .filter(|_| output.status.success())
+        .ok_or_else(|| format!("Built image {} could not be inspected", tag))?;
+    Ok((id.to_string(), size.parse().unwrap_or(0)))
+}
+
+#[tauri::command]
+pub async fn build_image(
+    app: AppHandle,
+    project_path: String,
+    service: String,
+    tag: Option<String>,
+) -> Result<ImageBuild, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let dir = deps::service_dir(Path::new(&project_path), &service)?;
+        if !dir.join("Dockerfile").is_file() {
+            return Err(Error::not_found(
+                "no_dockerfile",
+                format!("The {} has no Dockerfile", service),
+            )
+            .with("service", &service));
+        }
+        let tag = tag
+            .filter(|t| !t.trim().is_empty())
+            .map(|t| t.trim().to_string())
+            .unwrap_or_else(|| default_tag(&project_path, &service));
+        check_tag(&tag)?;
+        let engine = docker::require()?;
+
+        let mut cmd = engine.command();
+        cmd.args(["build", "-t", &tag])
+            .arg("--label")
+            .arg(format!("{}={}", PROJECT_LABEL, paths::key(&project_path)))
+            .arg("--label")
+            .arg(format!("{}={}", SERVICE_LABEL, service))
+            .arg(".")
+            .current_dir(&dir);
+        let operation = format!("image:{}:{}", paths::key(&project_path), service);
+        let started = Instant::now();
+        let output = exec::run_streamed(&app, BUILD_EVENT, &operation, &mut cmd)
+            .map_err(|e| exec::run_failed(engine.program(), e))?;
+        if !output.status.success() {
+            let tail: Vec<&str> = output
+                .lines
+                .iter()
+                .rev()
+                .take(5)
+                .map(String::as_str)
+                .collect();
+            return Err(Error::tool_failed(
+                engine.program(),
+                format!(
+                    "Image build failed: {}",
+                    tail.into_iter().rev().collect::<Vec<_>>().join("\n")
+                ),
+            )
+            .with("tag", &tag));
+        }
+        let duration_ms = started.elapsed().as_millis();
+
+        let (id, size_bytes) = inspect(engine, &tag)?;
+        tracing::info!(%project_path, %service, %tag, size_bytes, "built image");
+        Ok(ImageBuild {
+            service,
+            tag,
+            id,
+            size_bytes,
+            duration_ms,
+        })
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn list_images(project_path: String) -> Result<Vec<ProjectImage>, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let engine = docker::require()?;
+        let mut images = Vec::new();
+        for service in SERVICES {
+            let mut cmd = engine.command();
+            cmd.args(["images", "--filter"])
+                .arg(format!(
+                    "label={}={}",
+                    PROJECT_LABEL,
+                    paths::key(&project_path)
+                ))
+                .arg("--filter")
+                .arg(format!("label={}={}", SERVICE_LABEL, service))
+                .args([
+                    "--format",
+                    "{{.Repository}}\t{{.Tag}}\t{{.ID}}\t{{.CreatedSince}}\t{{.Size}}",
+                ]);
+            let output =
+                exec::output(&mut cmd).map_err(|e| exec::run_failed(engine.program(), e))?;
+            if !output.status.success() {
+                return Err(Error::tool_failed(
+                    engine.program(),
+                    format!(
+                        "Listing images failed: {}",
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ),
+                ));
+            }
+            for line in String::from_utf8_lossy(&output.stdout).lines() {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let [repository, tag, id, created, size] = fields[..] else {
+                    continue;
+                };
+                images.push(ProjectImage {
+                    service,
+                    repository: repository.to_string(),
+                    tag: tag.to_string(),
+                    id: id.to_string(),
+                    created: created.to_string(),
+                    size: size.to_string(),
+                });
+            }
+        }
+        Ok(images)
+    })
+    .await
+}
@@ -0,0 +1,72 @@
+use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::projects;
+
+const OPEN_PROJECT_EVENT: &str = "open-project";
+
+// A project requested before the frontend was listening, picked up once it loads
+#[derive(Default)]
+pub struct PendingOpen(Mutex<Option<String>>);
+
+#[derive(Clone, serde::Serialize)]
+struct OpenProject {
+    path: String,
+}
+
+// First argument that names a registered project directory, relative to the launching
+// shell. Goes through the same checks as command paths, so a share is never touched.
+fn project_arg(app: &AppHandle, argv: &[String], cwd: &Path) -> Option<String> {
+    argv.iter()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| {
+            let path = PathBuf::from(arg);
+            if path.is_absolute() {
+                path
+            } else {
+                cwd.join(path)
+            }
+        })
+        .find_map(|path| projects::project_dir(app, &path.to_string_lossy()).ok())
+}
+
+pub fn focus_main(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+pub fn open_project(app: &AppHandle, path: String) {
+    tracing::info!(%path, "opening project from outside the app");
+    let _ = app.emit(OPEN_PROJECT_EVENT, OpenProject { path });
+}
+
+// The window isn't listening yet at startup, so the path waits for take_pending_project
+pub fn queue_project(app: &AppHandle, path: String) {
+    *app.state::<PendingOpen>().0.lock() = Some(path);
+}
+
+// A second launch hands its arguments to us and exits, so only one ProcessManager owns the ports
+pub fn on_second_instance(app: &AppHandle, argv: Vec<String>, cwd: String) {
+    focus_main(app);
+    if let Some(path) = project_arg(app, &argv, Path::new(&cwd)) {
+        open_project(app, path);
+    }
+}
+
+pub fn handle_launch_args(app: &AppHandle) {
+    let argv: Vec<String> = std::env::args().collect();
+    let cwd = std::env::current_dir().unwrap_or_default();
+    if let Some(path) = project_arg(app, &argv, &cwd) {
+        queue_project(app, path);
+    }
+}
+
+#[tauri::command]
+pub fn take_pending_project(state: State<PendingOpen>) -> Option<String> {
+    state.0.lock().take()
+}
@@ -0,0 +1,409 @@
+use std::fs;
+use std::net::{IpAddr, SocketAddr, TcpStream};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::task;
+
+const LAN_HOST: &str = "0.0.0.0";
+const LOCAL_HOST: &str = "127.0.0.1";
+
+#[derive(serde::Serialize)]
+pub struct LanAccess {
+    command: String,
+    status: LanStatus,
+}
+
+#[derive(serde::Serialize)]
+pub struct LanStatus {
+    addresses: Vec<String>,
+    urls: Vec<String>,
+    // Only a local bind check, see lan_status
+    bound: bool,
+    firewall: FirewallStatus,
+}
+
+#[derive(serde::Serialize)]
+pub struct FirewallStatus {
+    enabled: Option<bool>,
+    port_allowed: Option<bool>,
+    blocked: bool,
+    guidance: Option<String>,
+}
+
+// Rewrites the service command so it binds every interface (or back to loopback)
+#[tauri::command]
+pub async fn set_lan_access(
+    project_path: String,
+    command: String,
+    port: u16,
+    enabled: bool,
+) -> Result<LanAccess, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let path = Path::new(&project_path);
+
+        let host = if enabled { LAN_HOST } else { LOCAL_HOST };
+        let command = rewrite_host(&resolve_script(path, &command), host);
+
+        Ok(LanAccess {
+            command,
+            status: lan_status(port),
+        })
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn check_lan_access(port: u16) -> Result<LanStatus, Error> {
+    task::blocking(move || Ok(lan_status(port))).await
+}
+
+fn lan_status(port: u16) -> LanStatus {
+    let addresses = lan_addresses();
+    let urls = addresses
+        .iter()
+        .map(|ip| format!("http://{}:{}", ip, port))
+        .collect();
+
+    // A connection to our own LAN address never leaves this machine, so this only shows the
+    // service is bound to that interface. Whether other devices get through is up to the
+    // firewall, which is reported separately.
+    let bound = addresses.iter().any(|ip| {
+        TcpStream::connect_timeout(&SocketAddr::new(*ip, port), Duration::from_millis(500)).is_ok()
+    });
+
+    LanStatus {
+        addresses: addresses.iter().map(|ip| ip.to_string()).collect(),
+        urls,
+        bound,
+        firewall: firewall_status(port),
+    }
+}
+
+fn lan_addresses() -> Vec<IpAddr> {
+    let mut addresses: Vec<IpAddr> = if_addrs::get_if_addrs()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|iface| !iface.is_loopback() && !iface.is_link_local())
+        .map(|iface| iface.ip())
+        .filter(|ip| ip.is_ipv4())
+        .collect();
+    addresses.sort();
+    addresses.dedup();
+    addresses
+}
+
+// `npm run dev` can't take a second --host on top of the one baked into the
+// generated script, so vite scripts are expanded into a direct npx invocation
+fn resolve_script(path: &Path, command: &str) -> String {
+    let words: Vec<&str> = command.split_whitespace().collect();
+    let script = match words.as_slice() {
+        ["npm", "run", script] | ["pnpm", "run", script] | ["yarn", "run", script] => *script,
+        ["pnpm", script] | ["yarn", script] if *script != "install" => *script,
+        _ => return command.to_string(),
+    };
+
+    let package = fs::read_to_string(path.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok());
+
+    match package.as_ref().and_then(|p| p["scripts"][script].as_str()) {
+        Some(body) if body.trim_start().starts_with("vite") => format!("npx {}", body.trim()),
+        _ => command.to_string(),
+    }
+}
+
+fn rewrite_host(command: &str, host: &str) -> String {
+    let mut words: Vec<String> = command.split_whitespace().map(String::from).collect();
+    let mut replaced = false;
+
+    let mut i = 0;
+    while i < words.len() {
+        if words[i] == "--host" && i + 1 < words.len() {
+            words[i + 1] = host.to_string();
+            replaced = true;
+            i += 1;
+        } else if words[i].starts_with("--host=") {
+            words[i] = format!("--host={}", host);
+            replaced = true;
+        }
+        i += 1;
+    }
+
+    let binds_server = words.iter().any(|w| {
+        let name = w.rsplit(['/', '\\']).next().unwrap_or(w);
+        name == "vite" || name.starts_with("uvicorn")
+    });
+
+    if !replaced && binds_server {
+        words.push("--host".to_string());
+        words.push(host.to_string());
+    }
+
+    words.join(" ")
+}
+
+fn firewall_status(port: u16) -> FirewallStatus {
+    let (enabled, port_allowed, guidance) = if cfg!(windows) {
+        windows_firewall(port)
+    } else if cfg!(target_os = "macos") {
+        macos_firewall()
+    } else {
+        ufw_firewall(port)
+    };
+
+    FirewallStatus {
+        enabled,
+        port_allowed,
+        blocked: enabled == Some(true) && port_allowed == Some(false),
+        guidance,
+    }
+}
+
+fn command_stdout(program: &str, args: &[&str]) -> Option<String> {
+    let output = exec::output(Command::new(program).args(args)).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// netsh localizes its field names, so output without the English keys is unknown
+// rather than "off" or "blocked"
+fn netsh_field<'a>(block: &'a str, name: &str) -> Option<&'a str> {
+    block
+        .lines()
+        .find(|l| l.trim_start().starts_with(name))
+        .and_then(|l| l.split_once(':'))
+        .map(|(_, v)| v.trim())
+}
+
+fn windows_firewall(port: u16) -> (Option<bool>, Option<bool>, Option<String>) {
+    let enabled = command_stdout("netsh", &["advfirewall", "show", "currentprofile", "state"])
+        .and_then(|out| {
+            // "State                                 ON", without a colon
+            out.lines()
+                .find_map(|l| l.trim_start().strip_prefix("State"))
+                .map(|state| state.trim() == "ON")
+        });
+
+    let port_allowed = if enabled == Some(true) {
+        command_stdout(
+            "netsh",
+            &[
+                "advfirewall",
+                "firewall",
+                "show",
+                "rule",
+                "name=all",
+                "dir=in",
+                "verbose",
+            ],
+        )
+        .and_then(|out| windows_rule_allows(&out, port))
+    } else {
+        None
+    };
+
+    let guidance = format!(
+        "Run in an elevated terminal: netsh advfirewall firewall add rule name=\"devLLM {}\" dir=in action=allow protocol=TCP localport={}",
+        port, port
+    );
+
+    (enabled, port_allowed, Some(guidance))
+}
+
+// Rules are separated by blank lines; a rule counts if it is an enabled inbound
+// allow for the port, or a per-program allow for node/python on any port
+fn windows_rule_allows(output: &str, port: u16) -> Option<bool> {
+    let port = port.to_string();
+    let mut rules = output
+        .split("\r\n\r\n")
+        .flat_map(|b| b.split("\n\n"))
+        .filter(|rule| netsh_field(rule, "Enabled").is_some())
+        .peekable();
+    rules.peek()?;
+
+    let allows = rules.any(|rule| {
+        let field = |name: &str| netsh_field(rule, name).unwrap_or_default();
+
+        if field("Enabled") != "Yes" || field("Action") != "Allow" {
+            return false;
+        }
+        let protocol = field("Protocol");
+        if protocol != "TCP" && protocol != "Any" {
+            return false;
+        }
+
+        let local_port = field("LocalPort");
+        if local_port.split(',').any(|p| p.trim() == port) {
+            return true;
+        }
+
+        let program = field("Program").to_lowercase();
+        local_port == "Any" && (program.ends_with("node.exe") || program.ends_with("python.exe"))
+    });
+    Some(allows)
+}
+
+fn macos_firewall() -> (Option<bool>, Option<bool>, Option<String>) {
+    let enabled = command_stdout(
+        "/usr/libexec/ApplicationFirewall/socketfilterfw",
+        &["--getglobalstate"],
+    )
+    .map(|out| out.contains("enabled"));
+
+    let guidance = "Allow incoming connections for node/python in System Settings > Network > Firewall > Options".to_string();
+
+    (enabled, None, Some(guidance))
+}
+
+fn ufw_firewall(port: u16) -> (Option<bool>, Option<bool>, Option<String>) {
+    let guidance = format!("Run: sudo ufw allow {}/tcp", port);
+    // Without root `ufw status` fails or prints nothing useful; that's unknown, not off
+    let Some(out) = command_stdout("ufw", &["status"]).filter(|out| out.contains("Status:")) else {
+        return (None, None, Some(guidance));
+    };
+
+    let enabled = out.contains("Status: active");
+    let port_allowed = enabled.then(|| {
+        out.lines().any(|l| {
+            let mut cols = l.split_whitespace();
+            let target = cols.next().unwrap_or("");
+            let rule_port = target.split('/').next().unwrap_or("");
+            rule_port == port.to_string() && l.contains("ALLOW")
+        })
+    });
+
+    (Some(enabled), port_allowed, Some(guidance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_the_bind_host() {
+        let cases = [
+            ("npx vite", "npx vite --host 0.0.0.0"),
+            (
+                "npx vite --host 127.0.0.1 --port 5173",
+                "npx vite --host 0.0.0.0 --port 5173",
+            ),
+            ("npx vite --host=localhost", "npx vite --host=0.0.0.0"),
+            (
+                "uvicorn app.main:app --reload",
+                "uvicorn app.main:app --reload --host 0.0.0.0",
+            ),
+            (
+                ".venv/bin/uvicorn app.main:app",
+                ".venv/bin/uvicorn app.main:app --host 0.0.0.0",
+            ),
+            (
+                r".venv\Scripts\uvicorn.exe app.main:app",
+                r".venv\Scripts\uvicorn.exe app.main:app --host 0.0.0.0",
+            ),
+            // Only servers known to take --host get one added
+            ("npm run dev", "npm run dev"),
+            ("flask run", "flask run"),
+        ];
+        for (command, expected) in cases {
+            assert_eq!(rewrite_host(command, "0.0.0.0"), expected, "{}", command);
+        }
+        assert_eq!(
+            rewrite_host("npx vite --host 0.0.0.0", "127.0.0.1"),
+            "npx vite --host 127.0.0.1"
+        );
+    }
+
+    const RULES: &str = "
+Rule Name:                            Node.js JavaScript Runtime
+----------------------------------------------------------------------
+Enabled:                              Yes
+Direction:                            In
+Profiles:                             Private
+LocalIP:                              Any
+RemoteIP:                             Any
+Protocol:                             UDP
+LocalPort:                            Any
+RemotePort:                           Any
+Program:                              C:\\Program Files\\nodejs\\node.exe
+Action:                               Allow
+
+Rule Name:                            devLLM 8000
+----------------------------------------------------------------------
+Enabled:                              Yes
+Direction:                            In
+Protocol:                             TCP
+LocalPort:                            8000,8001
+Action:                               Allow
+
+Rule Name:                            Blocked 5173
+----------------------------------------------------------------------
+Enabled:                              Yes
+Direction:                            In
+Protocol:                             TCP
+LocalPort:                            5173
+Action:                               Block
+
+Rule Name:                            Old 3000
+----------------------------------------------------------------------
+Enabled:                              No
+Direction:                            In
+Protocol:                             TCP
+LocalPort:                            3000
+Action:                               Allow
+
+Rule Name:                            Python
+----------------------------------------------------------------------
+Enabled:                              Yes
+Direction:                            In
+Protocol:                             TCP
+LocalPort:                            Any
+Program:                              C:\\Python312\\python.exe
+Action:                               Allow
+";
+
+    #[test]
+    fn reads_netsh_rules() {
+        assert_eq!(windows_rule_allows(RULES, 8000), Some(true));
+        assert_eq!(windows_rule_allows(RULES, 8001), Some(true));
+        assert_eq!(
+            windows_rule_allows(&RULES.replace('\n', "\r\n"), 8001),
+            Some(true)
+        );
+        // Blocked and disabled rules don't count, but python.exe is allowed on any port
+        assert_eq!(windows_rule_allows(RULES, 5173), Some(true));
+
+        let python_rule = RULES.rfind("Rule Name:").unwrap();
+        let without_python = &RULES[..python_rule];
+        assert_eq!(windows_rule_allows(without_python, 5173), Some(false));
+        assert_eq!(windows_rule_allows(without_python, 3000), Some(false));
+        // The node rule is UDP only
+        assert_eq!(windows_rule_allows(without_python, 4000), Some(false));
+    }
+
+    #[test]
+    fn localized_netsh_output_is_unknown() {
+        let german = "
+Regelname:                            devLLM 8000
+----------------------------------------------------------------------
+Aktiviert:                            Ja
+Richtung:                             Eingehend
+Protokoll:                            TCP
+Lokaler Port:                         8000
+Aktion:                               Zulassen
+";
+        assert_eq!(windows_rule_allows(german, 8000), None);
+        assert_eq!(windows_rule_allows("", 8000), None);
+        assert_eq!(
+            windows_rule_allows("No rules match the specified criteria.", 8000),
+            None
+        );
+    }
+}
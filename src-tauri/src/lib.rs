@@ -1,100 +1,448 @@
-use std::collections::HashMap;
+mod appdata;
+mod architecture;
+mod audit;
+mod autostart;
+mod backup;
+mod bench;
+mod cache;
+mod cancel;
+mod cleanup;
+mod collection;
+mod compose;
+mod contract;
+mod coverage;
+mod crash;
+mod db;
+mod deeplink;
+mod deps;
+mod devcontainer;
+mod devdeps;
+mod docker;
+mod er;
+mod error;
+mod exec;
+mod export;
+mod format;
+mod graph;
+mod graphql;
+mod images;
+mod instance;
+mod lan;
+mod licenses;
+mod lint;
+mod localhttp;
+mod logbuf;
+mod logging;
+mod manifest;
+mod migrations;
+mod mock;
+mod modelgen;
+mod node;
+mod notify;
+mod openapi;
+mod paths;
+mod pg_migrate;
+mod preflight;
+mod projects;
+mod pyproject;
+mod python;
+mod reaper;
+mod requestbin;
+mod reset;
+mod resources;
+mod scaffold;
+mod sdk;
+mod security;
+mod seed;
+mod servicelog;
+mod settings;
+mod shortcuts;
+mod sse;
+mod stats;
+mod task;
+mod telemetry;
+mod testing;
+mod toolchain;
+mod tools;
+mod tray;
+mod typecheck;
+mod versioned;
+mod watch;
+
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::path::Path;
-use std::process::{Child, Command};
-use std::sync::Mutex;
-use tauri::State;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use tauri::{AppHandle, Manager};
+
+use error::{Error, ErrorKind};
 
 // Store running processes
 struct ProcessManager {
     processes: Mutex<HashMap<String, Child>>,
+    // Last launch of each service, so it can be started again from the tray
+    launches: Mutex<HashMap<String, ServiceLaunch>>,
+    // How services ended, most recent last
+    history: Mutex<VecDeque<reaper::ServiceExit>>,
+    // Compose stacks started this session, keyed like processes with a compose: suffix
+    stacks: Mutex<HashMap<String, compose::Stack>>,
+    // Recent output per service, for the log panel
+    logs: Mutex<HashMap<String, logbuf::LogBuffer>>,
+    // Containers from start_dev_dependency, keyed like processes with a dependency: suffix
+    dependencies: Mutex<HashMap<String, devdeps::DevDependency>>,
+    // Mock backends from start_mock_server, keyed like processes with a mock suffix
+    mocks: Mutex<HashMap<String, mock::MockServer>>,
+}
+
+#[derive(Clone)]
+struct ServiceLaunch {
+    service_type: String,
+    project_path: String,
+    command: String,
+    env_vars: Option<HashMap<String, String>>,
+}
+
+#[tauri::command]
+async fn start_service(
+    app: AppHandle,
+    service_type: String,
+    project_path: String,
+    command: String,
+    env_vars: Option<HashMap<String, String>>,
+    preflight: Option<bool>,
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let launch = ServiceLaunch {
+            service_type,
+            project_path,
+            command,
+            env_vars,
+        };
+        start_launch(&app, &launch, preflight.unwrap_or(false))
+    })
+    .await
 }
 
+// The only way a command proposed by the assistant gets run: it always goes through the
+// allowlist, and always through preflight
 #[tauri::command]
-fn start_service(
+async fn start_assistant_service(
+    app: AppHandle,
     service_type: String,
     project_path: String,
     command: String,
     env_vars: Option<HashMap<String, String>>,
-    state: State<ProcessManager>,
-) -> Result<String, String> {
-    let key = format!("{}:{}", project_path, service_type);
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        exec::check_assistant_command(&command)?;
+        let launch = ServiceLaunch {
+            service_type,
+            project_path,
+            command,
+            env_vars,
+        };
+        start_launch(&app, &launch, true)
+    })
+    .await
+}
+
+fn start_launch(
+    app: &AppHandle,
+    launch: &ServiceLaunch,
+    preflight: bool,
+) -> Result<String, Error> {
+    let state = app.state::<ProcessManager>();
+    let key = format!("{}:{}", paths::key(&launch.project_path), launch.service_type);
+    let restarted = state.launches.lock().contains_key(&key);
+    let message = launch_service(app, launch, preflight)?;
+    if restarted {
+        notify::when_healthy(app, launch);
+    }
+    tray::refresh(app);
+    Ok(message)
+}
+
+fn launch_service(
+    app: &AppHandle,
+    launch: &ServiceLaunch,
+    preflight: bool,
+) -> Result<String, Error> {
+    let state = app.state::<ProcessManager>();
+    let ServiceLaunch {
+        service_type,
+        project_path,
+        command,
+        env_vars,
+    } = launch;
+    let key = format!("{}:{}", paths::key(project_path), service_type);
 
     {
-        let processes = state.processes.lock().map_err(|e| e.to_string())?;
+        let mut processes = state.processes.lock();
+        // An entry whose process already exited (e.g. left behind by an operation that
+        // failed midway) is dropped rather than blocking the service for good
+        if processes
+            .get_mut(&key)
+            .is_some_and(|child| !matches!(child.try_wait(), Ok(None)))
+        {
+            processes.remove(&key);
+        }
         if processes.contains_key(&key) {
-            return Err(format!("{} is already running", service_type));
+            return Err(Error::new(
+                ErrorKind::Conflict,
+                "service_running",
+                format!("{} is already running", service_type),
+            )
+            .with("service", service_type));
         }
     }
 
     let path = Path::new(&project_path);
     if !path.exists() {
-        return Err(format!("Path does not exist: {}", project_path));
+        return Err(Error::path_not_found(project_path));
     }
 
-    let child = if cfg!(windows) {
-        use std::os::windows::process::CommandExt;
-        const CREATE_NEW_CONSOLE: u32 = 0x00000010;
+    if preflight {
+        let report = preflight::run(service_type, path, command, env_vars.as_ref());
+        if !report.ok {
+            tracing::warn!(service = %service_type, "preflight failed: {}", report.summary());
+            return Err(report.into_error());
+        }
+    }
 
-        let mut cmd = Command::new("cmd");
-        cmd.args(&["/k", &command])
-            .current_dir(path)
-            .creation_flags(CREATE_NEW_CONSOLE);
+    // Projects pinning a package manager run it through Corepack
+    let command = node::resolve_start_command(path, command)?;
 
-        // Apply environment variables
-        if let Some(vars) = &env_vars {
-            for (key, value) in vars {
-                cmd.env(key, value);
-            }
+    // The backend runs against its venv without relying on `activate`
+    let mut venv_env = Vec::new();
+    let venv_command = match service_type.as_str() {
+        "backend" => python::resolve_venv_command(path, &command),
+        _ => None,
+    };
+    let command = match venv_command {
+        Some(resolved) => {
+            venv_env = resolved.env;
+            resolved.command
         }
+        None => command,
+    };
 
-        cmd.spawn()
-            .map_err(|e| format!("Failed to start {}: {}", service_type, e))?
-    } else {
-        let mut cmd = Command::new("sh");
-        cmd.args(&["-c", &command])
-            .current_dir(path);
-
-        // Apply environment variables
-        if let Some(vars) = &env_vars {
-            for (key, value) in vars {
-                cmd.env(key, value);
-            }
+    // Output goes to the log panel rather than a console window of its own
+    let mut cmd = exec::service_command(path, &command)?;
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    exec::hide_window(&mut cmd);
+
+    // Apply environment variables
+    cmd.envs(venv_env.iter().map(|(k, v)| (k, v)));
+    if let Some(vars) = &env_vars {
+        for (key, value) in vars {
+            cmd.env(key, value);
         }
+    }
 
-        cmd.spawn()
-            .map_err(|e| format!("Failed to start {}: {}", service_type, e))?
-    };
+    let mut child = exec::spawn(&mut cmd).map_err(|e| {
+        let program = command.split_whitespace().next().unwrap_or_default();
+        let message = format!("Failed to start {}: {}", service_type, e);
+        Error::spawn_failed(program, message, &e).with("service", service_type)
+    })?;
+    servicelog::reset(app, &key);
+    if let Some(stdout) = child.stdout.take() {
+        let on_line = servicelog::to_panel(&key, project_path, service_type);
+        servicelog::follow(app, stdout, "stdout", on_line);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        let on_line = servicelog::to_panel(&key, project_path, service_type);
+        servicelog::follow(app, stderr, "stderr", on_line);
+    }
 
     let pid = child.id();
-    let mut processes = state.processes.lock().map_err(|e| e.to_string())?;
-    processes.insert(key, child);
+    tracing::info!(service = %service_type, pid, path = %project_path, "service started");
+    state.processes.lock().insert(key.clone(), child);
+    state.launches.lock().insert(key, launch.clone());
 
     Ok(format!("{} started with PID {}", service_type, pid))
 }
 
+fn stop_process(state: &ProcessManager, key: &str) -> Result<bool, String> {
+    // Killing can take a while (taskkill walks the tree), so it happens after the lock is released
+    let removed = state.processes.lock().remove(key);
+    let Some(mut child) = removed else {
+        return Ok(false);
+    };
+    let status = kill_process(&mut child);
+    reaper::record(state, key, child.id(), status, true);
+    tracing::info!(service = %key, pid = child.id(), "service stopped");
+    Ok(true)
+}
+
+fn kill_process(child: &mut Child) -> Option<ExitStatus> {
+    // Once our child has exited its PID may already belong to an unrelated process,
+    // so nothing is ever killed by PID after that
+    match child.try_wait() {
+        Ok(None) => {}
+        Ok(Some(status)) => {
+            tracing::debug!(pid = child.id(), "process already exited; nothing to kill");
+            return Some(status);
+        }
+        Err(_) => return None,
+    }
+    if cfg!(windows) {
+        // Windows doesn't reuse a PID while a handle to the process is open, and Child
+        // holds one, so while it is running the PID is ours. taskkill /T takes the
+        // console's children (node, uvicorn) along with it.
+        let pid = child.id();
+        let _ = exec::output(
+            Command::new("taskkill").args(["/F", "/T", "/PID", &pid.to_string()]),
+        );
+    }
+    #[cfg(unix)]
+    kill_group(child);
+    // Terminates through the handle, never the PID; also covers a failed taskkill
+    let _ = child.kill();
+    child.wait().ok()
+}
+
+// Services lead their own process group (see exec::service_command), so the group takes
+// along what the shell or npm started. SIGTERM first so dev servers can clean up, then
+// SIGKILL for anything left. The child isn't reaped until kill_process waits on it, so
+// its PID, and with it the group ID, can't have been reused in the meantime.
+#[cfg(unix)]
+fn kill_group(child: &Child) {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    let group = -(child.id() as libc::pid_t);
+    let exited = || {
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        let flags = libc::WEXITED | libc::WNOHANG | libc::WNOWAIT;
+        // With WNOWAIT the exit is only observed; the child stays waitable
+        let found = unsafe { libc::waitid(libc::P_PID, child.id(), &mut info, flags) };
+        found != 0 || unsafe { info.si_pid() } != 0
+    };
+    unsafe { libc::kill(group, libc::SIGTERM) };
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !exited() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(50));
+    }
+    unsafe { libc::kill(group, libc::SIGKILL) };
+}
+
 #[tauri::command]
-fn stop_service(
+async fn stop_service(
+    app: AppHandle,
     service_type: String,
     project_path: String,
-    state: State<ProcessManager>,
-) -> Result<String, String> {
-    let key = format!("{}:{}", project_path, service_type);
-    let mut processes = state.processes.lock().map_err(|e| e.to_string())?;
-
-    if let Some(mut child) = processes.remove(&key) {
-        if cfg!(windows) {
-            let pid = child.id();
-            let _ = Command::new("taskkill")
-                .args(&["/F", "/T", "/PID", &pid.to_string()])
-                .output();
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let state = app.state::<ProcessManager>();
+        let key = format!("{}:{}", paths::key(&project_path), service_type);
+        let stopped = stop_process(&state, &key)?;
+        tray::refresh(&app);
+
+        if stopped {
+            Ok(format!("{} stopped", service_type))
         } else {
-            let _ = child.kill();
+            Err(Error::new(
+                ErrorKind::Conflict,
+                "service_not_running",
+                format!("{} is not running", service_type),
+            )
+            .with("service", &service_type))
         }
-        Ok(format!("{} stopped", service_type))
-    } else {
-        Err(format!("{} is not running", service_type))
-    }
+    })
+    .await
+}
+
+#[derive(serde::Serialize)]
+struct ServiceInfo {
+    key: String,
+    // "process", "compose", "dependency" or "mock"
+    kind: &'static str,
+    service: String,
+    project_path: String,
+    running: bool,
+    pid: Option<u32>,
+    // Container states of a compose stack
+    stack: Option<compose::StackStatus>,
+}
+
+// Everything started this session: processes (including stopped ones that can be
+// restarted) and compose stacks
+#[tauri::command]
+async fn list_services(app: AppHandle) -> Result<Vec<ServiceInfo>, Error> {
+    task::blocking(move || {
+        let state = app.state::<ProcessManager>();
+        let launches = state.launches.lock().clone();
+        let mut services: Vec<ServiceInfo> = {
+            let mut processes = state.processes.lock();
+            launches
+                .into_iter()
+                .map(|(key, launch)| {
+                    let pid = processes
+                        .get_mut(&key)
+                        .and_then(|child| matches!(child.try_wait(), Ok(None)).then(|| child.id()));
+                    ServiceInfo {
+                        key,
+                        kind: "process",
+                        service: launch.service_type,
+                        project_path: launch.project_path,
+                        running: pid.is_some(),
+                        pid,
+                        stack: None,
+                    }
+                })
+                .collect()
+        };
+
+        let stacks = state.stacks.lock().clone();
+        let dependencies = state.dependencies.lock().clone();
+        let engine = (!stacks.is_empty() || !dependencies.is_empty())
+            .then(docker::engine)
+            .flatten();
+        for (key, stack) in stacks {
+            let status = compose::status(engine, key.clone(), &stack);
+            services.push(ServiceInfo {
+                key,
+                kind: "compose",
+                service: "compose".to_string(),
+                project_path: stack.project_path,
+                running: status.running,
+                pid: None,
+                stack: Some(status),
+            });
+        }
+        for (key, dependency) in dependencies {
+            services.push(ServiceInfo {
+                key,
+                kind: "dependency",
+                service: dependency.kind.to_string(),
+                project_path: dependency.project_path.clone(),
+                running: engine.is_some_and(|e| devdeps::is_running(e, &dependency.container)),
+                pid: None,
+                stack: None,
+            });
+        }
+        for (key, server) in state.mocks.lock().clone() {
+            services.push(ServiceInfo {
+                key,
+                kind: "mock",
+                service: "backend".to_string(),
+                project_path: server.project_path,
+                running: true,
+                pid: None,
+                stack: None,
+            });
+        }
+
+        services.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(services)
+    })
+    .await
 }
 
 #[derive(serde::Serialize)]
@@ -104,13 +452,15 @@ struct DetectedProject {
     frontend_port: Option<u16>,
     backend_port: Option<u16>,
     project_name: String,
+    frontend_command: Option<String>,
+    backend_command: Option<String>,
+    drift: Vec<deps::DriftIssue>,
 }
 
-#[tauri::command]
-fn detect_project(project_path: String) -> Result<DetectedProject, String> {
+fn detect_project_blocking(project_path: String) -> Result<DetectedProject, Error> {
     let path = Path::new(&project_path);
     if !path.exists() {
-        return Err("Path does not exist".to_string());
+        return Err(Error::path_not_found(&project_path));
     }
 
     let frontend_path = path.join("frontend");
@@ -118,6 +468,7 @@ fn detect_project(project_path: String) -> Result<DetectedProject, String> {
 
     let has_frontend = frontend_path.join("package.json").exists();
     let has_backend = backend_path.join("requirements.txt").exists()
+        || backend_path.join("pyproject.toml").exists()
         || backend_path.join("main.py").exists();
 
     let frontend_port = if has_frontend {
@@ -138,15 +489,54 @@ fn detect_project(project_path: String) -> Result<DetectedProject, String> {
         .unwrap_or("Unknown")
         .to_string();
 
+    let frontend_command = has_frontend.then(|| {
+        format!("{} run dev", node::detect_package_manager(&frontend_path).program())
+    });
+    let backend_command = backend_port.map(|port| backend_start_command(&backend_path, port));
+
+    // Warn about stale or missing installs before a service start fails on them
+    let mut drift = Vec::new();
+    if has_frontend {
+        drift.extend(deps::lockfile_drift(&frontend_path));
+    }
+    if has_backend {
+        drift.extend(deps::lockfile_drift(&backend_path));
+    }
+
     Ok(DetectedProject {
         has_frontend,
         has_backend,
         frontend_port,
         backend_port,
         project_name,
+        frontend_command,
+        backend_command,
+        drift,
     })
 }
 
+#[tauri::command]
+async fn detect_project(app: AppHandle, project_path: String) -> Result<DetectedProject, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let project = detect_project_blocking(project_path.clone())?;
+        watch::record(&app, &project_path, &project);
+        Ok(project)
+    })
+    .await
+}
+
+fn backend_start_command(backend_path: &Path, port: u16) -> String {
+    let module = if backend_path.join("main.py").exists() { "main" } else { "app" };
+    let uvicorn = match python::detect_python_manager(backend_path) {
+        python::PythonPackageManager::Uv => "uv run uvicorn".to_string(),
+        _ => python::venv_bin(Path::new(".venv"), "uvicorn")
+            .to_string_lossy()
+            .replace('\\', "/"),
+    };
+    format!("{} {}:app --reload --port {}", uvicorn, module, port)
+}
+
 fn detect_port(path: &Path, service_type: &str) -> Option<u16> {
     if service_type == "frontend" {
         for ext in &["ts", "js"] {
@@ -174,7 +564,7 @@ fn extract_port(content: &str) -> Option<u16> {
         if line.contains("port") || line.contains("PORT") {
             for word in line.split(|c: char| !c.is_ascii_digit()) {
                 if let Ok(port) = word.parse::<u16>() {
-                    if port >= 1024 && port <= 65535 {
+                    if port >= 1024 {
                         return Some(port);
                     }
                 }
@@ -184,601 +574,304 @@ fn extract_port(content: &str) -> Option<u16> {
     None
 }
 
+// Each option is its own IPC argument, which is what the frontend sends
+#[allow(clippy::too_many_arguments)]
 #[tauri::command]
-fn create_project(
+async fn create_project(
     project_path: String,
     project_name: String,
     frontend_port: u16,
     backend_port: u16,
-) -> Result<String, String> {
-    let base = Path::new(&project_path);
-    let frontend = base.join("frontend");
-    let backend = base.join("backend");
-
-    // Create directories
-    fs::create_dir_all(&frontend).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&backend).map_err(|e| e.to_string())?;
-
-    // ========== FRONTEND ==========
-    let frontend_package = format!(r#"{{
-  "name": "{}-frontend",
-  "private": true,
-  "version": "0.1.0",
-  "type": "module",
-  "scripts": {{
-    "dev": "vite --host 127.0.0.1 --port {}",
-    "build": "tsc -b && vite build",
-    "preview": "vite preview"
-  }},
-  "dependencies": {{
-    "react": "^19.1.0",
-    "react-dom": "^19.1.0"
-  }},
-  "devDependencies": {{
-    "@types/react": "^19.1.6",
-    "@types/react-dom": "^19.1.5",
-    "@vitejs/plugin-react": "^4.5.0",
-    "autoprefixer": "^10.4.21",
-    "postcss": "^8.5.3",
-    "tailwindcss": "^3.4.17",
-    "typescript": "~5.8.3",
-    "vite": "^7.0.0"
-  }}
-}}"#, project_name.to_lowercase().replace(" ", "-"), frontend_port);
-
-    fs::write(frontend.join("package.json"), frontend_package).map_err(|e| e.to_string())?;
-
-    // .env.example
-    let env_example = format!("VITE_API_URL=http://127.0.0.1:{}", backend_port);
-    fs::write(frontend.join(".env.example"), &env_example).map_err(|e| e.to_string())?;
-    fs::write(frontend.join(".env"), &env_example).map_err(|e| e.to_string())?;
-
-    let vite_config = format!(r#"import {{ defineConfig }} from "vite";
-import react from "@vitejs/plugin-react";
-
-export default defineConfig({{
-  plugins: [react()],
-  server: {{
-    host: "127.0.0.1",
-    port: {},
-    strictPort: true,
-  }},
-}});"#, frontend_port);
-
-    fs::write(frontend.join("vite.config.ts"), vite_config).map_err(|e| e.to_string())?;
-
-    let index_html = format!(r#"<!doctype html>
-<html lang="en">
-  <head>
-    <meta charset="UTF-8" />
-    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
-    <title>{}</title>
-  </head>
-  <body>
-    <div id="root"></div>
-    <script type="module" src="/src/main.tsx"></script>
-  </body>
-</html>"#, project_name);
-
-    fs::write(frontend.join("index.html"), index_html).map_err(|e| e.to_string())?;
-
-    let tsconfig = r#"{
-  "compilerOptions": {
-    "target": "ES2020",
-    "useDefineForClassFields": true,
-    "lib": ["ES2020", "DOM", "DOM.Iterable"],
-    "module": "ESNext",
-    "skipLibCheck": true,
-    "moduleResolution": "bundler",
-    "allowImportingTsExtensions": true,
-    "verbatimModuleSyntax": true,
-    "noEmit": true,
-    "jsx": "react-jsx",
-    "strict": true,
-    "baseUrl": ".",
-    "paths": {
-      "@/*": ["src/*"]
-    }
-  },
-  "include": ["src"]
-}"#;
-
-    fs::write(frontend.join("tsconfig.json"), tsconfig).map_err(|e| e.to_string())?;
-
-    let tailwind_config = r#"export default {
-  content: ["./index.html", "./src/**/*.{js,ts,jsx,tsx}"],
-  theme: { extend: {} },
-  plugins: [],
-}"#;
-
-    fs::write(frontend.join("tailwind.config.js"), tailwind_config).map_err(|e| e.to_string())?;
-
-    let postcss_config = r#"export default {
-  plugins: { tailwindcss: {}, autoprefixer: {} },
-}"#;
-
-    fs::write(frontend.join("postcss.config.js"), postcss_config).map_err(|e| e.to_string())?;
-
-    // Create src directories
-    let src = frontend.join("src");
-    let api_dir = src.join("api");
-    let hooks_dir = src.join("hooks");
-    let types_dir = src.join("types");
-    fs::create_dir_all(&api_dir).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&hooks_dir).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&types_dir).map_err(|e| e.to_string())?;
-
-    // API Client
-    let api_client = r#"const API_URL = import.meta.env.VITE_API_URL || 'http://127.0.0.1:8000';
-
-export interface ApiResponse<T> {
-  data: T | null;
-  error: string | null;
-}
-
-async function request<T>(
-  endpoint: string,
-  options: RequestInit = {}
-): Promise<ApiResponse<T>> {
-  try {
-    const response = await fetch(`${API_URL}${endpoint}`, {
-      headers: {
-        'Content-Type': 'application/json',
-        ...options.headers,
-      },
-      ...options,
-    });
-
-    if (!response.ok) {
-      const error = await response.text();
-      return { data: null, error: error || `HTTP ${response.status}` };
-    }
-
-    const data = await response.json();
-    return { data, error: null };
-  } catch (err) {
-    return { data: null, error: err instanceof Error ? err.message : 'Unknown error' };
-  }
-}
-
-export const api = {
-  get: <T>(endpoint: string) => request<T>(endpoint),
-
-  post: <T>(endpoint: string, body: unknown) =>
-    request<T>(endpoint, {
-      method: 'POST',
-      body: JSON.stringify(body),
-    }),
-
-  put: <T>(endpoint: string, body: unknown) =>
-    request<T>(endpoint, {
-      method: 'PUT',
-      body: JSON.stringify(body),
-    }),
-
-  delete: <T>(endpoint: string) =>
-    request<T>(endpoint, { method: 'DELETE' }),
-};
-"#;
-
-    fs::write(api_dir.join("client.ts"), api_client).map_err(|e| e.to_string())?;
-
-    // useApi Hook
-    let use_api = r#"import { useState, useEffect, useCallback } from 'react';
-import { api } from '../api/client';
-
-interface UseApiState<T> {
-  data: T | null;
-  loading: boolean;
-  error: string | null;
-}
-
-export function useApi<T>(endpoint: string) {
-  const [state, setState] = useState<UseApiState<T>>({
-    data: null,
-    loading: true,
-    error: null,
-  });
-
-  const fetchData = useCallback(async () => {
-    setState(prev => ({ ...prev, loading: true, error: null }));
-    const { data, error } = await api.get<T>(endpoint);
-    setState({ data, loading: false, error });
-  }, [endpoint]);
-
-  useEffect(() => {
-    fetchData();
-  }, [fetchData]);
-
-  return { ...state, refetch: fetchData };
-}
-
-export function useMutation<T, B = unknown>(endpoint: string, method: 'post' | 'put' | 'delete' = 'post') {
-  const [state, setState] = useState<UseApiState<T>>({
-    data: null,
-    loading: false,
-    error: null,
-  });
-
-  const mutate = useCallback(async (body?: B) => {
-    setState(prev => ({ ...prev, loading: true, error: null }));
-
-    let result;
-    if (method === 'post') {
-      result = await api.post<T>(endpoint, body);
-    } else if (method === 'put') {
-      result = await api.put<T>(endpoint, body);
-    } else {
-      result = await api.delete<T>(endpoint);
-    }
-
-    setState({ data: result.data, loading: false, error: result.error });
-    return result;
-  }, [endpoint, method]);
-
-  return { ...state, mutate };
-}
-"#;
-
-    fs::write(hooks_dir.join("useApi.ts"), use_api).map_err(|e| e.to_string())?;
-
-    // Types
-    let types = r#"export interface Item {
-  id: number;
-  name: string;
-  description: string | null;
-  created_at: string;
-}
-
-export interface CreateItem {
-  name: string;
-  description?: string;
-}
-
-export interface HealthResponse {
-  status: string;
-}
-"#;
-
-    fs::write(types_dir.join("index.ts"), types).map_err(|e| e.to_string())?;
-
-    let main_tsx = r#"import React from 'react';
-import ReactDOM from 'react-dom/client';
-import App from './App';
-import './index.css';
-
-ReactDOM.createRoot(document.getElementById('root')!).render(
-  <React.StrictMode>
-    <App />
-  </React.StrictMode>,
-);"#;
-
-    fs::write(src.join("main.tsx"), main_tsx).map_err(|e| e.to_string())?;
-
-    let app_tsx = format!(r#"import {{ useState }} from 'react';
-import {{ useApi, useMutation }} from './hooks/useApi';
-import type {{ Item, CreateItem, HealthResponse }} from './types';
-
-function App() {{
-  const {{ data: health }} = useApi<HealthResponse>('/health');
-  const {{ data: items, loading, error, refetch }} = useApi<Item[]>('/items');
-  const {{ mutate: createItem, loading: creating }} = useMutation<Item, CreateItem>('/items', 'post');
-
-  const [newItem, setNewItem] = useState('');
-
-  const handleCreate = async () => {{
-    if (!newItem.trim()) return;
-    const result = await createItem({{ name: newItem }});
-    if (!result.error) {{
-      setNewItem('');
-      refetch();
-    }}
-  }};
-
-  return (
-    <div className="min-h-screen bg-slate-900 p-8">
-      <div className="max-w-2xl mx-auto">
-        <div className="flex justify-between items-center mb-8">
-          <h1 className="text-3xl font-bold text-white">{}</h1>
-          <span className={{`px-3 py-1 rounded-full text-sm ${{
-            health?.status === 'healthy' ? 'bg-green-500/20 text-green-400' : 'bg-red-500/20 text-red-400'
-          }}`}}>
-            {{health?.status || 'checking...'}}
-          </span>
-        </div>
-
-        <div className="bg-slate-800 rounded-lg p-6 mb-6">
-          <h2 className="text-lg font-semibold text-white mb-4">Add Item</h2>
-          <div className="flex gap-3">
-            <input
-              type="text"
-              value={{newItem}}
-              onChange={{(e) => setNewItem(e.target.value)}}
-              placeholder="Item name..."
-              className="flex-1 px-4 py-2 bg-slate-700 border border-slate-600 rounded-lg text-white placeholder-slate-400 focus:outline-none focus:border-blue-500"
-              onKeyDown={{(e) => e.key === 'Enter' && handleCreate()}}
-            />
-            <button
-              onClick={{handleCreate}}
-              disabled={{creating}}
-              className="px-6 py-2 bg-blue-600 hover:bg-blue-700 disabled:opacity-50 text-white rounded-lg font-medium transition-colors"
-            >
-              {{creating ? 'Adding...' : 'Add'}}
-            </button>
-          </div>
-        </div>
-
-        <div className="bg-slate-800 rounded-lg p-6">
-          <h2 className="text-lg font-semibold text-white mb-4">Items</h2>
-          {{loading ? (
-            <p className="text-slate-400">Loading...</p>
-          ) : error ? (
-            <p className="text-red-400">{{error}}</p>
-          ) : items?.length === 0 ? (
-            <p className="text-slate-400">No items yet. Add one above!</p>
-          ) : (
-            <ul className="space-y-2">
-              {{items?.map((item) => (
-                <li key={{item.id}} className="flex justify-between items-center p-3 bg-slate-700/50 rounded-lg">
-                  <span className="text-white">{{item.name}}</span>
-                  <span className="text-slate-500 text-sm">{{new Date(item.created_at).toLocaleDateString()}}</span>
-                </li>
-              ))}}
-            </ul>
-          )}}
-        </div>
-      </div>
-    </div>
-  );
-}}
-
-export default App;"#, project_name);
-
-    fs::write(src.join("App.tsx"), app_tsx).map_err(|e| e.to_string())?;
-
-    let index_css = r#"@tailwind base;
-@tailwind components;
-@tailwind utilities;
-
-body {
-  margin: 0;
-  font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif;
-}"#;
-
-    fs::write(src.join("index.css"), index_css).map_err(|e| e.to_string())?;
-
-    // ========== BACKEND ==========
-    let routes_dir = backend.join("routes");
-    let models_dir = backend.join("models");
-    let schemas_dir = backend.join("schemas");
-    fs::create_dir_all(&routes_dir).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&models_dir).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&schemas_dir).map_err(|e| e.to_string())?;
-
-    // .env.example
-    let backend_env = format!(r#"DATABASE_URL=sqlite:///./app.db
-BACKEND_PORT={}
-"#, backend_port);
-    fs::write(backend.join(".env.example"), &backend_env).map_err(|e| e.to_string())?;
-    fs::write(backend.join(".env"), &backend_env).map_err(|e| e.to_string())?;
-
-    // database.py
-    let database_py = r#"from sqlalchemy import create_engine
-from sqlalchemy.ext.declarative import declarative_base
-from sqlalchemy.orm import sessionmaker
-import os
-
-DATABASE_URL = os.getenv("DATABASE_URL", "sqlite:///./app.db")
-
-engine = create_engine(DATABASE_URL, connect_args={"check_same_thread": False})
-SessionLocal = sessionmaker(autocommit=False, autoflush=False, bind=engine)
-Base = declarative_base()
-
-def get_db():
-    db = SessionLocal()
-    try:
-        yield db
-    finally:
-        db.close()
-"#;
-
-    fs::write(backend.join("database.py"), database_py).map_err(|e| e.to_string())?;
-
-    // models/__init__.py
-    let models_init = r#"from .item import Item
-"#;
-    fs::write(models_dir.join("__init__.py"), models_init).map_err(|e| e.to_string())?;
-
-    // models/item.py
-    let item_model = r#"from sqlalchemy import Column, Integer, String, DateTime
-from sqlalchemy.sql import func
-from database import Base
-
-class Item(Base):
-    __tablename__ = "items"
-
-    id = Column(Integer, primary_key=True, index=True)
-    name = Column(String, nullable=False)
-    description = Column(String, nullable=True)
-    created_at = Column(DateTime(timezone=True), server_default=func.now())
-"#;
-
-    fs::write(models_dir.join("item.py"), item_model).map_err(|e| e.to_string())?;
-
-    // schemas/__init__.py
-    let schemas_init = r#"from .item import ItemCreate, ItemResponse
-"#;
-    fs::write(schemas_dir.join("__init__.py"), schemas_init).map_err(|e| e.to_string())?;
-
-    // schemas/item.py
-    let item_schema = r#"from pydantic import BaseModel
-from datetime import datetime
-from typing import Optional
-
-class ItemCreate(BaseModel):
-    name: str
-    description: Optional[str] = None
-
-class ItemResponse(BaseModel):
-    id: int
-    name: str
-    description: Optional[str]
-    created_at: datetime
-
-    class Config:
-        from_attributes = True
-"#;
-
-    fs::write(schemas_dir.join("item.py"), item_schema).map_err(|e| e.to_string())?;
-
-    // routes/__init__.py
-    let routes_init = r#"from .items import router as items_router
-"#;
-    fs::write(routes_dir.join("__init__.py"), routes_init).map_err(|e| e.to_string())?;
-
-    // routes/items.py
-    let items_route = r#"from fastapi import APIRouter, Depends, HTTPException
-from sqlalchemy.orm import Session
-from typing import List
-
-from database import get_db
-from models import Item
-from schemas import ItemCreate, ItemResponse
-
-router = APIRouter(prefix="/items", tags=["items"])
-
-@router.get("", response_model=List[ItemResponse])
-def get_items(db: Session = Depends(get_db)):
-    return db.query(Item).order_by(Item.created_at.desc()).all()
-
-@router.get("/{item_id}", response_model=ItemResponse)
-def get_item(item_id: int, db: Session = Depends(get_db)):
-    item = db.query(Item).filter(Item.id == item_id).first()
-    if not item:
-        raise HTTPException(status_code=404, detail="Item not found")
-    return item
-
-@router.post("", response_model=ItemResponse)
-def create_item(item: ItemCreate, db: Session = Depends(get_db)):
-    db_item = Item(**item.model_dump())
-    db.add(db_item)
-    db.commit()
-    db.refresh(db_item)
-    return db_item
-
-@router.delete("/{item_id}")
-def delete_item(item_id: int, db: Session = Depends(get_db)):
-    item = db.query(Item).filter(Item.id == item_id).first()
-    if not item:
-        raise HTTPException(status_code=404, detail="Item not found")
-    db.delete(item)
-    db.commit()
-    return {"message": "Item deleted"}
-"#;
-
-    fs::write(routes_dir.join("items.py"), items_route).map_err(|e| e.to_string())?;
-
-    // main.py
-    let main_py = format!(r#"from fastapi import FastAPI
-from fastapi.middleware.cors import CORSMiddleware
-from dotenv import load_dotenv
-
-from database import engine, Base
-from routes import items_router
-
-load_dotenv()
-
-# Create tables
-Base.metadata.create_all(bind=engine)
-
-app = FastAPI(title="{}")
-
-app.add_middleware(
-    CORSMiddleware,
-    allow_origins=["*"],
-    allow_credentials=True,
-    allow_methods=["*"],
-    allow_headers=["*"],
-)
-
-app.include_router(items_router)
-
-@app.get("/health")
-async def health():
-    return {{"status": "healthy"}}
-
-@app.get("/")
-async def root():
-    return {{"message": "Welcome to {}"}}
-"#, project_name, project_name);
-
-    fs::write(backend.join("main.py"), main_py).map_err(|e| e.to_string())?;
-
-    let requirements = r#"fastapi>=0.115.0
-uvicorn[standard]>=0.34.0
-sqlalchemy>=2.0.0
-python-dotenv>=1.0.0
-"#;
-
-    fs::write(backend.join("requirements.txt"), requirements).map_err(|e| e.to_string())?;
-
-    let readme = format!(r#"# {} Backend
-
-## Setup
+    python_manager: Option<String>,
+    // Adds a Playwright setup that exercises the items flow
+    include_e2e: Option<bool>,
+    // Writes .devcontainer/devcontainer.json for VS Code Dev Containers and Codespaces
+    include_devcontainer: Option<bool>,
+    // Adds an ItemService gRPC API from a shared .proto: grpcio plus gRPC-web on the
+    // backend, a generated protobuf-ts client on the frontend
+    include_grpc: Option<bool>,
+    // Lets cancel_operation stop the scaffold; whatever was written is then removed
+    operation_id: Option<String>,
+) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = paths::new_path(&project_path)?;
+        let project_name = project_name.trim().to_string();
+        if project_name.is_empty() || project_name.chars().any(char::is_control) {
+            return Err(Error::invalid(
+                "invalid_project_name",
+                "Project name must not be empty or contain line breaks",
+            )
+            .with("name", &project_name));
+        }
+        let base = Path::new(&project_path);
+        let use_uv = python_manager.as_deref() == Some("uv");
+        let include_e2e = include_e2e.unwrap_or(false);
+        let include_grpc = include_grpc.unwrap_or(false);
 
-```bash
-python -m venv .venv
+        let (setup, run) = if use_uv {
+            ("uv sync".to_string(), format!("uv run uvicorn main:app --reload --port {}", backend_port))
+        } else {
+            (
+                r#"python -m venv .venv
 .venv/Scripts/activate  # Windows
 # source .venv/bin/activate  # Linux/Mac
-pip install -r requirements.txt
-```
-
-## Run
-
-```bash
-uvicorn main:app --reload --port {}
-```
-
-## API Docs
+pip install -r requirements.txt"#.to_string(),
+                format!("uvicorn main:app --reload --port {}", backend_port),
+            )
+        };
+        let package_name = scaffold::package_slug(&project_name);
+        let frontend_port = frontend_port.to_string();
+        let backend_port = backend_port.to_string();
+        let vars = [
+            ("project_name", project_name.as_str()),
+            ("package_name", package_name.as_str()),
+            ("frontend_port", frontend_port.as_str()),
+            ("backend_port", backend_port.as_str()),
+            ("setup", setup.as_str()),
+            ("run", run.as_str()),
+            ("e2e", if include_e2e { "true" } else { "" }),
+            ("grpc", if include_grpc { "true" } else { "" }),
+        ];
+
+        // Both trees are assembled in memory and written out concurrently at the end
+        let mut frontend = scaffold::Tree::new(base.join("frontend"));
+        frontend.add_template(&scaffold::FULLSTACK, "frontend", &vars, &[])?;
+        if include_e2e {
+            frontend.add_template(&scaffold::E2E, "frontend", &vars, &[])?;
+        }
+        if include_grpc {
+            frontend.add_template(&scaffold::GRPC, "frontend", &vars, &[])?;
+        }
 
-Once running, visit:
-- Swagger UI: http://127.0.0.1:{}/docs
-- ReDoc: http://127.0.0.1:{}/redoc
+        // uv projects keep dependencies in pyproject.toml with the interpreter pinned
+        let skip: &[&str] = if use_uv {
+            &["requirements.txt"]
+        } else {
+            &["pyproject.toml", ".python-version"]
+        };
+        let mut backend = scaffold::Tree::new(base.join("backend"));
+        backend.add_template(&scaffold::FULLSTACK, "backend", &vars, skip)?;
+        if include_grpc {
+            backend.add_template(&scaffold::GRPC, "backend", &vars, &[])?;
+        }
 
-## Project Structure
+        let mut trees = vec![frontend, backend];
+        if include_devcontainer.unwrap_or(false) {
+            let mut devcontainer = scaffold::Tree::new(base.join(".devcontainer"));
+            let frontend = devcontainer::Frontend {
+                dir: "frontend".to_string(),
+                port: frontend_port.parse().ok(),
+                node: None,
+                manager: node::PackageManager::Npm,
+            };
+            let backend = devcontainer::Backend {
+                dir: "backend".to_string(),
+                port: backend_port.parse().ok(),
+                python: Some("3.12".to_string()),
+                manager: if use_uv {
+                    python::PythonPackageManager::Uv
+                } else {
+                    python::PythonPackageManager::Pip
+                },
+            };
+            devcontainer.add(
+                "devcontainer.json",
+                devcontainer::render(&project_name, Some(&frontend), Some(&backend)),
+            );
+            trees.push(devcontainer);
+        }
 
-```
-backend/
-├── main.py          # FastAPI app entry point
-├── database.py      # SQLAlchemy setup
-├── models/          # Database models
-│   └── item.py
-├── schemas/         # Pydantic schemas
-│   └── item.py
-└── routes/          # API routes
-    └── items.py
-```
-"#, project_name, backend_port, backend_port, backend_port);
+        let operation = operation_id.as_deref().map(cancel::begin);
+        let token = operation.as_ref().map(|o| o.token().clone()).unwrap_or_default();
+        scaffold::write_all(&trees, &token)?;
 
-    fs::write(backend.join("README.md"), readme).map_err(|e| e.to_string())?;
+        Ok(format!("Project created at {}", project_path))
+    })
+    .await
+}
 
-    Ok(format!("Project created at {}", project_path))
+// Every IPC call gets a span, so backend log lines can be tied to the command that caused them
+fn traced_handler<F>(handler: F) -> impl Fn(tauri::ipc::Invoke) -> bool + Send + Sync + 'static
+where
+    F: Fn(tauri::ipc::Invoke) -> bool + Send + Sync + 'static,
+{
+    move |invoke| {
+        let command = invoke.message.command().to_string();
+        crash::record_command(&command);
+        telemetry::record(&command);
+        audit::set_initiator(Some(command.clone()));
+        let span = tracing::info_span!("command", name = %command);
+        let _entered = span.enter();
+        let started = std::time::Instant::now();
+        let handled = handler(invoke);
+        audit::set_initiator(None);
+        tracing::debug!(elapsed_ms = started.elapsed().as_millis() as u64, "dispatched");
+        handled
+    }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // Must be registered first so a second launch exits before doing any work
+        .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            instance::on_second_instance(app, argv, cwd);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .manage(ProcessManager {
             processes: Mutex::new(HashMap::new()),
+            launches: Mutex::new(HashMap::new()),
+            history: Mutex::new(VecDeque::new()),
+            stacks: Mutex::new(HashMap::new()),
+            logs: Mutex::new(HashMap::new()),
+            dependencies: Mutex::new(HashMap::new()),
+            mocks: Mutex::new(HashMap::new()),
+        })
+        .manage(instance::PendingOpen::default())
+        .manage(deeplink::PendingCreate::default())
+        .manage(shortcuts::ActiveProject::default())
+        .manage(watch::DetectionWatch::default())
+        .setup(|app| {
+            // Both read their files on first use, so managing them here costs nothing
+            app.manage(projects::ProjectRegistry::new(app.handle()));
+            app.manage(settings::SettingsState::new(app.handle()));
+            app.manage(logging::init(app.handle()));
+            crash::install(app.handle());
+            audit::init(app.handle());
+            tray::init(app.handle())?;
+            // Nothing here is needed to show the window, so it loads off the setup path
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn_blocking(move || {
+                let current = handle.state::<settings::SettingsState>().get();
+                let logs = handle.state::<logging::LogState>();
+                if let Err(e) = logs.set_level(&current.log_level) {
+                    tracing::warn!("Could not apply the log level: {}", e);
+                }
+                logs.prune(current.log_retention_days);
+                telemetry::init(&handle, current.telemetry.record);
+                let shortcut_handle = handle.clone();
+                let _ = handle.run_on_main_thread(move || {
+                    if let Err(e) = shortcuts::apply(&shortcut_handle, &current.shortcuts) {
+                        tracing::warn!("Global shortcuts unavailable: {}", e);
+                    }
+                });
+                reaper::start(handle);
+            });
+            instance::handle_launch_args(app.handle());
+            if let Err(e) = deeplink::init(app.handle()) {
+                tracing::warn!("Deep links unavailable: {}", e);
+            }
+            tracing::info!(version = env!("CARGO_PKG_VERSION"), "devLLM starting");
+            Ok(())
+        })
+        // With the tray available, closing the window keeps services running in the background
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                let _ = window.hide();
+                api.prevent_close();
+            }
         })
-        .invoke_handler(tauri::generate_handler![
+        .invoke_handler(traced_handler(tauri::generate_handler![
             create_project,
             start_service,
             stop_service,
-            detect_project
-        ])
+            detect_project,
+            appdata::export_app_data,
+            appdata::import_app_data,
+            audit::get_audit_log,
+            autostart::set_autostart,
+            autostart::autostart_project,
+            backup::backup_database,
+            backup::list_backups,
+            backup::restore_database,
+            cache::get_cache_usage,
+            cache::clear_caches,
+            cancel::cancel_operation,
+            crash::list_crash_reports,
+            crash::read_crash_report,
+            db::list_tables,
+            db::get_table_schema,
+            db::run_query,
+            db::test_db_connection,
+            deeplink::take_pending_create,
+            deps::check_outdated,
+            deps::add_dependency,
+            deps::remove_dependency,
+            deps::check_lockfile_drift,
+            er::get_er_diagram,
+            export::export_table,
+            graph::get_dependency_graph,
+            instance::take_pending_project,
+            lan::set_lan_access,
+            lan::check_lan_access,
+            licenses::scan_licenses,
+            logging::get_app_logs,
+            manifest::get_manifest,
+            migrations::generate_migration,
+            migrations::run_migrations,
+            migrations::get_migration_status,
+            modelgen::generate_models,
+            node::install_node_deps,
+            pg_migrate::migrate_sqlite_to_postgres,
+            preflight::preflight_service,
+            preflight::free_port,
+            reaper::get_service_history,
+            python::create_venv,
+            python::install_python_deps,
+            pyproject::migrate_to_pyproject,
+            reset::reset_database,
+            security::audit_dependencies,
+            seed::seed_database,
+            settings::get_settings,
+            settings::update_settings,
+            shortcuts::set_active_project,
+            stats::get_table_stats,
+            telemetry::get_telemetry_preview,
+            telemetry::purge_telemetry,
+            toolchain::doctor,
+            toolchain::detect_version_managers,
+            toolchain::install_node_version,
+            toolchain::install_python_version,
+            tools::check_service_tools,
+            testing::run_tests,
+            lint::run_linter,
+            typecheck::run_type_check,
+            format::format_project,
+            bench::benchmark_endpoint,
+            testing::run_e2e,
+            docker::detect_docker,
+            list_services,
+            compose::start_stack,
+            compose::stop_stack,
+            compose::get_stack_status,
+            images::build_image,
+            images::list_images,
+            devcontainer::generate_devcontainer,
+            devdeps::start_dev_dependency,
+            devdeps::stop_dev_dependency,
+            resources::get_container_stats,
+            cleanup::docker_cleanup,
+            openapi::sync_api_types,
+            contract::check_contract,
+            collection::export_api_collection,
+            graphql::introspect_graphql,
+            mock::start_mock_server,
+            mock::stop_mock_server,
+            requestbin::start_request_bin,
+            requestbin::stop_request_bin,
+            requestbin::get_request_bin_requests,
+            sse::test_sse,
+            sdk::generate_sdk,
+            architecture::export_architecture,
+            servicelog::get_service_logs,
+            servicelog::clear_service_logs,
+            start_assistant_service,
+            projects::sync_projects
+        ]))
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::deps;
+use crate::error::Error;
+use crate::paths;
+use crate::python;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct LicensedPackage {
+    service: &'static str,
+    ecosystem: &'static str,
+    name: String,
+    version: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct LicenseGroup {
+    license: String,
+    // "strong", "weak" or "network" copyleft; None for permissive or unknown
+    copyleft: Option<&'static str>,
+    packages: Vec<LicensedPackage>,
+}
+
+#[derive(serde::Serialize)]
+pub struct LicenseReport {
+    total_packages: usize,
+    groups: Vec<LicenseGroup>,
+    copyleft_packages: usize,
+    unknown_packages: usize,
+    errors: Vec<String>,
+}
+
+const UNKNOWN: &str = "UNKNOWN";
+
+fn copyleft_kind(license: &str) -> Option<&'static str> {
+    let upper = license.to_uppercase();
+    // Dual-licensed packages can be used under the permissive option
+    if upper.contains(" OR ") && upper.split(" OR ").any(|l| copyleft_kind(l).is_none()) {
+        return None;
+    }
+    if upper.contains("AGPL") || upper.contains("AFFERO") || upper.contains("SSPL") {
+        Some("network")
+    } else if upper.contains("LGPL")
+        || upper.contains("LESSER GENERAL")
+        || upper.contains("MPL")
+        || upper.contains("MOZILLA")
+        || upper.contains("EPL")
+        || upper.contains("ECLIPSE")
+        || upper.contains("CDDL")
+    {
+        Some("weak")
+    } else if upper.contains("GPL")
+        || upper.contains("GENERAL PUBLIC")
+        || upper.contains("EUPL")
+        || upper.contains("OSL")
+    {
+        Some("strong")
+    } else {
+        None
+    }
+}
+
+#[tauri::command]
+pub async fn scan_licenses(project_path: String) -> Result<LicenseReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let path = Path::new(&project_path);
+
+        let (frontend, backend) = deps::service_dirs(path);
+        let mut found: Vec<(String, LicensedPackage)> = Vec::new();
+        let mut errors = Vec::new();
+
+        if let Some(frontend) = frontend {
+            let modules = frontend.join("node_modules");
+            if modules.is_dir() {
+                collect_node_licenses(&modules, &mut found);
+            } else {
+                errors.push("Frontend dependencies are not installed".to_string());
+            }
+        }
+
+        if let Some(backend) = backend {
+            let distributions = python::installed_distributions(&backend);
+            if distributions.is_empty() {
+                errors.push("Backend has no installed packages to scan".to_string());
+            }
+            for (name, (dist_info, version)) in distributions {
+                let license = python_license(&dist_info).unwrap_or_else(|| UNKNOWN.to_string());
+                found.push((
+                    license,
+                    LicensedPackage {
+                        service: "backend",
+                        ecosystem: "pypi",
+                        name,
+                        version,
+                    },
+                ));
+            }
+        }
+
+        let total_packages = found.len();
+        let mut grouped: BTreeMap<String, Vec<LicensedPackage>> = BTreeMap::new();
+        for (license, package) in found {
+            grouped.entry(license).or_default().push(package);
+        }
+
+        let mut groups: Vec<LicenseGroup> = grouped
+            .into_iter()
+            .map(|(license, mut packages)| {
+                packages.sort_by(|a, b| a.name.cmp(&b.name));
+                LicenseGroup {
+                    copyleft: copyleft_kind(&license),
+                    license,
+                    packages,
+                }
+            })
+            .collect();
+        // Most used licenses first
+        groups.sort_by_key(|g| std::cmp::Reverse(g.packages.len()));
+
+        let copyleft_packages = groups
+            .iter()
+            .filter(|g| g.copyleft.is_some())
+            .map(|g| g.packages.len())
+            .sum();
+        let unknown_packages = groups
+            .iter()
+            .filter(|g| g.license == UNKNOWN)
+            .map(|g| g.packages.len())
+            .sum();
+
+        Ok(LicenseReport {
+            total_packages,
+            groups,
+            copyleft_packages,
+            unknown_packages,
+            errors,
+        })
+    })
+    .await
+}
+
+// Walks node_modules recursively so nested (conflicting) versions are counted too
+fn collect_node_licenses(modules: &Path, found: &mut Vec<(String, LicensedPackage)>) {
+    let Ok(entries) = fs::read_dir(modules) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') {
+            continue;
+        }
+        if name.starts_with('@') {
+            collect_node_licenses(&dir, found);
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(dir.join("package.json")) else {
+            continue;
+        };
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+            continue;
+        };
+
+        found.push((
+            node_license(&json).unwrap_or_else(|| UNKNOWN.to_string()),
+            LicensedPackage {
+                service: "frontend",
+                ecosystem: "npm",
+                name: json["name"].as_str().unwrap_or(&name).to_string(),
+                version: json["version"].as_str().unwrap_or_default().to_string(),
+            },
+        ));
+
+        let nested = dir.join("node_modules");
+        if nested.is_dir() {
+            collect_node_licenses(&nested, found);
+        }
+    }
+}
+
+fn node_license(json: &serde_json::Value) -> Option<String> {
+    match &json["license"] {
+        serde_json::Value::String(license) => return Some(license.clone()),
+        // Legacy { "type": "MIT", "url": ... } form
+        serde_json::Value::Object(license) => {
+            return license.get("type")?.as_str().map(String::from);
+        }
+        _ => {}
+    }
+
+    let types: Vec<&str> = json["licenses"]
+        .as_array()?
+        .iter()
+        .filter_map(|l| l["type"].as_str())
+        .collect();
+    if types.is_empty() {
+        None
+    } else {
+        Some(types.join(" OR "))
+    }
+}
+
+// Prefers the PEP 639 expression, then the License field, then trove classifiers
+fn python_license(dist_info: &Path) -> Option<String> {
+    let metadata = fs::read_to_string(dist_info.join("METADATA")).ok()?;
+    let mut license = None;
+    let mut classifiers = Vec::new();
+
+    for line in metadata.lines() {
+        // Headers end at the first blank line; the rest is the long description
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("License-Expression:") {
+            return Some(value.trim().to_string());
+        }
+        if let Some(value) = line.strip_prefix("License:") {
+            let value = value.trim();
+            // Some packages paste the full license text here
+            if !value.is_empty() && value != UNKNOWN && value.len() <= 60 {
+                license = Some(value.to_string());
+            }
+        }
+        if let Some(value) = line.strip_prefix("Classifier: License ::") {
+            if let Some(name) = value.rsplit("::").next() {
+                let name = name.trim();
+                if name != "OSI Approved" {
+                    classifiers.push(name.to_string());
+                }
+            }
+        }
+    }
+
+    license.or_else(|| {
+        if classifiers.is_empty() {
+            None
+        } else {
+            Some(classifiers.join(" OR "))
+        }
+    })
+}
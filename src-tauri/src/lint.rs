@@ -0,0 +1,286 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::deps;
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::task;
+use crate::tools;
+
+#[derive(serde::Serialize)]
+pub struct Diagnostic {
+    file: String,
+    line: u64,
+    column: u64,
+    end_line: Option<u64>,
+    end_column: Option<u64>,
+    rule: Option<String>,
+    // "error" or "warning"
+    severity: &'static str,
+    message: String,
+    fixable: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct LintReport {
+    service: String,
+    linter: &'static str,
+    fixed: bool,
+    errors: usize,
+    warnings: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+// Linters exit non-zero whenever they report something, so only unparseable output fails
+fn run_json(cmd: &mut Command, tool: &str) -> Result<serde_json::Value, Error> {
+    let output = exec::output(cmd).map_err(|e| exec::run_failed(tool, e))?;
+    serde_json::from_slice(&output.stdout).map_err(|_| {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let tail: Vec<&str> = stderr.trim().lines().rev().take(5).collect();
+        Error::tool_failed(
+            tool,
+            format!(
+                "{} failed: {}",
+                tool,
+                tail.into_iter().rev().collect::<Vec<_>>().join("\n")
+            ),
+        )
+    })
+}
+
+fn ruff(dir: &Path, fix: bool) -> Result<Vec<Diagnostic>, Error> {
+    let bin =
+        tools::python_tool(dir, "ruff").ok_or_else(|| tools::not_installed("ruff", "backend"))?;
+    let mut cmd = Command::new(bin);
+    cmd.args(["check", "--output-format=json", "--no-cache"])
+        .current_dir(dir);
+    if fix {
+        cmd.arg("--fix");
+    }
+    cmd.arg(".");
+
+    Ok(parse_ruff(dir, &run_json(&mut cmd, "ruff")?))
+}
+
+fn parse_ruff(dir: &Path, json: &serde_json::Value) -> Vec<Diagnostic> {
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .map(|d| Diagnostic {
+            file: paths::relative(dir, d["filename"].as_str().unwrap_or_default()),
+            line: d["location"]["row"].as_u64().unwrap_or(0),
+            column: d["location"]["column"].as_u64().unwrap_or(0),
+            end_line: d["end_location"]["row"].as_u64(),
+            end_column: d["end_location"]["column"].as_u64(),
+            rule: d["code"].as_str().map(String::from),
+            // Ruff has no severities; only syntax errors come without a rule code
+            severity: if d["code"].is_string() {
+                "warning"
+            } else {
+                "error"
+            },
+            message: d["message"].as_str().unwrap_or_default().to_string(),
+            fixable: !d["fix"].is_null(),
+        })
+        .collect()
+}
+
+fn eslint(dir: &Path, fix: bool) -> Result<Vec<Diagnostic>, Error> {
+    let bin =
+        tools::node_bin(dir, "eslint").ok_or_else(|| tools::not_installed("eslint", "frontend"))?;
+    let mut cmd = exec::tool_command(&bin.to_string_lossy());
+    cmd.args(["--format", "json"]).current_dir(dir);
+    if fix {
+        cmd.arg("--fix");
+    }
+    cmd.arg(".");
+
+    Ok(parse_eslint(dir, &run_json(&mut cmd, "eslint")?))
+}
+
+fn parse_eslint(dir: &Path, json: &serde_json::Value) -> Vec<Diagnostic> {
+    let mut found = Vec::new();
+    for file in json.as_array().into_iter().flatten() {
+        let path = paths::relative(dir, file["filePath"].as_str().unwrap_or_default());
+        for m in file["messages"].as_array().into_iter().flatten() {
+            found.push(Diagnostic {
+                file: path.clone(),
+                line: m["line"].as_u64().unwrap_or(0),
+                column: m["column"].as_u64().unwrap_or(0),
+                end_line: m["endLine"].as_u64(),
+                end_column: m["endColumn"].as_u64(),
+                rule: m["ruleId"].as_str().map(String::from),
+                severity: if m["severity"].as_u64() == Some(2) {
+                    "error"
+                } else {
+                    "warning"
+                },
+                message: m["message"].as_str().unwrap_or_default().to_string(),
+                fixable: m.get("fix").is_some()
+                    || m["suggestions"].as_array().is_some_and(|s| !s.is_empty()),
+            });
+        }
+    }
+    found
+}
+
+#[tauri::command]
+pub async fn run_linter(
+    project_path: String,
+    service: String,
+    fix: Option<bool>,
+) -> Result<LintReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let dir = deps::service_dir(Path::new(&project_path), &service)?;
+        let fix = fix.unwrap_or(false);
+
+        let (linter, diagnostics) = if dir.join("package.json").exists() {
+            ("eslint", eslint(&dir, fix)?)
+        } else {
+            ("ruff", ruff(&dir, fix)?)
+        };
+
+        let errors = diagnostics.iter().filter(|d| d.severity == "error").count();
+        tracing::info!(%project_path, %service, linter, fix, found = diagnostics.len(), "ran linter");
+        Ok(LintReport {
+            service,
+            linter,
+            fixed: fix,
+            errors,
+            warnings: diagnostics.len() - errors,
+            diagnostics,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    type Row<'a> = (&'a str, u64, u64, Option<&'a str>, &'static str, bool);
+
+    fn summary(diagnostics: &[Diagnostic]) -> Vec<Row<'_>> {
+        diagnostics
+            .iter()
+            .map(|d| {
+                let rule = d.rule.as_deref();
+                (
+                    d.file.as_str(),
+                    d.line,
+                    d.column,
+                    rule,
+                    d.severity,
+                    d.fixable,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reads_ruff_json() {
+        let json = json!([
+            {
+                "code": "F401",
+                "filename": "/app/backend/app/main.py",
+                "location": { "row": 1, "column": 8 },
+                "end_location": { "row": 1, "column": 10 },
+                "message": "`os` imported but unused",
+                "fix": { "applicability": "safe", "edits": [] }
+            },
+            {
+                "code": null,
+                "filename": "/app/backend/app/crud.py",
+                "location": { "row": 12, "column": 5 },
+                "end_location": { "row": 12, "column": 6 },
+                "message": "SyntaxError: Expected an expression",
+                "fix": null
+            }
+        ]);
+
+        let diagnostics = parse_ruff(Path::new("/app/backend"), &json);
+        assert_eq!(
+            summary(&diagnostics),
+            [
+                ("app/main.py", 1, 8, Some("F401"), "warning", true),
+                ("app/crud.py", 12, 5, None, "error", false),
+            ]
+        );
+        assert_eq!(diagnostics[0].message, "`os` imported but unused");
+        assert_eq!(diagnostics[0].end_column, Some(10));
+        assert!(parse_ruff(Path::new("/app/backend"), &json!([])).is_empty());
+    }
+
+    #[test]
+    fn reads_eslint_json() {
+        let json = json!([
+            {
+                "filePath": "/app/frontend/src/App.tsx",
+                "messages": [
+                    {
+                        "ruleId": "no-unused-vars",
+                        "severity": 2,
+                        "message": "'x' is defined but never used.",
+                        "line": 3,
+                        "column": 7,
+                        "endLine": 3,
+                        "endColumn": 8
+                    },
+                    {
+                        "ruleId": "prefer-const",
+                        "severity": 1,
+                        "message": "'y' is never reassigned.",
+                        "line": 4,
+                        "column": 5,
+                        "fix": { "range": [40, 43], "text": "const" }
+                    },
+                    {
+                        "ruleId": "react-hooks/exhaustive-deps",
+                        "severity": 1,
+                        "message": "React Hook useEffect has a missing dependency.",
+                        "line": 9,
+                        "column": 6,
+                        "suggestions": [{ "desc": "Update the dependencies array" }]
+                    }
+                ]
+            },
+            {
+                "filePath": "/app/frontend/src/broken.ts",
+                "messages": [
+                    {
+                        "ruleId": null,
+                        "fatal": true,
+                        "severity": 2,
+                        "message": "Parsing error: Unexpected token",
+                        "line": 1,
+                        "column": 1
+                    }
+                ]
+            },
+            { "filePath": "/app/frontend/src/clean.ts", "messages": [] }
+        ]);
+
+        let diagnostics = parse_eslint(Path::new("/app/frontend"), &json);
+        assert_eq!(
+            summary(&diagnostics),
+            [
+                ("src/App.tsx", 3, 7, Some("no-unused-vars"), "error", false),
+                ("src/App.tsx", 4, 5, Some("prefer-const"), "warning", true),
+                (
+                    "src/App.tsx",
+                    9,
+                    6,
+                    Some("react-hooks/exhaustive-deps"),
+                    "warning",
+                    true
+                ),
+                ("src/broken.ts", 1, 1, None, "error", false),
+            ]
+        );
+        assert_eq!(diagnostics[0].end_line, Some(3));
+        assert_eq!(diagnostics[1].end_line, None);
+    }
+}
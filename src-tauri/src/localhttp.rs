@@ -0,0 +1,179 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+// A minimal HTTP/1.1 listener for the local dev servers (mock backend, request bin).
+// One thread per connection and no keep-alive; enough for a developer's own traffic.
+pub struct Request {
+    pub method: String,
+    // Path and query as sent, e.g. "/items?limit=5"
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    // Set when the body was over the limit and not read
+    pub truncated: bool,
+}
+
+impl Request {
+    pub fn path(&self) -> &str {
+        self.target.split(['?', '#']).next().unwrap_or("/")
+    }
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: Option<String>,
+}
+
+impl Response {
+    pub fn json(status: u16, body: String) -> Response {
+        Response {
+            status,
+            body: Some(body),
+        }
+    }
+
+    pub fn empty(status: u16) -> Response {
+        Response { status, body: None }
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+fn read_request(stream: &TcpStream, max_body: usize) -> Result<Request, String> {
+    stream
+        .set_read_timeout(Some(READ_TIMEOUT))
+        .map_err(|e| e.to_string())?;
+    let mut reader = BufReader::new(stream.try_clone().map_err(|e| e.to_string())?);
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|e| e.to_string())?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(format!("Malformed request: {}", request_line.trim()));
+    };
+    let (method, target) = (method.to_uppercase(), target.to_string());
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let content_length: usize = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+    let truncated = content_length > max_body;
+    let mut body = Vec::new();
+    if !truncated {
+        reader
+            .take(content_length as u64)
+            .read_to_end(&mut body)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(Request {
+        method,
+        target,
+        headers,
+        body,
+        truncated,
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> Result<(), String> {
+    let body = response.body.as_deref().unwrap_or_default();
+    // CORS is open so a frontend on another port can call the server directly
+    let mut text = format!(
+        "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n\
+         Access-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: *\r\n\
+         Access-Control-Allow-Headers: *\r\n",
+        response.status,
+        reason(response.status),
+        body.len()
+    );
+    if response.body.is_some() {
+        text.push_str("Content-Type: application/json\r\n");
+    }
+    text.push_str("\r\n");
+    text.push_str(body);
+    stream.write_all(text.as_bytes()).map_err(|e| e.to_string())
+}
+
+// Accepts connections until `stop` is set and the listener is woken by shutdown().
+// `handle` answers each request; `done` sees how it went, e.g. to log it.
+pub fn serve<H, D>(
+    listener: TcpListener,
+    max_body: usize,
+    stop: Arc<AtomicBool>,
+    handle: H,
+    done: D,
+) where
+    H: Fn(&Request) -> Response + Send + Sync + 'static,
+    D: Fn(Result<(&Request, &Response), String>) + Send + Sync + 'static,
+{
+    let handle = Arc::new(handle);
+    let done = Arc::new(done);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            let (handle, done) = (handle.clone(), done.clone());
+            thread::spawn(move || match read_request(&stream, max_body) {
+                Ok(request) => {
+                    let response = if request.truncated {
+                        Response::json(413, r#"{"detail":"Payload Too Large"}"#.to_string())
+                    } else if request.method == "OPTIONS" {
+                        Response::empty(204)
+                    } else {
+                        handle(&request)
+                    };
+                    match write_response(&mut stream, &response) {
+                        Ok(()) => done(Ok((&request, &response))),
+                        Err(e) => done(Err(e)),
+                    }
+                }
+                Err(e) => done(Err(e)),
+            });
+        }
+    });
+}
+
+pub fn shutdown(stop: &AtomicBool, port: u16) {
+    stop.store(true, Ordering::SeqCst);
+    // Wakes the accept loop so it sees the flag
+    let _ = TcpStream::connect(("127.0.0.1", port));
+}
@@ -0,0 +1,167 @@
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+// Captured process output, capped in memory: once `max_bytes` is reached the oldest
+// lines move to a spill file, so a chatty process running for days costs a fixed
+// amount of memory and nothing is lost
+pub struct LogBuffer {
+    lines: VecDeque<String>,
+    bytes: usize,
+    max_bytes: usize,
+    spill_path: Option<PathBuf>,
+    spill: Option<BufWriter<File>>,
+    spilled: u64,
+}
+
+impl LogBuffer {
+    pub fn new(max_bytes: usize, spill_path: Option<PathBuf>) -> Self {
+        LogBuffer {
+            lines: VecDeque::new(),
+            bytes: 0,
+            max_bytes,
+            spill_path,
+            spill: None,
+            spilled: 0,
+        }
+    }
+
+    pub fn push(&mut self, line: String) {
+        self.bytes += line.len();
+        self.lines.push_back(line);
+        let mut evicted_any = false;
+        while self.bytes > self.max_bytes && self.lines.len() > 1 {
+            let Some(evicted) = self.lines.pop_front() else {
+                break;
+            };
+            self.bytes -= evicted.len();
+            self.spilled += 1;
+            self.write_spill(&evicted);
+            evicted_any = true;
+        }
+        // The spill file may be opened while the process is still running, and a
+        // buffer that lives as long as its service never gets dropped
+        if evicted_any {
+            if let Some(spill) = &mut self.spill {
+                let _ = spill.flush();
+            }
+        }
+    }
+
+    // The file is only created once something actually spills
+    fn write_spill(&mut self, line: &str) {
+        if self.spill.is_none() {
+            let Some(path) = &self.spill_path else {
+                return;
+            };
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+            match OpenOptions::new().create(true).append(true).open(path) {
+                Ok(file) => self.spill = Some(BufWriter::new(file)),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "Failed to open log spill file: {}", e);
+                    // Don't retry on every line; the lines are simply dropped
+                    self.spill_path = None;
+                    return;
+                }
+            }
+        }
+        if let Some(spill) = &mut self.spill {
+            let _ = writeln!(spill, "{}", line);
+        }
+    }
+
+    pub fn spilled(&self) -> u64 {
+        self.spilled
+    }
+
+    pub fn spill_path(&self) -> Option<&PathBuf> {
+        self.spill_path.as_ref().filter(|_| self.spilled > 0)
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.lines.iter().cloned().collect()
+    }
+
+    // Drops what's in memory; a spill file is left as it is
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.bytes = 0;
+    }
+
+    pub fn into_lines(mut self) -> Vec<String> {
+        if let Some(spill) = &mut self.spill {
+            let _ = spill.flush();
+        }
+        self.lines.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spill_file(name: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        std::env::temp_dir()
+            .join(format!("devllm-logbuf-{}-{}", name, nanos))
+            .join("spill.log")
+    }
+
+    #[test]
+    fn evicts_the_oldest_lines_past_the_cap() {
+        let mut buffer = LogBuffer::new(10, None);
+        for line in ["aaaa", "bbbb", "cccc"] {
+            buffer.push(line.to_string());
+        }
+        assert_eq!(buffer.lines(), ["bbbb", "cccc"]);
+        assert_eq!(buffer.spilled(), 1);
+        // Without a spill file nothing is reported as readable there
+        assert_eq!(buffer.spill_path(), None);
+    }
+
+    #[test]
+    fn keeps_a_single_line_larger_than_the_cap() {
+        let mut buffer = LogBuffer::new(4, None);
+        buffer.push("a line longer than the cap".to_string());
+        assert_eq!(buffer.lines(), ["a line longer than the cap"]);
+        assert_eq!(buffer.spilled(), 0);
+
+        buffer.push("next".to_string());
+        assert_eq!(buffer.lines(), ["next"]);
+        assert_eq!(buffer.spilled(), 1);
+    }
+
+    #[test]
+    fn spills_evicted_lines_and_flushes_them() {
+        let path = spill_file("spill");
+        let mut buffer = LogBuffer::new(8, Some(path.clone()));
+        buffer.push("one".to_string());
+        buffer.push("two".to_string());
+        assert_eq!(buffer.spill_path(), None);
+        assert!(!path.exists());
+
+        for line in ["three", "four", "five"] {
+            buffer.push(line.to_string());
+        }
+        assert_eq!(buffer.lines(), ["four", "five"]);
+        assert_eq!(buffer.spilled(), 3);
+        assert_eq!(buffer.spill_path(), Some(&path));
+        // Readable while the buffer is still alive
+        assert_eq!(fs::read_to_string(&path).unwrap(), "one\ntwo\nthree\n");
+
+        // Clearing drops memory only; the accounting and the file stay
+        buffer.clear();
+        assert!(buffer.lines().is_empty());
+        assert_eq!(buffer.spilled(), 3);
+        buffer.push("six".to_string());
+        assert_eq!(buffer.lines(), ["six"]);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,198 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{self, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
+
+use crate::error::Error;
+use crate::task;
+
+const LOG_PREFIX: &str = "devllm";
+const DEFAULT_LOG_LINES: usize = 500;
+pub const LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+pub struct LogState {
+    dir: Option<PathBuf>,
+    filter: Option<reload::Handle<EnvFilter, Registry>>,
+    // Dropping the guard would stop the background writer
+    _guard: Option<WorkerGuard>,
+}
+
+impl LogState {
+    pub fn set_level(&self, level: &str) -> Result<(), String> {
+        // An explicit DEVLLM_LOG wins over the setting
+        if std::env::var_os("DEVLLM_LOG").is_some() {
+            return Ok(());
+        }
+        if let Some(filter) = &self.filter {
+            filter
+                .reload(EnvFilter::new(filter_directive(level)))
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    // Keeps one file per day of retention, deleting the oldest
+    pub fn prune(&self, retention_days: u32) {
+        let Some(dir) = &self.dir else {
+            return;
+        };
+        let mut files = log_files(dir);
+        let keep = retention_days.max(1) as usize;
+        if files.len() <= keep {
+            return;
+        }
+        let excess = files.len() - keep;
+        for file in files.drain(..excess) {
+            if let Err(e) = fs::remove_file(&file) {
+                tracing::warn!("Could not remove old log {}: {}", file.display(), e);
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct AppLogs {
+    directory: Option<String>,
+    files: Vec<String>,
+    lines: Vec<String>,
+}
+
+// Our own crate at the chosen level, dependencies only when they warn
+fn filter_directive(level: &str) -> String {
+    format!("warn,devllm_lib={}", level)
+}
+
+// "devllm.2026-10-16.log" names sort chronologically
+fn log_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| {
+                    p.file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|n| n.starts_with(LOG_PREFIX))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+pub fn log_dir(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|dir| dir.join("logs"))
+}
+
+// Daily files in <app data>/logs. Starts at the default level so it doesn't wait on the
+// settings file; set_level and prune apply the user's choices once settings are read.
+pub fn init(app: &AppHandle) -> LogState {
+    let dir = log_dir(app);
+    let appender = dir.as_ref().and_then(|dir| {
+        fs::create_dir_all(dir).ok()?;
+        rolling::Builder::new()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_PREFIX)
+            .filename_suffix("log")
+            .build(dir)
+            .ok()
+    });
+
+    let directive = std::env::var("DEVLLM_LOG").unwrap_or_else(|_| filter_directive("info"));
+    let (filter, handle) = reload::Layer::new(EnvFilter::new(directive));
+    let (file_layer, guard) = match appender {
+        Some(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            (
+                Some(fmt::layer().with_writer(writer).with_ansi(false)),
+                Some(guard),
+            )
+        }
+        None => (None, None),
+    };
+    let console_layer = cfg!(debug_assertions).then(fmt::layer);
+
+    let initialized = tracing_subscriber::registry()
+        .with(filter)
+        .with(file_layer)
+        .with(console_layer)
+        .try_init()
+        .is_ok();
+
+    LogState {
+        dir,
+        filter: initialized.then_some(handle),
+        _guard: guard,
+    }
+}
+
+#[tauri::command]
+pub async fn get_app_logs(
+    app: AppHandle,
+    lines: Option<usize>,
+    level: Option<String>,
+) -> Result<AppLogs, Error> {
+    task::blocking(move || {
+        let state = app.state::<LogState>();
+        let limit = lines.unwrap_or(DEFAULT_LOG_LINES);
+        let Some(dir) = &state.dir else {
+            return Ok(AppLogs {
+                directory: None,
+                files: Vec::new(),
+                lines: Vec::new(),
+            });
+        };
+
+        let files = log_files(dir);
+
+        // Only lines at or above the requested level, e.g. "warn" keeps WARN and ERROR
+        let wanted: Vec<String> = match &level {
+            Some(level) => {
+                let index = LEVELS
+                    .iter()
+                    .position(|l| l.eq_ignore_ascii_case(level))
+                    .ok_or_else(|| format!("Unknown log level: {}", level))?;
+                LEVELS[..=index].iter().map(|l| l.to_uppercase()).collect()
+            }
+            None => Vec::new(),
+        };
+
+        // Walk back from the newest file until enough lines are collected
+        let mut collected: Vec<String> = Vec::new();
+        for file in files.iter().rev() {
+            let content = fs::read_to_string(file).unwrap_or_default();
+            let mut matching: Vec<String> = content
+                .lines()
+                .filter(|line| {
+                    wanted.is_empty()
+                        || line
+                            .split_whitespace()
+                            .nth(1)
+                            .is_some_and(|l| wanted.iter().any(|w| w == l))
+                })
+                .map(String::from)
+                .collect();
+            matching.append(&mut collected);
+            collected = matching;
+            if collected.len() >= limit {
+                break;
+            }
+        }
+        let start = collected.len().saturating_sub(limit);
+
+        Ok(AppLogs {
+            directory: Some(dir.to_string_lossy().into_owned()),
+            files: files
+                .iter()
+                .map(|f| f.to_string_lossy().into_owned())
+                .collect(),
+            lines: collected.split_off(start),
+        })
+    })
+    .await
+}
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::paths;
+use crate::task;
+use crate::versioned::{self, Migration};
+
+const MANIFEST_DIR: &str = ".devllm";
+const MANIFEST_FILE: &str = "manifest.json";
+// Append a step here whenever the stored format changes
+const MANIFEST_MIGRATIONS: &[Migration] = &[versioned::initial];
+
+// Per-project state devLLM records about a project, stored in <root>/.devllm
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone)]
+pub struct ProjectManifest {
+    #[serde(default)]
+    pub python: Option<PythonEnv>,
+    // Services started automatically when the project is opened
+    #[serde(default)]
+    pub autostart: Vec<String>,
+    // Error counts of past type checks, oldest first
+    #[serde(default)]
+    pub type_checks: Vec<TypeCheckRun>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct TypeCheckRun {
+    pub service: String,
+    pub checker: String,
+    pub errors: usize,
+    pub warnings: usize,
+    pub at: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct PythonEnv {
+    pub venv_path: String,
+    pub python_path: String,
+    pub pip_path: String,
+    pub interpreter: String,
+    pub version: Option<String>,
+}
+
+// Services live in frontend/ and backend/ under the project root, but imported
+// projects may point a service straight at the root
+pub fn project_root(service_path: &Path) -> PathBuf {
+    let is_service_dir = matches!(
+        service_path.file_name().and_then(|n| n.to_str()),
+        Some("frontend") | Some("backend")
+    );
+
+    match service_path.parent() {
+        Some(parent) if is_service_dir || parent.join(MANIFEST_DIR).exists() => {
+            parent.to_path_buf()
+        }
+        _ => service_path.to_path_buf(),
+    }
+}
+
+pub fn manifest_path(root: &Path) -> PathBuf {
+    root.join(MANIFEST_DIR).join(MANIFEST_FILE)
+}
+
+pub fn load(root: &Path) -> Result<ProjectManifest, String> {
+    versioned::load(
+        &manifest_path(root),
+        MANIFEST_MIGRATIONS,
+        "project manifest",
+    )
+    .map(Option::unwrap_or_default)
+}
+
+pub fn save(root: &Path, manifest: &ProjectManifest) -> Result<(), String> {
+    versioned::save(
+        &manifest_path(root),
+        manifest,
+        MANIFEST_MIGRATIONS,
+        "project manifest",
+    )
+}
+
+pub fn update(
+    root: &Path,
+    f: impl FnOnce(&mut ProjectManifest),
+) -> Result<ProjectManifest, String> {
+    let mut manifest = load(root)?;
+    f(&mut manifest);
+    save(root, &manifest)?;
+    Ok(manifest)
+}
+
+#[tauri::command]
+pub async fn get_manifest(project_path: String) -> Result<ProjectManifest, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let path = Path::new(&project_path);
+        Ok(load(&project_root(path))?)
+    })
+    .await
+}
@@ -0,0 +1,180 @@
+use std::path::Path;
+use std::process::Command;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::projects;
+use crate::python::{self, PythonPackageManager};
+use crate::task;
+
+const MIGRATION_EVENT: &str = "migration-progress";
+
+#[derive(serde::Serialize)]
+pub struct MigrationReport {
+    success: bool,
+    exit_code: Option<i32>,
+    duration_ms: u128,
+    current_revision: Option<String>,
+    head_revision: Option<String>,
+    created_file: Option<String>,
+    error: Option<String>,
+}
+
+// alembic from the project environment: `uv run alembic` or `<venv python> -m alembic`
+pub fn alembic_command(backend: &Path) -> Result<Command, String> {
+    if !backend.join("alembic.ini").exists() {
+        return Err(
+            "No alembic.ini found; set up migrations with `alembic init migrations` first"
+                .to_string(),
+        );
+    }
+
+    let mut cmd = if python::detect_python_manager(backend) == PythonPackageManager::Uv {
+        let mut cmd = exec::tool_command("uv");
+        cmd.args(["run", "alembic"]);
+        cmd
+    } else {
+        let python = python::venv_python(backend)
+            .ok_or_else(|| "Backend has no virtual environment".to_string())?;
+        let mut cmd = Command::new(python);
+        cmd.args(["-m", "alembic"]);
+        cmd
+    };
+    cmd.current_dir(backend);
+    Ok(cmd)
+}
+
+// `alembic current` / `alembic heads` print "ae1027a6acf (head)" style lines
+fn revision(backend: &Path, subcommand: &str) -> Option<String> {
+    let mut cmd = alembic_command(backend).ok()?;
+    cmd.arg(subcommand);
+    let output = exec::output(&mut cmd).ok()?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|l| !l.starts_with("INFO"))
+        .filter_map(|l| l.split_whitespace().next())
+        .find(|rev| rev.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        .map(String::from)
+}
+
+fn run_alembic(
+    app: &AppHandle,
+    backend_path: &str,
+    mut cmd: Command,
+) -> Result<MigrationReport, Error> {
+    let backend = Path::new(backend_path);
+    let started = Instant::now();
+    let operation = format!("alembic:{}", backend_path);
+    let output = exec::run_streamed(app, MIGRATION_EVENT, &operation, &mut cmd)
+        .map_err(|e| exec::run_failed("alembic", e))?;
+
+    // "Generating /app/backend/migrations/versions/1a2b_add_users.py ...  done"
+    let created_file = output.lines.iter().find_map(|l| {
+        let rest = l.trim().strip_prefix("Generating ")?;
+        Some(rest.split(" ...").next().unwrap_or(rest).trim().to_string())
+    });
+
+    let error = if output.status.success() {
+        None
+    } else {
+        output
+            .lines
+            .iter()
+            .rev()
+            .find(|l| l.contains("Error") || l.starts_with("FAILED"))
+            .map(|l| l.trim().to_string())
+    };
+
+    Ok(MigrationReport {
+        success: output.status.success(),
+        exit_code: output.status.code(),
+        duration_ms: started.elapsed().as_millis(),
+        current_revision: revision(backend, "current"),
+        head_revision: revision(backend, "heads"),
+        created_file,
+        error,
+    })
+}
+
+#[tauri::command]
+pub async fn generate_migration(
+    app: AppHandle,
+    backend_path: String,
+    message: String,
+    autogenerate: Option<bool>,
+) -> Result<MigrationReport, Error> {
+    task::blocking(move || {
+        let backend_path = paths::existing_dir(&backend_path)?;
+        if message.trim().is_empty() {
+            return Err(Error::invalid(
+                "missing_message",
+                "A migration message is required",
+            ));
+        }
+
+        let mut cmd = alembic_command(Path::new(&backend_path))?;
+        cmd.args(["revision", "-m", message.trim()]);
+        if autogenerate.unwrap_or(true) {
+            cmd.arg("--autogenerate");
+        }
+        run_alembic(&app, &backend_path, cmd)
+    })
+    .await
+}
+
+// target defaults to "head"; "base" and relative "-N" targets downgrade
+pub fn run_migrations_blocking(
+    app: AppHandle,
+    backend_path: String,
+    target: Option<String>,
+) -> Result<MigrationReport, Error> {
+    let target = target.unwrap_or_else(|| "head".to_string());
+    let direction = if target == "base" || target.starts_with('-') {
+        "downgrade"
+    } else {
+        "upgrade"
+    };
+
+    let mut cmd = alembic_command(Path::new(&backend_path))?;
+    cmd.args([direction, &target]);
+    run_alembic(&app, &backend_path, cmd)
+}
+
+#[tauri::command]
+pub async fn run_migrations(
+    app: AppHandle,
+    backend_path: String,
+    target: Option<String>,
+) -> Result<MigrationReport, Error> {
+    task::blocking(move || {
+        let backend_path = projects::project_dir(&app, &backend_path)?;
+        run_migrations_blocking(app, backend_path, target)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn get_migration_status(backend_path: String) -> Result<MigrationReport, Error> {
+    task::blocking(move || {
+        let backend_path = paths::existing_dir(&backend_path)?;
+        let backend = Path::new(&backend_path);
+        alembic_command(backend)?;
+
+        let started = Instant::now();
+        let current_revision = revision(backend, "current");
+        let head_revision = revision(backend, "heads");
+        Ok(MigrationReport {
+            success: true,
+            exit_code: None,
+            duration_ms: started.elapsed().as_millis(),
+            current_revision,
+            head_revision,
+            created_file: None,
+            error: None,
+        })
+    })
+    .await
+}
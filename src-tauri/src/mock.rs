@@ -0,0 +1,206 @@
+use serde_json::Value;
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::error::{Error, ErrorKind};
+use crate::localhttp::{self, Response};
+use crate::openapi;
+use crate::paths;
+use crate::servicelog;
+use crate::task;
+use crate::ProcessManager;
+
+// Request bodies are read and dropped; anything larger is refused
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+#[derive(Clone, serde::Serialize)]
+pub struct MockServer {
+    key: String,
+    pub project_path: String,
+    port: u16,
+    url: String,
+    operations: usize,
+    spec_file: String,
+    #[serde(skip)]
+    stop: Arc<AtomicBool>,
+}
+
+pub fn key(project_path: &str) -> String {
+    format!("{}:mock", paths::key(project_path))
+}
+
+// How well a spec path like "/items/{item_id}" matches a request path: None when it
+// doesn't, otherwise the number of literal segments, so "/items/new" beats "/items/{id}"
+fn path_score(template: &str, path: &str) -> Option<usize> {
+    let template: Vec<&str> = template.trim_end_matches('/').split('/').collect();
+    let path: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    if template.len() != path.len() {
+        return None;
+    }
+    let mut literal = 0;
+    for (expected, actual) in template.iter().zip(&path) {
+        if expected.starts_with('{') && expected.ends_with('}') {
+            if actual.is_empty() {
+                return None;
+            }
+        } else if expected == actual {
+            literal += 1;
+        } else {
+            return None;
+        }
+    }
+    Some(literal)
+}
+
+fn detail(status: u16, message: &str) -> Response {
+    Response::json(status, serde_json::json!({ "detail": message }).to_string())
+}
+
+fn respond(spec: &Value, method: &str, path: &str) -> Response {
+    if method == "GET" && path == "/openapi.json" {
+        return Response::json(200, spec.to_string());
+    }
+    let operations = openapi::operations(spec);
+    let matching: Vec<(usize, &openapi::Operation)> = operations
+        .iter()
+        .filter_map(|operation| path_score(operation.path, path).map(|score| (score, operation)))
+        .collect();
+    if matching.is_empty() {
+        return detail(404, "Not Found");
+    }
+    let Some((_, operation)) = matching
+        .into_iter()
+        .filter(|(_, operation)| operation.method.eq_ignore_ascii_case(method))
+        .max_by_key(|(score, _)| *score)
+    else {
+        return detail(405, "Method Not Allowed");
+    };
+
+    // The first success code the operation documents, 200 when it lists none
+    let status = operation.op["responses"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(code, _)| code.parse::<u16>().ok())
+        .find(|code| (200..300).contains(code))
+        .unwrap_or(200);
+    if status == 204 {
+        return Response::empty(status);
+    }
+    let body = openapi::success_response(spec, operation.op)
+        .map(|schema| openapi::example(spec, schema, 0))
+        .unwrap_or(Value::Null);
+    Response::json(status, body.to_string())
+}
+
+pub fn stop_all(state: &ProcessManager) {
+    for (_, server) in state.mocks.lock().drain() {
+        localhttp::shutdown(&server.stop, server.port);
+    }
+}
+
+#[tauri::command]
+pub async fn start_mock_server(app: AppHandle, project_path: String) -> Result<MockServer, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let key = key(&project_path);
+        let state = app.state::<ProcessManager>();
+        if state.mocks.lock().contains_key(&key) {
+            return Err(Error::new(
+                ErrorKind::Conflict,
+                "mock_running",
+                "The mock server is already running for this project",
+            ));
+        }
+
+        let project = crate::detect_project_blocking(project_path.clone())?;
+        let port = project.backend_port.ok_or_else(|| {
+            Error::not_found(
+                "backend_port_unknown",
+                "Could not tell which port the backend listens on",
+            )
+        })?;
+        // Mocks stand in for a backend that is down, so the spec comes from the last sync
+        let spec = openapi::load_synced(root).ok_or_else(|| {
+            Error::not_found(
+                "no_synced_spec",
+                "No OpenAPI spec has been synced yet; run an API type sync while the backend is up",
+            )
+        })?;
+        let listener = TcpListener::bind(("127.0.0.1", port)).map_err(|e| {
+            Error::new(
+                ErrorKind::Conflict,
+                "port_in_use",
+                format!(
+                    "Port {} is in use; stop the backend before starting the mock ({})",
+                    port, e
+                ),
+            )
+            .with("port", port)
+        })?;
+
+        let server = MockServer {
+            key: key.clone(),
+            project_path: project_path.clone(),
+            port,
+            url: format!("http://127.0.0.1:{}", port),
+            operations: openapi::operations(&spec).len(),
+            spec_file: openapi::synced_spec_path(root)
+                .to_string_lossy()
+                .into_owned(),
+            stop: Arc::new(AtomicBool::new(false)),
+        };
+        servicelog::reset(&app, &key);
+        let (log_key, log_project) = (key.clone(), project_path.clone());
+        let log_app = app.clone();
+        localhttp::serve(
+            listener,
+            MAX_BODY_BYTES,
+            server.stop.clone(),
+            move |request| respond(&spec, &request.method, request.path()),
+            move |result| {
+                let (stream, line) = match result {
+                    Ok((request, response)) => (
+                        "stdout",
+                        format!(
+                            "{} {} -> {}",
+                            request.method, request.target, response.status
+                        ),
+                    ),
+                    Err(e) => ("stderr", e),
+                };
+                servicelog::push(&log_app, &log_key, &log_project, "mock", stream, line);
+            },
+        );
+        state.mocks.lock().insert(key, server.clone());
+        tracing::info!(%project_path, port, "started mock server");
+        Ok(server)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn stop_mock_server(app: AppHandle, project_path: String) -> Result<String, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let server = app
+            .state::<ProcessManager>()
+            .mocks
+            .lock()
+            .remove(&key(&project_path));
+        let Some(server) = server else {
+            return Err(Error::new(
+                ErrorKind::Conflict,
+                "mock_not_running",
+                "The mock server is not running",
+            ));
+        };
+        localhttp::shutdown(&server.stop, server.port);
+        Ok(format!("Mock server on port {} stopped", server.port))
+    })
+    .await
+}
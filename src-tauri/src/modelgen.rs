@@ -0,0 +1,323 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use crate::db::{self, ColumnInfo, TableSchema};
+use crate::error::Error;
+use crate::paths;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct GeneratedModels {
+    files: Vec<String>,
+    skipped: Vec<String>,
+    warnings: Vec<String>,
+}
+
+const PYTHON_KEYWORDS: [&str; 16] = [
+    "and", "as", "class", "def", "del", "for", "from", "global", "if", "import", "in", "is",
+    "lambda", "not", "or", "type",
+];
+
+// "order_items" -> "OrderItem"
+fn class_name(table: &str) -> String {
+    let singular = if let Some(stem) = table.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if table.ends_with("ss") || table.ends_with("us") {
+        table.to_string()
+    } else {
+        table.strip_suffix('s').unwrap_or(table).to_string()
+    };
+    singular
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| part[..1].to_uppercase() + &part[1..])
+        .collect()
+}
+
+fn module_name(table: &str) -> String {
+    let name: String = table
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("t_{}", name)
+    } else if PYTHON_KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+// (SQLAlchemy type expression, import name, Python annotation)
+fn column_types(column: &ColumnInfo) -> (String, &'static str, &'static str) {
+    let declared = column.data_type.to_uppercase();
+    let length = declared
+        .split_once('(')
+        .and_then(|(_, rest)| rest.split([')', ',']).next()?.trim().parse::<u32>().ok());
+
+    if declared.contains("BIGINT") {
+        ("BigInteger".to_string(), "BigInteger", "int")
+    } else if declared.contains("INT") {
+        ("Integer".to_string(), "Integer", "int")
+    } else if declared.contains("CHAR") {
+        match length {
+            Some(n) => (format!("String({})", n), "String", "str"),
+            None => ("String".to_string(), "String", "str"),
+        }
+    } else if declared.contains("TEXT") || declared.contains("CLOB") {
+        ("Text".to_string(), "Text", "str")
+    } else if declared.contains("BOOL") {
+        ("Boolean".to_string(), "Boolean", "bool")
+    } else if declared.contains("TIMESTAMP") || declared.contains("DATETIME") {
+        let tz = declared.contains("WITH TIME ZONE");
+        (
+            format!("DateTime(timezone={})", if tz { "True" } else { "False" }),
+            "DateTime",
+            "datetime",
+        )
+    } else if declared == "DATE" {
+        ("Date".to_string(), "Date", "date")
+    } else if declared.starts_with("TIME") {
+        ("Time".to_string(), "Time", "time")
+    } else if declared.contains("REAL") || declared.contains("FLOA") || declared.contains("DOUB") {
+        ("Float".to_string(), "Float", "float")
+    } else if declared.contains("NUMERIC") || declared.contains("DECIMAL") {
+        ("Numeric".to_string(), "Numeric", "Decimal")
+    } else if declared.contains("BLOB") || declared.contains("BYTEA") {
+        ("LargeBinary".to_string(), "LargeBinary", "bytes")
+    } else if declared.contains("JSON") {
+        ("JSON".to_string(), "JSON", "dict")
+    } else {
+        ("String".to_string(), "String", "str")
+    }
+}
+
+fn is_timestamp_default(default: &str) -> bool {
+    let upper = default.to_uppercase();
+    upper.contains("CURRENT_TIMESTAMP") || upper.contains("NOW()")
+}
+
+fn model_source(schema: &TableSchema) -> String {
+    let mut imports: BTreeSet<&str> = BTreeSet::from(["Column"]);
+    let mut uses_func = false;
+    let mut lines = Vec::new();
+
+    for column in &schema.columns {
+        let (sa_type, import, _) = column_types(column);
+        imports.insert(import);
+
+        let mut args = vec![sa_type];
+        if let Some(fk) = schema
+            .foreign_keys
+            .iter()
+            .find(|fk| fk.column == column.name)
+        {
+            imports.insert("ForeignKey");
+            args.push(format!(
+                "ForeignKey(\"{}.{}\")",
+                fk.references_table,
+                fk.references_column.as_deref().unwrap_or("id")
+            ));
+        }
+        if column.primary_key {
+            args.push("primary_key=True".to_string());
+            args.push("index=True".to_string());
+        } else {
+            args.push(format!(
+                "nullable={}",
+                if column.nullable { "True" } else { "False" }
+            ));
+        }
+        if let Some(default) = &column.default_value {
+            if is_timestamp_default(default) {
+                uses_func = true;
+                args.push("server_default=func.now()".to_string());
+            }
+        }
+
+        // Columns named like Python keywords or with odd characters keep their DB name
+        let attr = module_name(&column.name);
+        if attr == column.name {
+            lines.push(format!("    {} = Column({})", attr, args.join(", ")));
+        } else {
+            lines.push(format!(
+                "    {} = Column(\"{}\", {})",
+                attr,
+                column.name,
+                args.join(", ")
+            ));
+        }
+    }
+
+    let mut source = format!(
+        "from sqlalchemy import {}\n",
+        imports.into_iter().collect::<Vec<_>>().join(", ")
+    );
+    if uses_func {
+        source.push_str("from sqlalchemy.sql import func\n");
+    }
+    source.push_str(&format!(
+        "from database import Base\n\nclass {}(Base):\n    __tablename__ = \"{}\"\n\n{}\n",
+        class_name(&schema.name),
+        schema.name,
+        lines.join("\n")
+    ));
+    source
+}
+
+fn schema_source(schema: &TableSchema) -> String {
+    let class = class_name(&schema.name);
+    let mut type_imports: BTreeSet<&str> = BTreeSet::new();
+    let mut create = Vec::new();
+    let mut response = Vec::new();
+
+    for column in &schema.columns {
+        let (_, _, annotation) = column_types(column);
+        match annotation {
+            "datetime" | "date" | "time" => {
+                type_imports.insert(annotation);
+            }
+            _ => {}
+        }
+        let attr = module_name(&column.name);
+        let optional = column.nullable && !column.primary_key;
+        let typed = if optional {
+            format!("Optional[{}]", annotation)
+        } else {
+            annotation.to_string()
+        };
+        response.push(format!("    {}: {}", attr, typed));
+
+        // Server-generated values aren't part of the create payload
+        let generated = column.primary_key
+            || column
+                .default_value
+                .as_deref()
+                .is_some_and(is_timestamp_default);
+        if !generated {
+            if optional || column.default_value.is_some() {
+                create.push(format!("    {}: Optional[{}] = None", attr, annotation));
+            } else {
+                create.push(format!("    {}: {}", attr, annotation));
+            }
+        }
+    }
+
+    let mut source = String::from("from pydantic import BaseModel\n");
+    if !type_imports.is_empty() {
+        source.push_str(&format!(
+            "from datetime import {}\n",
+            type_imports.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+    }
+    if schema
+        .columns
+        .iter()
+        .any(|c| column_types(c).2 == "Decimal")
+    {
+        source.push_str("from decimal import Decimal\n");
+    }
+    source.push_str("from typing import Optional\n\n");
+    if create.is_empty() {
+        create.push("    pass".to_string());
+    }
+    source.push_str(&format!(
+        "class {class}Create(BaseModel):\n{}\n\nclass {class}Response(BaseModel):\n{}\n\n    class Config:\n        from_attributes = True\n",
+        create.join("\n"),
+        response.join("\n"),
+        class = class
+    ));
+    source
+}
+
+// Adds "from .x import A, B" unless the package already imports from that module
+fn register_import(init: &Path, module: &str, names: &str) -> Result<(), String> {
+    let mut content = fs::read_to_string(init).unwrap_or_default();
+    if content
+        .lines()
+        .any(|l| l.starts_with(&format!("from .{} import", module)))
+    {
+        return Ok(());
+    }
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&format!("from .{} import {}\n", module, names));
+    fs::write(init, content).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn generate_models(
+    backend_path: String,
+    database_url: String,
+    tables: Option<Vec<String>>,
+    overwrite: Option<bool>,
+) -> Result<GeneratedModels, Error> {
+    task::blocking(move || {
+        let backend_path = paths::existing_dir(&backend_path)?;
+        let backend = Path::new(&backend_path);
+        let overwrite = overwrite.unwrap_or(false);
+
+        let schemas: Vec<TableSchema> = db::introspect(backend, &database_url)?
+            .into_iter()
+            .filter(|s| s.name != "alembic_version")
+            .filter(|s| tables.as_ref().is_none_or(|t| t.contains(&s.name)))
+            .collect();
+        if schemas.is_empty() {
+            return Err(Error::not_found(
+                "no_tables",
+                "No matching tables found in the database",
+            ));
+        }
+
+        let models_dir = backend.join("models");
+        let schemas_dir = backend.join("schemas");
+        fs::create_dir_all(&models_dir)?;
+        fs::create_dir_all(&schemas_dir)?;
+
+        let mut files = Vec::new();
+        let mut skipped = Vec::new();
+        let mut warnings = Vec::new();
+
+        for schema in &schemas {
+            if !schema.columns.iter().any(|c| c.primary_key) {
+                // SQLAlchemy's ORM can't map a table without a primary key
+                warnings.push(format!(
+                    "{} has no primary key and was skipped",
+                    schema.name
+                ));
+                continue;
+            }
+
+            let module = module_name(&schema.name);
+            let class = class_name(&schema.name);
+            let model_path = models_dir.join(format!("{}.py", module));
+            let schema_path = schemas_dir.join(format!("{}.py", module));
+            if !overwrite && (model_path.exists() || schema_path.exists()) {
+                skipped.push(schema.name.clone());
+                continue;
+            }
+
+            fs::write(&model_path, model_source(schema))?;
+            fs::write(&schema_path, schema_source(schema))?;
+            register_import(&models_dir.join("__init__.py"), &module, &class)?;
+            register_import(
+                &schemas_dir.join("__init__.py"),
+                &module,
+                &format!("{}Create, {}Response", class, class),
+            )?;
+
+            files.push(model_path.to_string_lossy().into_owned());
+            files.push(schema_path.to_string_lossy().into_owned());
+        }
+
+        Ok(GeneratedModels {
+            files,
+            skipped,
+            warnings,
+        })
+    })
+    .await
+}
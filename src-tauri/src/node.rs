@@ -0,0 +1,341 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use crate::deps::{InstallIssue, InstallReport, PackageCounts, INSTALL_EVENT};
+use crate::error::Error;
+use crate::exec;
+use crate::notify;
+use crate::projects;
+use crate::task;
+use crate::toolchain;
+
+#[derive(Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+impl PackageManager {
+    pub fn program(self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Yarn => "yarn",
+            PackageManager::Bun => "bun",
+        }
+    }
+
+    pub fn lockfile(self) -> &'static str {
+        match self {
+            PackageManager::Npm => "package-lock.json",
+            PackageManager::Pnpm => "pnpm-lock.yaml",
+            PackageManager::Yarn => "yarn.lock",
+            PackageManager::Bun => "bun.lockb",
+        }
+    }
+
+    pub fn command(self) -> Command {
+        exec::tool_command(self.program())
+    }
+}
+
+// "packageManager": "pnpm@9.1.0+sha512..." in package.json
+pub fn pinned_package_manager(path: &Path) -> Option<(PackageManager, String)> {
+    let content = fs::read_to_string(path.join("package.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let (name, version) = json["packageManager"].as_str()?.split_once('@')?;
+    let pm = match name {
+        "npm" => PackageManager::Npm,
+        "pnpm" => PackageManager::Pnpm,
+        "yarn" => PackageManager::Yarn,
+        "bun" => PackageManager::Bun,
+        _ => return None,
+    };
+    let version = version.split('+').next().unwrap_or(version).to_string();
+    Some((pm, version))
+}
+
+pub fn detect_package_manager(path: &Path) -> PackageManager {
+    if let Some((pm, _)) = pinned_package_manager(path) {
+        return pm;
+    }
+    [
+        PackageManager::Pnpm,
+        PackageManager::Yarn,
+        PackageManager::Bun,
+        PackageManager::Npm,
+    ]
+    .into_iter()
+    .find(|pm| path.join(pm.lockfile()).exists())
+    .unwrap_or(PackageManager::Npm)
+}
+
+// Corepack runs exactly the pinned version; it has no support for bun
+fn corepack_pin(path: &Path, pm: PackageManager) -> Option<String> {
+    match pinned_package_manager(path) {
+        Some((pinned, version)) if pinned == pm && pm != PackageManager::Bun => Some(version),
+        _ => None,
+    }
+}
+
+fn ensure_corepack(pm: PackageManager, version: &str) -> Result<(), Error> {
+    let mut check = exec::tool_command("corepack");
+    check.arg("--version");
+    let available = exec::output(&mut check).is_ok_and(|o| o.status.success());
+    if !available {
+        // Corepack ships with Node, so the fix is a Node install that includes it
+        return Err(Error::missing_runtime(
+            "node",
+            format!(
+                "This project pins {}@{} through the packageManager field, but Corepack is not available. Install it with `npm install -g corepack`, or remove packageManager from package.json",
+                pm.program(),
+                version
+            ),
+        )
+        .with("program", "corepack"));
+    }
+
+    // Enabling puts the pinned shims on PATH for terminals and dev scripts; it can fail
+    // without admin rights, which is fine since devLLM calls `corepack <pm>` directly
+    if toolchain::which_all(pm.program()).is_empty() {
+        let mut enable = exec::tool_command("corepack");
+        enable.args(["enable", pm.program()]);
+        let _ = exec::output(&mut enable);
+    }
+    Ok(())
+}
+
+// The package manager invocation for a project, routed through Corepack when pinned
+pub fn manager_command(path: &Path, pm: PackageManager) -> Result<Command, Error> {
+    let Some(version) = corepack_pin(path, pm) else {
+        return Ok(pm.command());
+    };
+    ensure_corepack(pm, &version)?;
+
+    let mut cmd = exec::tool_command("corepack");
+    cmd.arg(pm.program())
+        // Never block a background process on the "download pnpm?" prompt
+        .env("COREPACK_ENABLE_DOWNLOAD_PROMPT", "0");
+    Ok(cmd)
+}
+
+// `pnpm run dev` becomes `corepack pnpm run dev` for projects pinning pnpm, and so
+// does an `npm run dev` left over from before the pin
+pub fn resolve_start_command(path: &Path, command: &str) -> Result<String, Error> {
+    let Some((pm, _)) = pinned_package_manager(path) else {
+        return Ok(command.to_string());
+    };
+    let trimmed = command.trim_start();
+    let command = match trimmed.strip_prefix("npm run ") {
+        Some(script) if pm != PackageManager::Npm => format!("{} run {}", pm.program(), script),
+        _ => {
+            if pm != PackageManager::Npm && trimmed.split_whitespace().next() == Some("npm") {
+                tracing::warn!(
+                    "`{}` runs npm in a project pinned to {}",
+                    trimmed,
+                    pm.program()
+                );
+            }
+            trimmed.to_string()
+        }
+    };
+
+    let Some(version) = corepack_pin(path, pm) else {
+        return Ok(command);
+    };
+    if command.split_whitespace().next() != Some(pm.program()) {
+        return Ok(command);
+    }
+    ensure_corepack(pm, &version)?;
+    Ok(format!("corepack {}", command))
+}
+
+#[tauri::command]
+pub async fn install_node_deps(
+    app: AppHandle,
+    frontend_path: String,
+) -> Result<InstallReport, Error> {
+    task::blocking(move || {
+        let frontend_path = projects::project_dir(&app, &frontend_path)?;
+        let path = Path::new(&frontend_path);
+        if !path.join("package.json").exists() {
+            return Err(Error::not_found(
+                "no_manifest",
+                format!("No package.json found in {}", frontend_path),
+            )
+            .with("path", &frontend_path));
+        }
+
+        let pm = detect_package_manager(path);
+        let mut cmd = manager_command(path, pm)?;
+        cmd.arg("install").current_dir(path);
+        match pm {
+            PackageManager::Npm => {
+                cmd.args(["--no-fund", "--no-audit"]);
+            }
+            PackageManager::Pnpm => {
+                cmd.arg("--reporter=append-only");
+            }
+            PackageManager::Yarn | PackageManager::Bun => {}
+        }
+
+        let started = Instant::now();
+        let operation = format!("{}:{}", pm.program(), frontend_path);
+        let output = exec::run_streamed(&app, INSTALL_EVENT, &operation, &mut cmd)
+            .map_err(|e| exec::run_failed(pm.program(), e))?;
+
+        let issues = if output.status.success() {
+            Vec::new()
+        } else {
+            parse_node_issues(pm, &output.lines)
+        };
+
+        notify::install_finished(
+            &app,
+            pm.program(),
+            &frontend_path,
+            output.status.success(),
+            started.elapsed(),
+        );
+        Ok(InstallReport {
+            success: output.status.success(),
+            exit_code: output.status.code(),
+            duration_ms: started.elapsed().as_millis(),
+            packages: parse_package_counts(&output.lines),
+            issues,
+        })
+    })
+    .await
+}
+
+// npm: "added 215 packages, removed 3 packages, changed 1 package, and audited ..."
+// pnpm: "Packages: +215 -3"
+fn parse_package_counts(lines: &[String]) -> Option<PackageCounts> {
+    for line in lines.iter().rev() {
+        let line = line.trim();
+
+        if let Some(rest) = line.strip_prefix("Packages:") {
+            let mut counts = PackageCounts::default();
+            for word in rest.split_whitespace() {
+                if let Some(n) = word.strip_prefix('+').and_then(|n| n.parse().ok()) {
+                    counts.added = n;
+                } else if let Some(n) = word.strip_prefix('-').and_then(|n| n.parse().ok()) {
+                    counts.removed = n;
+                }
+            }
+            return Some(counts);
+        }
+
+        if line.starts_with("added ")
+            || line.starts_with("removed ")
+            || line.starts_with("changed ")
+            || line.starts_with("up to date")
+        {
+            let mut counts = PackageCounts::default();
+            let words: Vec<&str> = line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|w| !w.is_empty())
+                .collect();
+            for pair in words.windows(2) {
+                let Ok(n) = pair[1].parse::<u32>() else {
+                    continue;
+                };
+                match pair[0] {
+                    "added" => counts.added = n,
+                    "removed" => counts.removed = n,
+                    "changed" => counts.changed = n,
+                    _ => {}
+                }
+            }
+            return Some(counts);
+        }
+    }
+    None
+}
+
+fn parse_node_issues(pm: PackageManager, lines: &[String]) -> Vec<InstallIssue> {
+    let mut issues = Vec::new();
+    let has = |needle: &str| lines.iter().find(|l| l.contains(needle));
+
+    if let Some(line) = has("ERR_PNPM_OUTDATED_LOCKFILE")
+        .or_else(|| has("ERR_PNPM_LOCKFILE_CONFIG_MISMATCH"))
+        .or_else(|| has("can only install packages when your package.json and package-lock.json"))
+        .or_else(|| has("Your lockfile needs to be updated"))
+        .or_else(|| has("The lockfile would have been modified"))
+    {
+        issues.push(InstallIssue {
+            kind: "lockfile_mismatch",
+            package: None,
+            message: line.trim().to_string(),
+            hint: format!(
+                "{} is out of sync with package.json; run a plain `{} install` to regenerate it",
+                pm.lockfile(),
+                pm.program()
+            ),
+        });
+    }
+
+    if let Some(line) = has("ERESOLVE").or_else(|| has("ERR_PNPM_PEER_DEP_ISSUES")) {
+        issues.push(InstallIssue {
+            kind: "version_conflict",
+            package: None,
+            message: line.trim().to_string(),
+            hint:
+                "Align the conflicting peer dependency versions, or retry with --legacy-peer-deps"
+                    .to_string(),
+        });
+    }
+
+    if let Some(line) = has("ETIMEDOUT")
+        .or_else(|| has("ENOTFOUND"))
+        .or_else(|| has("EAI_AGAIN"))
+    {
+        issues.push(InstallIssue {
+            kind: "network",
+            package: None,
+            message: line.trim().to_string(),
+            hint: "Check your internet connection or registry/proxy settings".to_string(),
+        });
+    }
+
+    if let Some(line) = has("EPERM").or_else(|| has("EBUSY")) {
+        issues.push(InstallIssue {
+            kind: "permission",
+            package: None,
+            message: line.trim().to_string(),
+            hint: "Stop the running frontend so node_modules is not locked, then retry".to_string(),
+        });
+    }
+
+    if let Some(line) = has("E404") {
+        issues.push(InstallIssue {
+            kind: "package_not_found",
+            package: None,
+            message: line.trim().to_string(),
+            hint: "Check the package name and version in package.json".to_string(),
+        });
+    }
+
+    if issues.is_empty() {
+        let message = lines
+            .iter()
+            .rev()
+            .find(|l| l.contains("ERR"))
+            .cloned()
+            .unwrap_or_else(|| format!("{} install failed", pm.program()));
+        issues.push(InstallIssue {
+            kind: "unknown",
+            package: None,
+            message: message.trim().to_string(),
+            hint: "See the install output for details".to_string(),
+        });
+    }
+
+    issues
+}
@@ -0,0 +1,108 @@
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::settings::SettingsState;
+use crate::ServiceLaunch;
+
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(60);
+// Installs quicker than this finish while the user is still looking
+const LONG_INSTALL: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Copy)]
+pub enum Event {
+    ServiceCrashed,
+    InstallFinished,
+    ServiceHealthy,
+}
+
+fn send(app: &AppHandle, event: Event, title: &str, body: &str) {
+    let prefs = app.state::<SettingsState>().get().notifications;
+    let enabled = match event {
+        Event::ServiceCrashed => prefs.service_crashed,
+        Event::InstallFinished => prefs.install_finished,
+        Event::ServiceHealthy => prefs.service_healthy,
+    };
+    if !enabled {
+        return;
+    }
+    if let Err(e) = app.notification().builder().title(title).body(body).show() {
+        tracing::warn!("Failed to show notification: {}", e);
+    }
+}
+
+fn project_name(project_path: &str) -> String {
+    Path::new(project_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| project_path.to_string())
+}
+
+pub fn service_crashed(app: &AppHandle, key: &str, code: Option<i32>) {
+    let (project, service) = key.rsplit_once(':').unwrap_or((key, ""));
+    let code = code
+        .map(|c| format!("exit code {}", c))
+        .unwrap_or_else(|| "terminated".to_string());
+    tracing::warn!(service = %key, "service exited unexpectedly ({})", code);
+    send(
+        app,
+        Event::ServiceCrashed,
+        &format!("{} crashed", service),
+        &format!("{} stopped unexpectedly ({})", project_name(project), code),
+    );
+}
+
+pub fn install_finished(app: &AppHandle, what: &str, path: &str, success: bool, elapsed: Duration) {
+    if elapsed < LONG_INSTALL {
+        return;
+    }
+    let (title, outcome) = if success {
+        (format!("{} install finished", what), "finished")
+    } else {
+        (format!("{} install failed", what), "failed")
+    };
+    send(
+        app,
+        Event::InstallFinished,
+        &title,
+        &format!(
+            "{} {} after {}s",
+            project_name(path),
+            outcome,
+            elapsed.as_secs()
+        ),
+    );
+}
+
+// Waits in the background for a restarted service to accept connections on its port
+pub fn when_healthy(app: &AppHandle, launch: &ServiceLaunch) {
+    let Some(port) = crate::detect_port(Path::new(&launch.project_path), &launch.service_type)
+    else {
+        return;
+    };
+    let app = app.clone();
+    let launch = launch.clone();
+    thread::spawn(move || {
+        let address = SocketAddr::from(([127, 0, 0, 1], port));
+        let started = Instant::now();
+        while started.elapsed() < HEALTH_TIMEOUT {
+            if TcpStream::connect_timeout(&address, Duration::from_millis(500)).is_ok() {
+                send(
+                    &app,
+                    Event::ServiceHealthy,
+                    &format!("{} is back up", launch.service_type),
+                    &format!(
+                        "{} is listening on port {}",
+                        project_name(&launch.project_path),
+                        port
+                    ),
+                );
+                return;
+            }
+            thread::sleep(Duration::from_secs(1));
+        }
+    });
+}
@@ -0,0 +1,792 @@
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::deps;
+use crate::error::{Error, ErrorKind};
+use crate::manifest;
+use crate::paths;
+use crate::task;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "patch", "options", "head", "trace",
+];
+// Methods the template's api client has a helper for
+const CLIENT_METHODS: [&str; 4] = ["get", "post", "put", "delete"];
+const TYPES_FILE: &str = "src/types/api.ts";
+const CLIENT_FILE: &str = "src/api/generated.ts";
+pub const GENERATED_HEADER: &str =
+    "// Generated by devLLM from the backend's /openapi.json. Changes are overwritten on the next sync.\n";
+
+#[derive(serde::Serialize)]
+pub struct ApiSyncReport {
+    spec_url: String,
+    files: Vec<String>,
+    schemas: usize,
+    operations: usize,
+    // "GET /items" style, relative to the last synced spec
+    added: Vec<String>,
+    removed: Vec<String>,
+    breaking: Vec<String>,
+    first_sync: bool,
+    warnings: Vec<String>,
+}
+
+pub struct Operation<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    pub op: &'a Value,
+    // Path-level parameters apply to every method under the path
+    pub path_item: &'a Value,
+}
+
+impl Operation<'_> {
+    pub fn label(&self) -> String {
+        format!("{} {}", self.method.to_uppercase(), self.path)
+    }
+}
+
+pub fn operations(spec: &Value) -> Vec<Operation<'_>> {
+    spec["paths"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .flat_map(|(path, item)| {
+            METHODS
+                .iter()
+                .filter(|method| item.get(**method).is_some())
+                .map(move |method| Operation {
+                    method,
+                    path,
+                    op: &item[*method],
+                    path_item: item,
+                })
+        })
+        .collect()
+}
+
+// Follows a local "$ref"; anything else is returned as is
+pub fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    value["$ref"]
+        .as_str()
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| spec.pointer(pointer))
+        .unwrap_or(value)
+}
+
+pub fn parameters<'a>(spec: &'a Value, operation: &Operation<'a>) -> Vec<&'a Value> {
+    let mut params: Vec<&Value> = operation.op["parameters"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|p| resolve(spec, p))
+        .collect();
+    for inherited in operation.path_item["parameters"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let inherited = resolve(spec, inherited);
+        let overridden = params
+            .iter()
+            .any(|p| p["name"] == inherited["name"] && p["in"] == inherited["in"]);
+        if !overridden {
+            params.push(inherited);
+        }
+    }
+    params
+}
+
+// The JSON schema of a request body or response, when it has one
+pub fn json_schema<'a>(spec: &'a Value, body: &'a Value) -> Option<&'a Value> {
+    let content = &resolve(spec, body)["content"];
+    content
+        .as_object()?
+        .iter()
+        .find(|(media, _)| media.contains("json"))
+        .map(|(_, c)| &c["schema"])
+        .filter(|schema| !schema.is_null())
+}
+
+pub fn success_response<'a>(spec: &'a Value, op: &'a Value) -> Option<&'a Value> {
+    let responses = op["responses"].as_object()?;
+    ["200", "201", "202", "2XX", "default"]
+        .iter()
+        .find_map(|code| responses.get(*code))
+        .and_then(|response| json_schema(spec, response))
+}
+
+// "Body_create_item_items_post" and "Page[Item]" -> valid TypeScript identifiers
+pub fn type_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+pub fn ref_name(schema: &Value) -> Option<&str> {
+    schema["$ref"]
+        .as_str()?
+        .strip_prefix("#/components/schemas/")
+}
+
+// "get_items_items_get" -> "getItemsItemsGet"
+pub fn camel(name: &str) -> String {
+    let mut out = String::new();
+    for (i, part) in name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .enumerate()
+    {
+        if i == 0 {
+            out.push_str(&part[..1].to_lowercase());
+        } else {
+            out.push_str(&part[..1].to_uppercase());
+        }
+        out.push_str(&part[1..]);
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn property_name(name: &str) -> String {
+    let identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if identifier {
+        name.to_string()
+    } else {
+        Value::from(name).to_string()
+    }
+}
+
+fn union(types: impl IntoIterator<Item = String>) -> String {
+    let mut seen = Vec::new();
+    for t in types {
+        if !seen.contains(&t) {
+            seen.push(t);
+        }
+    }
+    if seen.is_empty() {
+        "never".to_string()
+    } else {
+        seen.join(" | ")
+    }
+}
+
+// Schema names are prefixed with `prefix`, e.g. "Api." when they come from an import
+pub fn ts_type(schema: &Value, prefix: &str) -> String {
+    if let Some(name) = ref_name(schema) {
+        return format!("{}{}", prefix, type_name(name));
+    }
+    if let Some(values) = schema["enum"].as_array() {
+        return union(values.iter().map(Value::to_string));
+    }
+    if let Some(value) = schema.get("const") {
+        return value.to_string();
+    }
+    for (key, separator) in [("anyOf", " | "), ("oneOf", " | "), ("allOf", " & ")] {
+        if let Some(parts) = schema[key].as_array() {
+            let types = parts.iter().map(|part| {
+                let t = ts_type(part, prefix);
+                if separator == " & " && t.contains(" | ") {
+                    format!("({})", t)
+                } else {
+                    t
+                }
+            });
+            return if separator == " | " {
+                union(types)
+            } else {
+                types.collect::<Vec<_>>().join(separator)
+            };
+        }
+    }
+    let base = match &schema["type"] {
+        // OpenAPI 3.1 spells nullable as ["string", "null"]
+        Value::Array(types) => union(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|t| primitive(schema, t, prefix)),
+        ),
+        Value::String(t) => primitive(schema, t, prefix),
+        _ if schema.get("properties").is_some() => object(schema, prefix),
+        _ => "unknown".to_string(),
+    };
+    if schema["nullable"] == true {
+        format!("{} | null", base)
+    } else {
+        base
+    }
+}
+
+fn primitive(schema: &Value, t: &str, prefix: &str) -> String {
+    match t {
+        "string" if schema["format"] == "binary" => "Blob".to_string(),
+        "string" => "string".to_string(),
+        "integer" | "number" => "number".to_string(),
+        "boolean" => "boolean".to_string(),
+        "null" => "null".to_string(),
+        "array" => {
+            let item = ts_type(&schema["items"], prefix);
+            if item.contains(' ') {
+                format!("({})[]", item)
+            } else {
+                format!("{}[]", item)
+            }
+        }
+        "object" => object(schema, prefix),
+        _ => "unknown".to_string(),
+    }
+}
+
+fn fields(schema: &Value, prefix: &str) -> Vec<String> {
+    let required: BTreeSet<&str> = schema["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    schema["properties"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, property)| {
+            format!(
+                "{}{}: {}",
+                property_name(name),
+                if required.contains(name.as_str()) {
+                    ""
+                } else {
+                    "?"
+                },
+                ts_type(property, prefix)
+            )
+        })
+        .collect()
+}
+
+fn object(schema: &Value, prefix: &str) -> String {
+    let fields = fields(schema, prefix);
+    if !fields.is_empty() {
+        return format!("{{ {} }}", fields.join("; "));
+    }
+    match &schema["additionalProperties"] {
+        Value::Object(values) => format!(
+            "Record<string, {}>",
+            ts_type(&Value::Object(values.clone()), prefix)
+        ),
+        _ => "Record<string, unknown>".to_string(),
+    }
+}
+
+// A plausible value for a schema: its own example or default when it has one,
+// otherwise built from the types. Recursive schemas stop after a few levels.
+pub fn example(spec: &Value, schema: &Value, depth: usize) -> Value {
+    let schema = resolve(spec, schema);
+    if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+        return example.clone();
+    }
+    if let Some(first) = schema["examples"].as_array().and_then(|e| e.first()) {
+        return first.clone();
+    }
+    if let Some(first) = schema["enum"].as_array().and_then(|e| e.first()) {
+        return first.clone();
+    }
+    if let Some(value) = schema.get("const") {
+        return value.clone();
+    }
+    if depth > 5 {
+        return Value::Null;
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(parts) = schema[key].as_array() {
+            // The first alternative that isn't null
+            let part = parts.iter().find(|p| p["type"] != "null").or(parts.first());
+            return part.map_or(Value::Null, |p| example(spec, p, depth + 1));
+        }
+    }
+    if let Some(parts) = schema["allOf"].as_array() {
+        let mut merged = serde_json::Map::new();
+        for part in parts {
+            if let Value::Object(fields) = example(spec, part, depth + 1) {
+                merged.extend(fields);
+            }
+        }
+        return Value::Object(merged);
+    }
+    let ty = match &schema["type"] {
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null")
+            .unwrap_or("null"),
+        Value::String(t) => t.as_str(),
+        _ if schema.get("properties").is_some() => "object",
+        _ => "",
+    };
+    match ty {
+        "string" => Value::from(match schema["format"].as_str() {
+            Some("date-time") => "2024-01-01T12:00:00Z",
+            Some("date") => "2024-01-01",
+            Some("time") => "12:00:00",
+            Some("email") => "user@example.com",
+            Some("uuid") => "00000000-0000-4000-8000-000000000000",
+            Some("uri") | Some("url") => "https://example.com",
+            _ => "string",
+        }),
+        "integer" => Value::from(schema["minimum"].as_i64().unwrap_or(1)),
+        "number" => Value::from(schema["minimum"].as_f64().unwrap_or(1.0)),
+        "boolean" => Value::from(true),
+        "array" => Value::Array(vec![example(spec, &schema["items"], depth + 1)]),
+        "object" => Value::Object(
+            schema["properties"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, property)| (name.clone(), example(spec, property, depth + 1)))
+                .collect(),
+        ),
+        _ => Value::Null,
+    }
+}
+
+// Interfaces and aliases for components.schemas, under a leading `header` comment
+pub fn types_source(spec: &Value, header: &str) -> String {
+    let mut out = String::from(header);
+    for (name, schema) in spec["components"]["schemas"]
+        .as_object()
+        .into_iter()
+        .flatten()
+    {
+        out.push('\n');
+        let fields = fields(schema, "");
+        let plain_object = !fields.is_empty()
+            && ["anyOf", "oneOf", "allOf", "enum"]
+                .iter()
+                .all(|key| schema.get(*key).is_none());
+        if plain_object {
+            out.push_str(&format!("export interface {} {{\n", type_name(name)));
+            for field in fields {
+                out.push_str(&format!("  {};\n", field));
+            }
+            out.push_str("}\n");
+        } else {
+            out.push_str(&format!(
+                "export type {} = {};\n",
+                type_name(name),
+                ts_type(schema, "")
+            ));
+        }
+    }
+    out
+}
+
+// One function per operation, built on the hand-written `api` helper in client.ts
+fn client_source(spec: &Value, warnings: &mut Vec<String>) -> String {
+    let mut functions = Vec::new();
+    let mut uses_query = false;
+    let mut names = BTreeSet::new();
+    for operation in operations(spec) {
+        if !CLIENT_METHODS.contains(&operation.method) {
+            warnings.push(format!(
+                "{}: the api client has no {} helper, skipped",
+                operation.label(),
+                operation.method
+            ));
+            continue;
+        }
+        let base = operation.op["operationId"]
+            .as_str()
+            .map(camel)
+            .unwrap_or_else(|| camel(&format!("{} {}", operation.method, operation.path)));
+        let mut name = base.clone();
+        let mut n = 2;
+        while !names.insert(name.clone()) {
+            name = format!("{}{}", base, n);
+            n += 1;
+        }
+
+        let mut args = Vec::new();
+        let mut url = operation.path.to_string();
+        let mut query = Vec::new();
+        let mut query_required = false;
+        for param in parameters(spec, &operation) {
+            let Some(param_name) = param["name"].as_str() else {
+                continue;
+            };
+            let t = ts_type(&param["schema"], "Api.");
+            match param["in"].as_str() {
+                Some("path") => {
+                    let arg = camel(param_name);
+                    url = url.replace(
+                        &format!("{{{}}}", param_name),
+                        &format!("${{encodeURIComponent(String({}))}}", arg),
+                    );
+                    args.push(format!("{}: {}", arg, t));
+                }
+                Some("query") => {
+                    let required = param["required"] == true;
+                    query_required |= required;
+                    query.push(format!(
+                        "{}{}: {}",
+                        property_name(param_name),
+                        if required { "" } else { "?" },
+                        t
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        let body = &operation.op["requestBody"];
+        let mut body_arg = "undefined";
+        if !body.is_null() {
+            match json_schema(spec, body) {
+                Some(schema) => {
+                    let optional = resolve(spec, body)["required"] != true;
+                    args.push(format!(
+                        "body{}: {}",
+                        if optional { "?" } else { "" },
+                        ts_type(schema, "Api.")
+                    ));
+                    body_arg = "body";
+                }
+                None => {
+                    warnings.push(format!(
+                        "{}: only JSON request bodies are supported, skipped",
+                        operation.label()
+                    ));
+                    continue;
+                }
+            }
+        }
+        if !query.is_empty() {
+            uses_query = true;
+            args.push(format!(
+                "query{}: {{ {} }}",
+                if query_required { "" } else { "?" },
+                query.join("; ")
+            ));
+        }
+
+        let url = if query.is_empty() {
+            format!("`{}`", url)
+        } else {
+            format!("withQuery(`{}`, query)", url)
+        };
+        let response = success_response(spec, operation.op)
+            .map(|schema| ts_type(schema, "Api."))
+            .unwrap_or_else(|| "unknown".to_string());
+        let call = match operation.method {
+            "post" | "put" => format!(
+                "api.{}<{}>({}, {})",
+                operation.method, response, url, body_arg
+            ),
+            _ => format!("api.{}<{}>({})", operation.method, response, url),
+        };
+        functions.push(format!(
+            "// {}\nexport const {} = ({}) =>\n  {};\n",
+            operation.label(),
+            name,
+            args.join(", "),
+            call
+        ));
+    }
+
+    let mut out = String::from(GENERATED_HEADER);
+    out.push_str("import { api } from './client';\n");
+    if functions.iter().any(|f| f.contains("Api.")) {
+        out.push_str("import type * as Api from '../types/api';\n");
+    }
+    if uses_query {
+        out.push_str(
+            r#"
+function withQuery(path: string, query?: Record<string, unknown>): string {
+  const params = new URLSearchParams();
+  for (const [key, value] of Object.entries(query ?? {})) {
+    if (value === undefined || value === null) continue;
+    for (const item of Array.isArray(value) ? value : [value]) {
+      params.append(key, String(item));
+    }
+  }
+  const search = params.toString();
+  return search ? `${path}?${search}` : path;
+}
+"#,
+        );
+    }
+    for function in functions {
+        out.push('\n');
+        out.push_str(&function);
+    }
+    out
+}
+
+fn property_names<'a>(spec: &'a Value, schema: &'a Value) -> Vec<(&'a str, String)> {
+    let mut schema = resolve(spec, schema);
+    if schema["type"] == "array" {
+        schema = resolve(spec, &schema["items"]);
+    }
+    schema["properties"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, property)| (name.as_str(), ts_type(property, "")))
+        .collect()
+}
+
+fn required_names<'a>(spec: &'a Value, schema: &'a Value) -> BTreeSet<&'a str> {
+    resolve(spec, schema)["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect()
+}
+
+// Changes that break an existing caller: removed operations and schemas, new required
+// inputs, and response fields that disappeared or changed type
+pub fn breaking_changes(old: &Value, new: &Value) -> Vec<String> {
+    let mut breaking = Vec::new();
+    let new_ops = operations(new);
+    for before in operations(old) {
+        let Some(after) = new_ops
+            .iter()
+            .find(|o| o.method == before.method && o.path == before.path)
+        else {
+            breaking.push(format!("Removed {}", before.label()));
+            continue;
+        };
+        let label = before.label();
+
+        let old_params = parameters(old, &before);
+        for param in parameters(new, after) {
+            let name = param["name"].as_str().unwrap_or_default();
+            let previous = old_params
+                .iter()
+                .find(|p| p["name"] == param["name"] && p["in"] == param["in"]);
+            let required = param["required"] == true;
+            match previous {
+                None if required => {
+                    breaking.push(format!("{}: new required parameter {}", label, name))
+                }
+                Some(previous) if required && previous["required"] != true => {
+                    breaking.push(format!("{}: parameter {} is now required", label, name))
+                }
+                Some(previous)
+                    if ts_type(&previous["schema"], "") != ts_type(&param["schema"], "") =>
+                {
+                    breaking.push(format!(
+                        "{}: parameter {} changed from {} to {}",
+                        label,
+                        name,
+                        ts_type(&previous["schema"], ""),
+                        ts_type(&param["schema"], "")
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        let old_body = json_schema(old, &before.op["requestBody"]);
+        if let Some(new_body) = json_schema(new, &after.op["requestBody"]) {
+            let before_required = old_body
+                .map(|schema| required_names(old, schema))
+                .unwrap_or_default();
+            for name in required_names(new, new_body) {
+                if !before_required.contains(name) {
+                    breaking.push(format!("{}: request body requires {}", label, name));
+                }
+            }
+        }
+
+        if let (Some(old_response), Some(new_response)) = (
+            success_response(old, before.op),
+            success_response(new, after.op),
+        ) {
+            let after_fields = property_names(new, new_response);
+            for (name, t) in property_names(old, old_response) {
+                match after_fields.iter().find(|(n, _)| *n == name) {
+                    None => {
+                        breaking.push(format!("{}: response no longer includes {}", label, name))
+                    }
+                    Some((_, new_t)) if *new_t != t => breaking.push(format!(
+                        "{}: response field {} changed from {} to {}",
+                        label, name, t, new_t
+                    )),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let new_schemas = &new["components"]["schemas"];
+    for name in old["components"]["schemas"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(n, _)| n)
+    {
+        if new_schemas.get(name).is_none() {
+            breaking.push(format!("Removed schema {}", name));
+        }
+    }
+    breaking
+}
+
+// The spec served by the running backend
+// Where the backend listens when it runs, e.g. "http://127.0.0.1:8000"
+pub fn backend_url(root: &Path) -> Result<String, Error> {
+    deps::service_dir(root, "backend")?;
+    let project = crate::detect_project_blocking(root.to_string_lossy().into_owned())?;
+    let port = project.backend_port.ok_or_else(|| {
+        Error::not_found(
+            "backend_port_unknown",
+            "Could not tell which port the backend listens on",
+        )
+    })?;
+    Ok(format!("http://127.0.0.1:{}", port))
+}
+
+pub fn fetch(root: &Path) -> Result<(String, Value), Error> {
+    let url = format!("{}/openapi.json", backend_url(root)?);
+    let response = reqwest::blocking::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?
+        .get(&url)
+        .send()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Process,
+                "backend_not_running",
+                format!(
+                    "Could not reach the backend at {}; start it first ({})",
+                    url, e
+                ),
+            )
+            .with("url", &url)
+        })?;
+    if !response.status().is_success() {
+        return Err(Error::not_found(
+            "openapi_not_found",
+            format!("{} returned {}", url, response.status()),
+        )
+        .with("url", &url));
+    }
+    let spec: Value = response.json().map_err(|e| {
+        Error::invalid(
+            "invalid_openapi",
+            format!("{} is not valid JSON: {}", url, e),
+        )
+    })?;
+    if spec.get("openapi").is_none() && spec.get("swagger").is_none() {
+        return Err(Error::invalid(
+            "invalid_openapi",
+            format!("{} is not an OpenAPI document", url),
+        ));
+    }
+    Ok((url, spec))
+}
+
+// Where the spec of the last sync is kept, to diff the next one against
+pub fn synced_spec_path(root: &Path) -> std::path::PathBuf {
+    manifest::manifest_path(root).with_file_name("openapi.json")
+}
+
+pub fn load_synced(root: &Path) -> Option<Value> {
+    fs::read_to_string(synced_spec_path(root))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+// The running backend's spec, or the last synced one while the backend is down.
+// The first value says which: "backend" or "last_sync".
+pub fn current_spec(root: &Path) -> Result<(&'static str, Value), Error> {
+    match fetch(root) {
+        Ok((_, spec)) => Ok(("backend", spec)),
+        Err(e) => load_synced(root).map(|spec| ("last_sync", spec)).ok_or(e),
+    }
+}
+
+#[tauri::command]
+pub async fn sync_api_types(project_path: String) -> Result<ApiSyncReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let frontend = deps::service_dir(root, "frontend")?;
+        let (spec_url, spec) = fetch(root)?;
+        let previous = load_synced(root);
+
+        let labels = |spec: &Value| -> BTreeSet<String> {
+            operations(spec).iter().map(Operation::label).collect()
+        };
+        let now = labels(&spec);
+        let (added, removed, breaking) = match &previous {
+            Some(previous) => {
+                let before = labels(previous);
+                (
+                    now.difference(&before).cloned().collect(),
+                    before.difference(&now).cloned().collect(),
+                    breaking_changes(previous, &spec),
+                )
+            }
+            None => (Vec::new(), Vec::new(), Vec::new()),
+        };
+
+        let mut warnings = Vec::new();
+        let files = [
+            (TYPES_FILE, types_source(&spec, GENERATED_HEADER)),
+            (CLIENT_FILE, client_source(&spec, &mut warnings)),
+        ];
+        for (file, content) in &files {
+            let path = frontend.join(file);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, content)?;
+        }
+
+        let synced = synced_spec_path(root);
+        if let Some(dir) = synced.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let content = serde_json::to_string_pretty(&spec).map_err(|e| e.to_string())?;
+        fs::write(&synced, content)?;
+
+        tracing::info!(%project_path, breaking = breaking.len(), "synced API types");
+        Ok(ApiSyncReport {
+            spec_url,
+            files: files.iter().map(|(file, _)| file.to_string()).collect(),
+            schemas: spec["components"]["schemas"]
+                .as_object()
+                .map_or(0, |schemas| schemas.len()),
+            operations: now.len(),
+            added,
+            removed,
+            breaking,
+            first_sync: previous.is_none(),
+            warnings,
+        })
+    })
+    .await
+}
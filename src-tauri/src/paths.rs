@@ -0,0 +1,300 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::Error;
+
+// Below MAX_PATH with room for the 8.3 file name CreateDirectory reserves
+const LONG_PATH: usize = 248;
+
+// Every path a command receives goes through here before it touches the disk, so
+// `C:\app`, `C:/app/` and a symlink to it all name the same project
+
+fn invalid(code: &str, message: String, input: &str) -> Error {
+    Error::invalid(code, message).with("path", input)
+}
+
+// \\server\share and //server/share. \\?\C:\ verbatim paths name a local drive, and
+// \\wsl$ / \\wsl.localhost shares are the local WSL distributions, so those pass.
+fn is_network(path: &str) -> bool {
+    let path = path.replace('\\', "/");
+    let share = match path.strip_prefix("//?/") {
+        Some(rest) => match rest.get(..4) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("UNC/") => &rest[4..],
+            _ => return false,
+        },
+        None => match path.strip_prefix("//") {
+            Some(share) => share,
+            None => return false,
+        },
+    };
+    let host = share.split('/').next().unwrap_or("").to_ascii_lowercase();
+    host != "wsl$" && host != "wsl.localhost"
+}
+
+fn check(input: &str) -> Result<&Path, Error> {
+    if input.trim().is_empty() {
+        return Err(invalid("invalid_path", "Path is empty".to_string(), input));
+    }
+    if input.contains('\0') {
+        return Err(invalid(
+            "invalid_path",
+            "Path contains a NUL byte".to_string(),
+            input,
+        ));
+    }
+    // Network shares and device paths: slow to scan, and a crafted share name makes
+    // Windows hand the user's credentials to whoever hosts it
+    if is_network(input) {
+        return Err(invalid(
+            "network_path",
+            format!("{} is a network path; use a local directory", input),
+            input,
+        ));
+    }
+    let path = Path::new(input);
+    if !path.is_absolute() {
+        return Err(invalid(
+            "relative_path",
+            format!("{} is not an absolute path", input),
+            input,
+        ));
+    }
+    if path.components().any(|c| c == Component::ParentDir) {
+        return Err(invalid(
+            "path_traversal",
+            format!("{} contains '..'", input),
+            input,
+        ));
+    }
+    Ok(path)
+}
+
+// dunce strips the \\?\ prefix Windows adds, which cmd and most tools reject
+fn canonical(input: &str, path: &Path) -> Result<PathBuf, Error> {
+    let canonical = dunce::canonicalize(path).map_err(|_| Error::path_not_found(input))?;
+    // A symlink or junction can still lead onto a share
+    if is_network(&canonical.to_string_lossy()) {
+        return Err(invalid(
+            "network_path",
+            format!("{} resolves to a network path", input),
+            input,
+        ));
+    }
+    Ok(canonical)
+}
+
+fn to_string(path: PathBuf) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+pub fn existing_dir(input: &str) -> Result<String, Error> {
+    let path = canonical(input, check(input)?)?;
+    if !path.is_dir() {
+        return Err(invalid(
+            "not_a_directory",
+            format!("{} is not a directory", input),
+            input,
+        ));
+    }
+    Ok(to_string(path))
+}
+
+pub fn existing_file(input: &str) -> Result<String, Error> {
+    let path = canonical(input, check(input)?)?;
+    if !path.is_file() {
+        return Err(invalid(
+            "not_a_file",
+            format!("{} is not a file", input),
+            input,
+        ));
+    }
+    Ok(to_string(path))
+}
+
+// A path that may not exist yet: the deepest existing ancestor is canonicalized and
+// the remaining components appended
+pub fn new_path(input: &str) -> Result<String, Error> {
+    let path = check(input)?;
+    let mut missing = Vec::new();
+    let mut existing = path;
+    while !existing.exists() {
+        let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+            return Err(Error::path_not_found(input));
+        };
+        missing.push(name);
+        existing = parent;
+    }
+    let mut resolved = canonical(input, existing)?;
+    resolved.extend(missing.into_iter().rev());
+    Ok(to_string(resolved))
+}
+
+pub fn optional_dir(input: Option<String>) -> Result<Option<String>, Error> {
+    input.as_deref().map(existing_dir).transpose()
+}
+
+// Windows rejects paths past MAX_PATH (260) unless they carry the \\?\ prefix, which
+// deep project directories hit quickly once frontend/src/... is appended
+pub fn extended(path: &Path) -> PathBuf {
+    let text = path.to_string_lossy();
+    if !cfg!(windows) || !path.is_absolute() || text.len() < LONG_PATH || text.starts_with(r"\\?\")
+    {
+        return path.to_path_buf();
+    }
+    // The prefix switches off all normalization, so separators must be backslashes
+    let text = text.replace('/', "\\");
+    match text.strip_prefix(r"\\") {
+        Some(share) => PathBuf::from(format!(r"\\?\UNC\{}", share)),
+        None => PathBuf::from(format!(r"\\?\{}", text)),
+    }
+}
+
+// Process keys use '/' so they match however the frontend spelled the path
+pub fn key(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+// Whether the key `path` is `root` or somewhere below it; Windows paths match regardless
+// of case
+pub fn within(root: &str, path: &str) -> bool {
+    let (root, path) = if cfg!(windows) {
+        (root.to_lowercase(), path.to_lowercase())
+    } else {
+        (root.to_string(), path.to_string())
+    };
+    let root = root.trim_end_matches('/');
+    path.trim_end_matches('/') == root
+        || path
+            .strip_prefix(root)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+// A tool-reported file as a '/'-separated path relative to the service directory
+pub fn relative(dir: &Path, file: &str) -> String {
+    key(&Path::new(file)
+        .strip_prefix(dir)
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| file.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An absolute path of exactly `len` bytes under `base`
+    fn long_path(base: &str, sep: char, len: usize) -> String {
+        let mut path = base.to_string();
+        while path.len() < len {
+            path.push(sep);
+            path.push_str("nested dir");
+        }
+        path.truncate(len);
+        path
+    }
+
+    #[test]
+    fn network_paths() {
+        assert!(is_network(r"\\server\share\app"));
+        assert!(is_network("//server/share/app"));
+        assert!(is_network(r"\\?\UNC\server\share\app"));
+        assert!(is_network(r"\\?\unc\server\share"));
+        assert!(!is_network(r"\\?\C:\Users\me\app"));
+        assert!(!is_network(r"\\wsl$\Ubuntu\home\me\app"));
+        assert!(!is_network(r"\\WSL.localhost\Ubuntu\home\me"));
+        assert!(!is_network(r"C:\My Projects\app (1)"));
+        assert!(!is_network("/home/me/my app"));
+        assert!(!is_network(r"\server\share"));
+    }
+
+    #[test]
+    fn keys_use_forward_slashes() {
+        assert_eq!(key(r"C:\My Projects\app (1)"), "C:/My Projects/app (1)");
+        assert_eq!(key(r"\\?\C:\Users\me\app"), "//?/C:/Users/me/app");
+        assert_eq!(key(r"\\server\share\café & co"), "//server/share/café & co");
+        assert_eq!(key("/home/me/my app"), "/home/me/my app");
+    }
+
+    #[test]
+    fn within_root() {
+        assert!(within("/home/me/my app", "/home/me/my app"));
+        assert!(within("/home/me/my app/", "/home/me/my app/backend"));
+        assert!(within("/home/me/my app", "/home/me/my app/front end/src"));
+        assert!(!within("/home/me/my app", "/home/me/my app2"));
+        assert!(!within("/home/me/my app", "/home/me"));
+        assert_eq!(
+            within("C:/Projects/App", "c:/projects/app/backend"),
+            cfg!(windows)
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn relative_to_service_dir() {
+        let dir = Path::new(r"C:\My Projects\app");
+        assert_eq!(
+            relative(dir, r"C:\My Projects\app\src\lib #1.rs"),
+            "src/lib #1.rs"
+        );
+        assert_eq!(relative(dir, r"C:\Other\main.py"), "C:/Other/main.py");
+        assert_eq!(relative(dir, r"src\main.py"), "src/main.py");
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn relative_to_service_dir() {
+        let dir = Path::new("/home/me/my app");
+        assert_eq!(
+            relative(dir, "/home/me/my app/src/lib #1.rs"),
+            "src/lib #1.rs"
+        );
+        assert_eq!(relative(dir, "/home/me/my app/src/café.py"), "src/café.py");
+        assert_eq!(relative(dir, "/srv/other/main.py"), "/srv/other/main.py");
+        assert_eq!(relative(dir, "src/main.py"), "src/main.py");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn extended_long_paths() {
+        let short = Path::new(r"C:\My Projects\app");
+        assert_eq!(extended(short), short);
+
+        let below = long_path(r"C:\My Projects", '\\', LONG_PATH - 1);
+        assert_eq!(extended(Path::new(&below)), Path::new(&below));
+
+        let long = long_path(r"C:\My Projects", '\\', 300);
+        assert_eq!(
+            extended(Path::new(&long)),
+            PathBuf::from(format!(r"\\?\{}", long))
+        );
+
+        let at_limit = long_path(r"C:\My Projects", '\\', LONG_PATH);
+        assert!(extended(Path::new(&at_limit))
+            .to_string_lossy()
+            .starts_with(r"\\?\C:\"));
+
+        // The prefix turns off '/' handling, so separators are converted
+        let mixed = long_path("C:/My Projects", '/', 300);
+        assert_eq!(
+            extended(Path::new(&mixed)),
+            PathBuf::from(format!(r"\\?\{}", mixed.replace('/', "\\")))
+        );
+
+        let share = long_path(r"\\server\share", '\\', 300);
+        assert_eq!(
+            extended(Path::new(&share)),
+            PathBuf::from(format!(r"\\?\UNC\{}", &share[2..]))
+        );
+
+        let verbatim = format!(r"\\?\{}", long);
+        assert_eq!(extended(Path::new(&verbatim)), Path::new(&verbatim));
+
+        let relative = long_path("nested", '\\', 300);
+        assert_eq!(extended(Path::new(&relative)), Path::new(&relative));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn extended_leaves_paths_alone() {
+        let long = long_path("/home/me/my app", '/', 300);
+        assert_eq!(extended(Path::new(&long)), Path::new(&long));
+    }
+}
@@ -0,0 +1,365 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::net::TcpListener;
+use std::path::Path;
+use std::process::Command;
+
+use crate::error::{Error, ErrorKind, Problem, Remediation};
+use crate::exec;
+use crate::paths;
+use crate::python;
+use crate::task;
+use crate::toolchain;
+
+#[derive(serde::Serialize)]
+pub struct PreflightFailure {
+    check: &'static str,
+    message: String,
+    hint: String,
+    remediation: Option<Remediation>,
+}
+
+#[derive(serde::Serialize)]
+pub struct PreflightReport {
+    pub ok: bool,
+    port: Option<u16>,
+    failures: Vec<PreflightFailure>,
+}
+
+impl PreflightReport {
+    pub fn summary(&self) -> String {
+        self.failures
+            .iter()
+            .map(|f| f.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    pub fn into_error(self) -> Error {
+        let message = format!("Preflight failed: {}", self.summary());
+        self.failures
+            .into_iter()
+            .filter_map(|f| f.remediation)
+            .fold(
+                Error::new(ErrorKind::Conflict, "preflight_failed", message),
+                Error::with_remediation,
+            )
+    }
+}
+
+pub fn run(
+    service_type: &str,
+    path: &Path,
+    command: &str,
+    env_vars: Option<&HashMap<String, String>>,
+) -> PreflightReport {
+    let mut failures = Vec::new();
+    check_runtime(path, &mut failures);
+    check_dependencies(path, &mut failures);
+
+    let port = command_port(path, command)
+        .or_else(|| {
+            env_vars
+                .and_then(|v| v.get("PORT"))
+                .and_then(|p| p.parse().ok())
+        })
+        .or_else(|| crate::detect_port(path, service_type));
+    if let Some(port) = port {
+        check_port(port, &mut failures);
+    }
+
+    check_env_vars(path, env_vars, &mut failures);
+
+    PreflightReport {
+        ok: failures.is_empty(),
+        port,
+        failures,
+    }
+}
+
+#[tauri::command]
+pub async fn preflight_service(
+    service_type: String,
+    project_path: String,
+    command: String,
+    env_vars: Option<HashMap<String, String>>,
+) -> Result<PreflightReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let path = Path::new(&project_path);
+        Ok(run(&service_type, path, &command, env_vars.as_ref()))
+    })
+    .await
+}
+
+fn is_python(path: &Path) -> bool {
+    path.join("requirements.txt").exists() || path.join("pyproject.toml").exists()
+}
+
+// The interpreter the service runs on, at the version the project pins
+fn check_runtime(path: &Path, failures: &mut Vec<PreflightFailure>) {
+    let (tool, required) = if path.join("package.json").exists() {
+        ("node", toolchain::node_requirement(path))
+    } else if is_python(path)
+        && python::venv_dir(path).is_none()
+        && python::detect_python_manager(path) != python::PythonPackageManager::Uv
+    {
+        // An existing venv brings its own interpreter; uv downloads one itself
+        ("python", toolchain::python_requirement(path))
+    } else {
+        return;
+    };
+
+    let version = if toolchain::which_all(tool).is_empty() {
+        None
+    } else {
+        toolchain::tool_version(tool)
+    };
+    let message = match (&version, &required) {
+        (None, _) => format!("{} is not installed", tool),
+        (Some(v), Some(r)) if toolchain::satisfies(v, r) == Some(false) => {
+            format!(
+                "{} {} does not satisfy the project requirement {}",
+                tool, v, r
+            )
+        }
+        _ => return,
+    };
+    failures.push(PreflightFailure {
+        check: "runtime",
+        message,
+        hint: format!("Install a {} version the project supports", tool),
+        remediation: Some(Remediation::new(Problem::MissingRuntime {
+            tool: tool.to_string(),
+            required_version: required,
+        })),
+    });
+}
+
+// Kills whatever holds the port, as long as it is still the process that was reported
+#[tauri::command]
+pub async fn free_port(port: u16, pid: u32) -> Result<(), Error> {
+    task::blocking(move || {
+        match port_owner(port) {
+            Some((owner, _)) if owner == pid => {}
+            Some((owner, _)) => {
+                return Err(Error::new(
+                    ErrorKind::Conflict,
+                    "port_owner_changed",
+                    format!("Port {} is now used by PID {}", port, owner),
+                )
+                .with("pid", owner))
+            }
+            None => return Ok(()),
+        }
+
+        let mut cmd = if cfg!(windows) {
+            let mut cmd = Command::new("taskkill");
+            cmd.args(["/F", "/T", "/PID", &pid.to_string()]);
+            cmd
+        } else {
+            let mut cmd = Command::new("kill");
+            cmd.arg(pid.to_string());
+            cmd
+        };
+        let output = exec::output(&mut cmd)?;
+        if !output.status.success() {
+            return Err(Error::tool_failed(
+                "kill",
+                format!(
+                    "Could not stop PID {}: {}",
+                    pid,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            ));
+        }
+        tracing::info!(port, pid, "freed port");
+        Ok(())
+    })
+    .await
+}
+
+fn check_dependencies(path: &Path, failures: &mut Vec<PreflightFailure>) {
+    let service_path = path.to_string_lossy().into_owned();
+    if path.join("package.json").exists() && !path.join("node_modules").is_dir() {
+        failures.push(PreflightFailure {
+            check: "dependencies",
+            message: "Frontend dependencies are not installed".to_string(),
+            hint: "Run the dependency install before starting the frontend".to_string(),
+            remediation: Some(Remediation::new(Problem::MissingDependencies {
+                service_path: service_path.clone(),
+                ecosystem: "npm",
+            })),
+        });
+    }
+
+    // uv creates and syncs its environment on `uv run`
+    if !is_python(path) || python::detect_python_manager(path) == python::PythonPackageManager::Uv {
+        return;
+    }
+
+    if python::venv_dir(path).is_none() {
+        failures.push(PreflightFailure {
+            check: "venv",
+            message: "Backend has no virtual environment".to_string(),
+            hint: "Create the virtual environment, then install dependencies".to_string(),
+            remediation: Some(Remediation::new(Problem::MissingVenv {
+                backend_path: service_path,
+            })),
+        });
+        return;
+    }
+
+    let installed = python::installed_distributions(path);
+    let missing: Vec<String> = python::declared_requirements(path)
+        .iter()
+        .filter_map(|r| python::requirement_name(r))
+        .filter(|name| !installed.contains_key(name))
+        .collect();
+    if !missing.is_empty() {
+        failures.push(PreflightFailure {
+            check: "dependencies",
+            message: format!("Backend packages not installed: {}", missing.join(", ")),
+            hint: "Install the backend dependencies into the virtual environment".to_string(),
+            remediation: Some(Remediation::new(Problem::MissingDependencies {
+                service_path,
+                ecosystem: "pypi",
+            })),
+        });
+    }
+}
+
+fn flag_port(words: &[&str]) -> Option<u16> {
+    words.iter().enumerate().find_map(|(i, word)| {
+        if let Some(value) = word.strip_prefix("--port=") {
+            return value.parse().ok();
+        }
+        if *word == "--port" || *word == "-p" {
+            return words.get(i + 1)?.parse().ok();
+        }
+        None
+    })
+}
+
+// --port on the command line, or inside the package.json script it runs
+fn command_port(path: &Path, command: &str) -> Option<u16> {
+    let words: Vec<&str> = command.split_whitespace().collect();
+    if let Some(port) = flag_port(&words) {
+        return Some(port);
+    }
+
+    let script = match words.as_slice() {
+        [_, "run", script, ..] => *script,
+        ["pnpm" | "yarn" | "bun", script, ..] => *script,
+        _ => return None,
+    };
+    let package: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path.join("package.json")).ok()?).ok()?;
+    let body = package["scripts"][script].as_str()?;
+    flag_port(&body.split_whitespace().collect::<Vec<_>>())
+}
+
+fn check_port(port: u16, failures: &mut Vec<PreflightFailure>) {
+    // Dev servers bind either loopback or all interfaces; either being taken is a clash
+    let free = TcpListener::bind(("127.0.0.1", port)).is_ok()
+        && TcpListener::bind(("0.0.0.0", port)).is_ok();
+    if free {
+        return;
+    }
+
+    let owner = port_owner(port);
+    let message = match &owner {
+        Some((pid, Some(name))) => format!("Port {} is in use by {} (PID {})", port, name, pid),
+        Some((pid, None)) => format!("Port {} is in use by PID {}", port, pid),
+        None => format!("Port {} is already in use", port),
+    };
+    failures.push(PreflightFailure {
+        check: "port",
+        message,
+        hint: "Stop the other process or start the service on a different port".to_string(),
+        remediation: Some(Remediation::new(Problem::PortInUse {
+            port,
+            pid: owner.as_ref().map(|(pid, _)| *pid),
+            process: owner.and_then(|(_, name)| name),
+        })),
+    });
+}
+
+fn command_stdout(program: &str, args: &[&str]) -> Option<String> {
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    let output = exec::output(&mut cmd).ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn port_owner(port: u16) -> Option<(u32, Option<String>)> {
+    if cfg!(windows) {
+        // "  TCP    0.0.0.0:8000    0.0.0.0:0    LISTENING    1234"
+        let suffix = format!(":{}", port);
+        let pid = command_stdout("netstat", &["-ano", "-p", "tcp"])?
+            .lines()
+            .map(|l| l.split_whitespace().collect::<Vec<_>>())
+            .find(|cols| cols.len() == 5 && cols[1].ends_with(&suffix) && cols[3] == "LISTENING")
+            .and_then(|cols| cols[4].parse::<u32>().ok())?;
+        // "\"python.exe\",\"1234\",..."
+        let name = command_stdout(
+            "tasklist",
+            &["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"],
+        )
+        .and_then(|out| out.split('"').nth(1).map(String::from));
+        Some((pid, name))
+    } else {
+        let target = format!("-iTCP:{}", port);
+        let pid = command_stdout("lsof", &["-nP", &target, "-sTCP:LISTEN", "-t"])?
+            .lines()
+            .find_map(|l| l.trim().parse::<u32>().ok())?;
+        let name = command_stdout("ps", &["-p", &pid.to_string(), "-o", "comm="])
+            .map(|out| out.trim().to_string())
+            .filter(|name| !name.is_empty());
+        Some((pid, name))
+    }
+}
+
+fn env_keys(path: &Path) -> HashSet<String> {
+    fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .filter_map(|l| l.trim_start_matches("export ").split_once('='))
+                .map(|(key, _)| key.trim().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Every key documented in .env.example must come from .env, the start request or the environment
+fn check_env_vars(
+    path: &Path,
+    env_vars: Option<&HashMap<String, String>>,
+    failures: &mut Vec<PreflightFailure>,
+) {
+    let defined = env_keys(&path.join(".env"));
+    let mut missing: Vec<String> = env_keys(&path.join(".env.example"))
+        .into_iter()
+        .filter(|key| !defined.contains(key))
+        .filter(|key| !env_vars.is_some_and(|v| v.contains_key(key)))
+        .filter(|key| std::env::var_os(key).is_none())
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+
+    missing.sort();
+    failures.push(PreflightFailure {
+        check: "env",
+        message: format!("Missing environment variables: {}", missing.join(", ")),
+        hint: "Add them to .env (see .env.example) or pass them when starting the service"
+            .to_string(),
+        remediation: Some(Remediation::new(Problem::MissingEnvVars {
+            service_path: path.to_string_lossy().into_owned(),
+            keys: missing,
+        })),
+    });
+}
@@ -0,0 +1,143 @@
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Manager};
+
+use crate::error::{Error, ErrorKind};
+use crate::paths;
+use crate::task;
+use crate::versioned::{self, Migration};
+
+const PROJECTS_FILE: &str = "projects.json";
+const PROJECTS_MIGRATIONS: &[Migration] = &[versioned::initial];
+
+// The project directories the user has added to devLLM. Paths from outside the app
+// (deep links, a second launch's arguments) and commands that run or delete things
+// only act inside these.
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+struct Registry {
+    // '/'-separated, as paths::key gives them
+    roots: Vec<String>,
+}
+
+pub struct ProjectRegistry {
+    path: Option<PathBuf>,
+    // Read on first use: a deep link, a launch argument or a command that checks it
+    registry: OnceLock<Mutex<Registry>>,
+}
+
+impl ProjectRegistry {
+    pub fn new(app: &AppHandle) -> Self {
+        let path = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join(PROJECTS_FILE));
+        ProjectRegistry {
+            path,
+            registry: OnceLock::new(),
+        }
+    }
+
+    // A missing or unreadable file starts an empty registry; the frontend syncs it on load
+    fn loaded(&self) -> &Mutex<Registry> {
+        self.registry.get_or_init(|| {
+            let registry = match &self.path {
+                Some(p) => match versioned::load(p, PROJECTS_MIGRATIONS, "project registry") {
+                    Ok(registry) => registry.unwrap_or_default(),
+                    Err(e) => {
+                        tracing::warn!("Ignoring project registry: {}", e);
+                        // A newer build's file stays put; save refuses to overwrite it
+                        if versioned::written_by_newer(p, PROJECTS_MIGRATIONS).is_none() {
+                            versioned::preserve_unreadable(p);
+                        }
+                        Registry::default()
+                    }
+                },
+                None => Registry::default(),
+            };
+            Mutex::new(registry)
+        })
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        let path = paths::key(path);
+        self.loaded()
+            .lock()
+            .roots
+            .iter()
+            .any(|root| paths::within(root, &path))
+    }
+
+    // Re-reads the file after something else replaced it, e.g. an app data import
+    pub fn reload(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let registry =
+            versioned::load(path, PROJECTS_MIGRATIONS, "project registry")?.unwrap_or_default();
+        *self.loaded().lock() = registry;
+        Ok(())
+    }
+
+    fn save(&self, registry: &Registry) -> Result<(), String> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or_else(|| "App data directory is unavailable".to_string())?;
+        versioned::save(path, registry, PROJECTS_MIGRATIONS, "project registry")
+    }
+}
+
+// Like paths::existing_dir, but also refuses a directory outside every registered project
+pub fn project_dir(app: &AppHandle, input: &str) -> Result<String, Error> {
+    let path = paths::existing_dir(input)?;
+    if !app.state::<ProjectRegistry>().contains(&path) {
+        return Err(Error::new(
+            ErrorKind::PermissionDenied,
+            "project_not_registered",
+            format!("{} is not inside a project added to devLLM", path),
+        )
+        .with("path", &path));
+    }
+    Ok(path)
+}
+
+// One project as the frontend knows it: the directory it was created in, when devLLM
+// created it, and its service directories
+#[derive(serde::Deserialize)]
+pub struct ProjectPaths {
+    root: Option<String>,
+    dirs: Vec<String>,
+}
+
+// Replaces the registry with the frontend's project list. Only the directories given are
+// registered, never a parent derived from them, which could be a home or workspace folder
+// holding unrelated projects. Directories that no longer exist are left out.
+#[tauri::command]
+pub async fn sync_projects(
+    app: AppHandle,
+    projects: Vec<ProjectPaths>,
+) -> Result<Vec<String>, Error> {
+    task::blocking(move || sync(&app.state::<ProjectRegistry>(), projects)).await
+}
+
+fn sync(state: &ProjectRegistry, projects: Vec<ProjectPaths>) -> Result<Vec<String>, Error> {
+    let mut synced = Registry::default();
+    for project in projects {
+        for dir in project.root.iter().chain(&project.dirs) {
+            let Ok(dir) = paths::existing_dir(dir) else {
+                continue;
+            };
+            let dir = paths::key(&dir);
+            if !synced.roots.contains(&dir) {
+                synced.roots.push(dir);
+            }
+        }
+    }
+    state.save(&synced)?;
+    let roots = synced.roots.clone();
+    *state.loaded().lock() = synced;
+    Ok(roots)
+}
@@ -0,0 +1,219 @@
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, ErrorKind};
+use crate::manifest;
+use crate::paths;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct PyprojectMigration {
+    pyproject_path: String,
+    manager: String,
+    dependencies: usize,
+    dev_dependencies: usize,
+    skipped: Vec<String>,
+    next_step: Option<String>,
+}
+
+fn read_requirements(path: &Path, skipped: &mut Vec<String>) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    let mut found = Vec::new();
+    for line in content.lines() {
+        let line = line.split(" #").next().unwrap_or("").trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        // Includes, editables and index options have no PEP 621 equivalent
+        if line.starts_with('-') {
+            skipped.push(line.to_string());
+            continue;
+        }
+        found.push(line.to_string());
+    }
+    found
+}
+
+fn toml_array(items: &[String]) -> String {
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    let body: Vec<String> = items
+        .iter()
+        .map(|i| format!("    \"{}\",", i.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect();
+    format!("[\n{}\n]", body.join("\n"))
+}
+
+// "uvicorn[standard]>=0.34" -> uvicorn = { version = ">=0.34", extras = ["standard"] }
+fn poetry_entry(requirement: &str) -> String {
+    let split = requirement
+        .find(|c: char| "<>=!~;[ ".contains(c))
+        .unwrap_or(requirement.len());
+    let (name, rest) = requirement.split_at(split);
+    let (extras, constraint) = match rest.strip_prefix('[') {
+        Some(rest) => match rest.split_once(']') {
+            Some((extras, constraint)) => (Some(extras), constraint),
+            None => (None, rest),
+        },
+        None => (None, rest),
+    };
+    let constraint = constraint.split(';').next().unwrap_or("").trim();
+    let version = if constraint.is_empty() {
+        "*"
+    } else {
+        constraint
+    };
+
+    match extras {
+        Some(extras) => {
+            let extras: Vec<String> = extras
+                .split(',')
+                .map(|e| format!("\"{}\"", e.trim()))
+                .collect();
+            format!(
+                "{} = {{ version = \"{}\", extras = [{}] }}",
+                name,
+                version,
+                extras.join(", ")
+            )
+        }
+        None => format!("{} = \"{}\"", name, version),
+    }
+}
+
+fn requires_python(backend: &Path) -> String {
+    let root = manifest::project_root(backend);
+    let version = manifest::load(&root)
+        .ok()
+        .and_then(|m| m.python)
+        .and_then(|p| p.version);
+
+    match version
+        .as_deref()
+        .map(|v| v.split('.').take(2).collect::<Vec<_>>())
+    {
+        Some(parts) if parts.len() == 2 => format!(">={}.{}", parts[0], parts[1]),
+        _ => ">=3.10".to_string(),
+    }
+}
+
+fn project_name(backend: &Path) -> String {
+    let root = manifest::project_root(backend);
+    let base = root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("app")
+        .to_lowercase();
+    let slug: String = base
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("{}-backend", slug.trim_matches('-'))
+}
+
+#[tauri::command]
+pub async fn migrate_to_pyproject(
+    backend_path: String,
+    manager: Option<String>,
+) -> Result<PyprojectMigration, Error> {
+    task::blocking(move || {
+        let backend_path = paths::existing_dir(&backend_path)?;
+        let path = Path::new(&backend_path);
+        let requirements = path.join("requirements.txt");
+        if !requirements.exists() {
+            return Err(Error::not_found(
+                "no_manifest",
+                format!("No requirements.txt found in {}", backend_path),
+            )
+            .with("path", &backend_path));
+        }
+        let pyproject_path = path.join("pyproject.toml");
+        if pyproject_path.exists() {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                "pyproject_exists",
+                "pyproject.toml already exists",
+            ));
+        }
+
+        let manager = manager.unwrap_or_else(|| "pip".to_string());
+        if !["pip", "uv", "poetry"].contains(&manager.as_str()) {
+            return Err(Error::invalid(
+                "unsupported_package_manager",
+                format!("Unsupported package manager: {}", manager),
+            )
+            .with("manager", &manager));
+        }
+
+        let mut skipped = Vec::new();
+        let dependencies = read_requirements(&requirements, &mut skipped);
+        let dev_file = path.join("requirements-dev.txt");
+        let dev_dependencies = read_requirements(&dev_file, &mut skipped);
+
+        let mut content = format!(
+            "[project]\nname = \"{}\"\nversion = \"0.1.0\"\ndescription = \"\"\nrequires-python = \"{}\"\ndependencies = {}\n",
+            project_name(path),
+            requires_python(path),
+            toml_array(&dependencies)
+        );
+
+        match manager.as_str() {
+            "uv" => {
+                if !dev_dependencies.is_empty() {
+                    content.push_str(&format!(
+                        "\n[dependency-groups]\ndev = {}\n",
+                        toml_array(&dev_dependencies)
+                    ));
+                }
+                // The backend is an application, not a distributable package
+                content.push_str("\n[tool.uv]\npackage = false\n");
+            }
+            "poetry" => {
+                content.push_str("\n[tool.poetry]\npackage-mode = false\n");
+                if !dev_dependencies.is_empty() {
+                    content.push_str("\n[tool.poetry.group.dev.dependencies]\n");
+                    for dep in &dev_dependencies {
+                        content.push_str(&poetry_entry(dep));
+                        content.push('\n');
+                    }
+                }
+            }
+            _ => {
+                if !dev_dependencies.is_empty() {
+                    content.push_str(&format!(
+                        "\n[project.optional-dependencies]\ndev = {}\n",
+                        toml_array(&dev_dependencies)
+                    ));
+                }
+            }
+        }
+
+        fs::write(&pyproject_path, content)?;
+
+        // Keep the originals around, but out of the way of requirements.txt-first tooling
+        fs::rename(&requirements, path.join("requirements.txt.bak"))?;
+        if dev_file.exists() {
+            fs::rename(&dev_file, path.join("requirements-dev.txt.bak"))?;
+        }
+
+        let next_step = match manager.as_str() {
+            "uv" => Some("Run `uv lock` to create uv.lock".to_string()),
+            "poetry" => Some("Run `poetry lock` to create poetry.lock".to_string()),
+            _ => None,
+        };
+
+        Ok(PyprojectMigration {
+            pyproject_path: pyproject_path.to_string_lossy().into_owned(),
+            manager,
+            dependencies: dependencies.len(),
+            dev_dependencies: dev_dependencies.len(),
+            skipped,
+            next_step,
+        })
+    })
+    .await
+}
@@ -0,0 +1,721 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use crate::deps::{InstallIssue, InstallReport, PackageCounts, INSTALL_EVENT};
+use crate::error::Error;
+use crate::exec;
+use crate::manifest::{self, PythonEnv};
+use crate::notify;
+use crate::paths;
+use crate::projects;
+use crate::task;
+
+const VENV_DIR: &str = ".venv";
+
+pub fn venv_bin(venv: &Path, name: &str) -> PathBuf {
+    if cfg!(windows) {
+        venv.join("Scripts").join(format!("{}.exe", name))
+    } else {
+        venv.join("bin").join(name)
+    }
+}
+
+// Prefer the interpreter recorded in the manifest, then the conventional .venv/venv dirs
+pub fn venv_python(backend_path: &Path) -> Option<PathBuf> {
+    let root = manifest::project_root(backend_path);
+    if let Some(env) = manifest::load(&root).ok().and_then(|m| m.python) {
+        let python = PathBuf::from(env.python_path);
+        if python.exists() {
+            return Some(python);
+        }
+    }
+
+    [VENV_DIR, "venv"]
+        .iter()
+        .map(|dir| venv_bin(&backend_path.join(dir), "python"))
+        .find(|python| python.exists())
+}
+
+pub fn venv_dir(backend_path: &Path) -> Option<PathBuf> {
+    let python = venv_python(backend_path)?;
+    python.parent()?.parent().map(Path::to_path_buf)
+}
+
+pub fn site_packages(venv: &Path) -> Option<PathBuf> {
+    if cfg!(windows) {
+        let site = venv.join("Lib").join("site-packages");
+        return site.exists().then_some(site);
+    }
+    fs::read_dir(venv.join("lib"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path().join("site-packages"))
+        .find(|site| site.exists())
+}
+
+// Normalised name -> (dist-info dir, version) for everything installed in the venv
+pub fn installed_distributions(backend_path: &Path) -> HashMap<String, (PathBuf, String)> {
+    let Some(site) = venv_dir(backend_path).and_then(|v| site_packages(&v)) else {
+        return HashMap::new();
+    };
+    let Ok(entries) = fs::read_dir(site) else {
+        return HashMap::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            let stem = file_name.strip_suffix(".dist-info")?;
+            let (name, version) = stem.rsplit_once('-')?;
+            Some((normalize_name(name), (entry.path(), version.to_string())))
+        })
+        .collect()
+}
+
+// Requirement strings from requirements.txt, or [project].dependencies in pyproject.toml
+pub fn declared_requirements(backend_path: &Path) -> Vec<String> {
+    if let Ok(content) = fs::read_to_string(backend_path.join("requirements.txt")) {
+        return content
+            .lines()
+            .filter(|l| requirement_name(l).is_some())
+            .map(|l| l.split('#').next().unwrap_or("").trim().to_string())
+            .collect();
+    }
+
+    let Ok(content) = fs::read_to_string(backend_path.join("pyproject.toml")) else {
+        return Vec::new();
+    };
+    let mut in_project = false;
+    let mut in_deps = false;
+    let mut found = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && !in_deps {
+            in_project = trimmed == "[project]";
+            continue;
+        }
+        if in_project && trimmed.starts_with("dependencies") {
+            in_deps = true;
+        }
+        if in_deps {
+            found.extend(trimmed.split('"').skip(1).step_by(2).map(String::from));
+            if trimmed.ends_with(']') {
+                in_deps = false;
+            }
+        }
+    }
+    found
+}
+
+pub struct VenvCommand {
+    pub command: String,
+    pub env: Vec<(String, String)>,
+}
+
+// Rewrites the leading program of a service command to the venv's interpreter or
+// console script, and activates the venv through the environment, so services
+// don't depend on the spawned shell having run `activate`. A venv recorded elsewhere
+// (e.g. at the project root) belongs to some other service and is left alone
+pub fn resolve_venv_command(backend_path: &Path, command: &str) -> Option<VenvCommand> {
+    let venv = venv_dir(backend_path).filter(|venv| venv.starts_with(backend_path))?;
+    let bin_dir = venv_bin(&venv, "python").parent()?.to_path_buf();
+
+    let trimmed = command.trim_start();
+    let (program, rest) = trimmed.split_once(' ').unwrap_or((trimmed, ""));
+
+    // Launchers that already manage their own environment are left alone
+    let resolved = if ["uv", "poetry", "pipenv", "hatch"].contains(&program) {
+        None
+    } else {
+        // ".venv/Scripts/uvicorn" on macOS or ".venv/bin/uvicorn" on Windows still
+        // name a console script; only the basename matters
+        let name = program
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or(program)
+            .trim_end_matches(".exe");
+        let name = match name {
+            "python3" | "py" => "python",
+            other => other,
+        };
+        let candidate = venv_bin(&venv, name);
+        candidate.exists().then_some(candidate)
+    };
+
+    let command = match resolved {
+        Some(path) if rest.is_empty() => exec::quote_arg(&path.to_string_lossy()),
+        Some(path) => format!("{} {}", exec::quote_arg(&path.to_string_lossy()), rest),
+        None => command.to_string(),
+    };
+
+    let mut paths = vec![bin_dir];
+    paths.extend(std::env::split_paths(
+        &std::env::var_os("PATH").unwrap_or_default(),
+    ));
+    let path_var = std::env::join_paths(paths).ok()?;
+
+    Some(VenvCommand {
+        command,
+        env: vec![
+            (
+                "VIRTUAL_ENV".to_string(),
+                venv.to_string_lossy().into_owned(),
+            ),
+            ("PATH".to_string(), path_var.to_string_lossy().into_owned()),
+        ],
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PythonPackageManager {
+    Pip,
+    Uv,
+    Poetry,
+}
+
+pub fn detect_python_manager(backend_path: &Path) -> PythonPackageManager {
+    let pyproject = fs::read_to_string(backend_path.join("pyproject.toml")).unwrap_or_default();
+    if backend_path.join("uv.lock").exists() || pyproject.contains("[tool.uv]") {
+        PythonPackageManager::Uv
+    } else if backend_path.join("poetry.lock").exists() || pyproject.contains("[tool.poetry]") {
+        PythonPackageManager::Poetry
+    } else {
+        PythonPackageManager::Pip
+    }
+}
+
+// PEP 503 normalisation so "Pydantic_Core" and "pydantic-core" compare equal
+pub fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase().replace(['_', '.'], "-")
+}
+
+pub fn requirement_name(line: &str) -> Option<String> {
+    let line = line.split('#').next().unwrap_or("").trim();
+    if line.is_empty() || line.starts_with('-') {
+        return None;
+    }
+    let name: String = line
+        .chars()
+        .take_while(|c| !"<>=!~[;@ ".contains(*c))
+        .collect();
+    (!name.is_empty()).then(|| normalize_name(&name))
+}
+
+// Candidate interpreters in order of preference; `py -3` is the Windows launcher
+fn interpreter_candidates() -> Vec<Vec<&'static str>> {
+    if cfg!(windows) {
+        vec![vec!["py", "-3"], vec!["python"], vec!["python3"]]
+    } else {
+        vec![vec!["python3"], vec!["python"]]
+    }
+}
+
+fn python_version(program: &str, args: &[&str]) -> Option<String> {
+    let output = exec::output(Command::new(program).args(args).arg("--version")).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Older interpreters print the version on stderr
+    let text = if output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    } else {
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+    text.trim()
+        .strip_prefix("Python ")
+        .map(|v| v.trim().to_string())
+}
+
+// A `key = value` line from the venv's pyvenv.cfg
+fn venv_setting(venv: &Path, key: &str) -> Option<String> {
+    let content = fs::read_to_string(venv.join("pyvenv.cfg")).ok()?;
+    content.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == key)
+            .then(|| value.trim().to_string())
+            .filter(|v| !v.is_empty())
+    })
+}
+
+// The venv module writes `version`, uv and virtualenv `version_info` ("3.12.1.final.0" from
+// virtualenv); asking the venv's own interpreter covers a config with neither
+fn venv_version(venv: &Path) -> Option<String> {
+    venv_setting(venv, "version")
+        .or_else(|| venv_setting(venv, "version_info"))
+        .map(|v| {
+            v.split('.')
+                .take_while(|part| part.chars().all(|c| c.is_ascii_digit()))
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .filter(|v| !v.is_empty())
+        .or_else(|| python_version(&venv_bin(venv, "python").to_string_lossy(), &[]))
+}
+
+fn find_interpreter(requested: Option<&str>) -> Result<(Vec<String>, Option<String>), String> {
+    if let Some(requested) = requested {
+        let parts: Vec<&str> = requested.split_whitespace().collect();
+        let (program, args) = parts
+            .split_first()
+            .ok_or_else(|| "Interpreter must not be empty".to_string())?;
+        let version = python_version(program, args)
+            .ok_or_else(|| format!("Interpreter not usable: {}", requested))?;
+        return Ok((parts.iter().map(|s| s.to_string()).collect(), Some(version)));
+    }
+
+    for candidate in interpreter_candidates() {
+        if let Some(version) = python_version(candidate[0], &candidate[1..]) {
+            return Ok((
+                candidate.iter().map(|s| s.to_string()).collect(),
+                Some(version),
+            ));
+        }
+    }
+
+    Err("No Python interpreter found on PATH".to_string())
+}
+
+#[tauri::command]
+pub async fn create_venv(
+    backend_path: String,
+    interpreter: Option<String>,
+) -> Result<PythonEnv, Error> {
+    task::blocking(move || {
+        let backend_path = paths::existing_dir(&backend_path)?;
+        let path = Path::new(&backend_path);
+
+        let venv = path.join(VENV_DIR);
+
+        // uv creates the environment itself, honouring .python-version
+        if detect_python_manager(path) == PythonPackageManager::Uv {
+            return Ok(create_uv_venv(path, &venv, interpreter.as_deref())?);
+        }
+
+        let python = venv_bin(&venv, "python");
+        // Reuse an existing environment instead of clobbering installed packages, and
+        // describe it as it is rather than by whichever interpreter is on PATH now
+        let (interpreter, version) = if python.exists() {
+            let interpreter = venv_setting(&venv, "executable")
+                .unwrap_or_else(|| python.to_string_lossy().into_owned());
+            (interpreter, venv_version(&venv))
+        } else {
+            let (interpreter, version) = find_interpreter(interpreter.as_deref())?;
+            let output = exec::output(
+                Command::new(&interpreter[0])
+                    .args(&interpreter[1..])
+                    .args(["-m", "venv", VENV_DIR])
+                    .current_dir(path),
+            )
+            .map_err(|e| exec::run_failed(&interpreter.join(" "), e))?;
+
+            if !output.status.success() {
+                return Err(Error::tool_failed(
+                    "python",
+                    format!(
+                        "Failed to create virtual environment: {}",
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ),
+                ));
+            }
+            (interpreter.join(" "), version)
+        };
+
+        let env = PythonEnv {
+            venv_path: venv.to_string_lossy().into_owned(),
+            python_path: python.to_string_lossy().into_owned(),
+            pip_path: venv_bin(&venv, "pip").to_string_lossy().into_owned(),
+            interpreter,
+            version,
+        };
+
+        Ok(record_venv(path, env)?)
+    })
+    .await
+}
+
+fn record_venv(backend_path: &Path, env: PythonEnv) -> Result<PythonEnv, String> {
+    let root = manifest::project_root(backend_path);
+    manifest::update(&root, |m| m.python = Some(env.clone()))?;
+    Ok(env)
+}
+
+fn create_uv_venv(
+    backend_path: &Path,
+    venv: &Path,
+    interpreter: Option<&str>,
+) -> Result<PythonEnv, String> {
+    let python = venv_bin(venv, "python");
+
+    if !python.exists() {
+        let mut cmd = exec::tool_command("uv");
+        cmd.args(["venv", VENV_DIR]).current_dir(backend_path);
+        if let Some(interpreter) = interpreter {
+            cmd.args(["--python", interpreter]);
+        }
+        let output = exec::output(&mut cmd).map_err(|e| format!("Failed to run uv: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "Failed to create virtual environment: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+    }
+
+    let version = venv_version(venv);
+    // uv venvs ship without pip; packages go through `uv pip`/`uv sync`
+    record_venv(
+        backend_path,
+        PythonEnv {
+            venv_path: venv.to_string_lossy().into_owned(),
+            python_path: python.to_string_lossy().into_owned(),
+            pip_path: venv_bin(venv, "pip").to_string_lossy().into_owned(),
+            interpreter: "uv".to_string(),
+            version,
+        },
+    )
+}
+
+#[tauri::command]
+pub async fn install_python_deps(
+    app: AppHandle,
+    backend_path: String,
+) -> Result<InstallReport, Error> {
+    task::blocking(move || {
+        let backend_path = projects::project_dir(&app, &backend_path)?;
+        let path = Path::new(&backend_path);
+
+        if detect_python_manager(path) == PythonPackageManager::Uv {
+            let mut cmd = exec::tool_command("uv");
+            cmd.arg("sync").current_dir(path);
+            return run_install(&app, &backend_path, "uv", cmd);
+        }
+
+        let python = venv_python(path).ok_or_else(|| {
+            Error::not_found("no_venv", "No virtual environment found; create one first")
+        })?;
+
+        let mut cmd = Command::new(&python);
+        cmd.args([
+            "-m",
+            "pip",
+            "install",
+            "--disable-pip-version-check",
+            "--progress-bar",
+            "off",
+        ])
+        .current_dir(path);
+
+        if path.join("requirements.txt").exists() {
+            cmd.args(["-r", "requirements.txt"]);
+        } else if path.join("pyproject.toml").exists() {
+            // The backend isn't a buildable package, so install its declared dependencies directly
+            let requirements = declared_requirements(path);
+            if requirements.is_empty() {
+                return Err(Error::invalid(
+                    "no_dependencies",
+                    "pyproject.toml declares no dependencies",
+                ));
+            }
+            cmd.args(requirements);
+        } else {
+            return Err(Error::not_found(
+                "no_manifest",
+                "No requirements.txt or pyproject.toml found",
+            )
+            .with("path", &backend_path));
+        }
+
+        run_install(&app, &backend_path, "pip", cmd)
+    })
+    .await
+}
+
+fn run_install(
+    app: &AppHandle,
+    backend_path: &str,
+    tool: &str,
+    mut cmd: Command,
+) -> Result<InstallReport, Error> {
+    let started = Instant::now();
+    let operation = format!("{}:{}", tool, backend_path);
+    let output = exec::run_streamed(app, INSTALL_EVENT, &operation, &mut cmd)
+        .map_err(|e| exec::run_failed(tool, e))?;
+
+    let issues = if output.status.success() {
+        Vec::new()
+    } else {
+        parse_pip_issues(&output.lines)
+    };
+
+    notify::install_finished(
+        app,
+        tool,
+        backend_path,
+        output.status.success(),
+        started.elapsed(),
+    );
+    Ok(InstallReport {
+        success: output.status.success(),
+        exit_code: output.status.code(),
+        duration_ms: started.elapsed().as_millis(),
+        packages: parse_install_counts(&output.lines),
+        issues,
+    })
+}
+
+fn requirement_after<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let rest = &line[line.find(marker)? + marker.len()..];
+    rest.split(|c: char| c.is_whitespace() || "<>=!~[;(".contains(c))
+        .find(|s| !s.is_empty())
+}
+
+fn parse_pip_issues(lines: &[String]) -> Vec<InstallIssue> {
+    let mut issues = Vec::new();
+    let mut failed_wheel: Option<String> = None;
+    let mut compiler_missing = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(pkg) = requirement_after(line, "Failed building wheel for ") {
+            failed_wheel = Some(pkg.to_string());
+        } else if line.contains("Microsoft Visual C++ 14.0 or greater is required")
+            || line.contains("unable to execute 'gcc'")
+            || line.contains("command 'gcc' failed")
+            || line.contains("command 'cc' failed")
+            || line.contains("xcrun: error: invalid active developer path")
+        {
+            compiler_missing = true;
+        } else if line.contains("No solution found when resolving dependencies") {
+            // uv explains the conflict in the indented lines that follow
+            let causes: Vec<String> = lines[i + 1..]
+                .iter()
+                .take_while(|l| l.starts_with(' ') || l.starts_with('\u{2570}'))
+                .map(|l| l.trim().to_string())
+                .collect();
+            issues.push(InstallIssue {
+                kind: "version_conflict",
+                package: None,
+                message: causes.join(" "),
+                hint: "Loosen or align the conflicting constraints in pyproject.toml".to_string(),
+            });
+        } else if line.contains("The conflict is caused by:") {
+            let causes: Vec<String> = lines[i + 1..]
+                .iter()
+                .take_while(|l| !l.trim().is_empty())
+                .map(|l| l.trim().to_string())
+                .collect();
+            issues.push(InstallIssue {
+                kind: "version_conflict",
+                package: None,
+                message: causes.join("; "),
+                hint: "Loosen or align the conflicting pins in requirements.txt".to_string(),
+            });
+        } else if let Some(pkg) = requirement_after(line, "No matching distribution found for ") {
+            issues.push(InstallIssue {
+                kind: "package_not_found",
+                package: Some(pkg.to_string()),
+                message: line.trim().to_string(),
+                hint: format!(
+                    "Check the spelling of {} and that the pinned version supports this Python version",
+                    pkg
+                ),
+            });
+        } else if line.contains("NewConnectionError")
+            || line.contains("Temporary failure in name resolution")
+            || line.contains("Could not fetch URL")
+        {
+            issues.push(InstallIssue {
+                kind: "network",
+                package: None,
+                message: line.trim().to_string(),
+                hint: "Check your internet connection or proxy settings (HTTPS_PROXY)".to_string(),
+            });
+        } else if line.contains("Access is denied") || line.contains("Permission denied") {
+            issues.push(InstallIssue {
+                kind: "permission",
+                package: None,
+                message: line.trim().to_string(),
+                hint: "Stop the running backend so its files are not locked, then retry"
+                    .to_string(),
+            });
+        }
+    }
+
+    // pip repeats itself (each retry, the ERROR summary); the first report of each
+    // problem is kept
+    let mut seen = HashSet::new();
+    issues.retain(|issue| seen.insert((issue.kind, issue.package.clone())));
+
+    if compiler_missing {
+        let hint = if cfg!(windows) {
+            "Install the Microsoft C++ Build Tools (\"Desktop development with C++\"), or pin a version with prebuilt wheels"
+        } else if cfg!(target_os = "macos") {
+            "Install the Xcode Command Line Tools: xcode-select --install"
+        } else {
+            "Install a C compiler and Python headers (e.g. build-essential python3-dev)"
+        };
+        issues.push(InstallIssue {
+            kind: "missing_compiler",
+            package: failed_wheel.clone(),
+            message: match &failed_wheel {
+                Some(pkg) => format!(
+                    "{} needs to be compiled from source but no compiler was found",
+                    pkg
+                ),
+                None => "A package needs to be compiled from source but no compiler was found"
+                    .to_string(),
+            },
+            hint: hint.to_string(),
+        });
+    }
+
+    if issues.is_empty() {
+        let message = lines
+            .iter()
+            .rev()
+            .find(|l| l.starts_with("ERROR:"))
+            .cloned()
+            .unwrap_or_else(|| "pip exited with an error".to_string());
+        issues.push(InstallIssue {
+            kind: "unknown",
+            package: failed_wheel,
+            message,
+            hint: "See the install output for details".to_string(),
+        });
+    }
+
+    issues
+}
+
+// pip: "Successfully installed a-1.0 b-2.0"; uv: "Installed 12 packages", "Uninstalled 2 packages"
+fn parse_install_counts(lines: &[String]) -> Option<PackageCounts> {
+    if let Some(list) = lines
+        .iter()
+        .find_map(|l| l.strip_prefix("Successfully installed "))
+    {
+        return Some(PackageCounts {
+            added: list.split_whitespace().count() as u32,
+            ..Default::default()
+        });
+    }
+
+    let count = |prefix: &str| {
+        lines.iter().find_map(|l| {
+            l.trim()
+                .strip_prefix(prefix)?
+                .split_whitespace()
+                .next()?
+                .parse::<u32>()
+                .ok()
+        })
+    };
+    let added = count("Installed ");
+    let removed = count("Uninstalled ");
+    (added.is_some() || removed.is_some()).then(|| PackageCounts {
+        added: added.unwrap_or(0),
+        removed: removed.unwrap_or(0),
+        changed: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issues(output: &str) -> Vec<InstallIssue> {
+        let lines: Vec<String> = output.lines().map(String::from).collect();
+        parse_pip_issues(&lines)
+    }
+
+    fn kinds(issues: &[InstallIssue]) -> Vec<(&str, Option<&str>)> {
+        issues
+            .iter()
+            .map(|i| (i.kind, i.package.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn reports_each_problem_once() {
+        let found = issues(
+            "\
+WARNING: Retrying (Retry(total=4)) after connection broken by 'NewConnectionError(...)': /simple/fastapi/
+WARNING: Retrying (Retry(total=3)) after connection broken by 'NewConnectionError(...)': /simple/fastapi/
+ERROR: Could not find a version that satisfies the requirement fastapi==0.110.0 (from versions: none)
+ERROR: No matching distribution found for fastapi==0.110.0
+ERROR: No matching distribution found for fastapi
+ERROR: No matching distribution found for uvicorn[standard]>=0.27
+ERROR: Could not install packages due to an OSError: [Errno 13] Permission denied: 'site-packages/a'
+ERROR: Could not install packages due to an OSError: [Errno 13] Permission denied: 'site-packages/b'",
+        );
+        assert_eq!(
+            kinds(&found),
+            [
+                ("network", None),
+                ("package_not_found", Some("fastapi")),
+                ("package_not_found", Some("uvicorn")),
+                ("permission", None),
+            ]
+        );
+        assert_eq!(
+            found[1].message,
+            "ERROR: No matching distribution found for fastapi==0.110.0"
+        );
+    }
+
+    #[test]
+    fn explains_version_conflicts() {
+        let found = issues(
+            "\
+ERROR: Cannot install fastapi==0.110.0 and starlette==0.20.0 because these package versions have conflicting dependencies.
+
+The conflict is caused by:
+    The user requested starlette==0.20.0
+    fastapi 0.110.0 depends on starlette<0.37.0 and >=0.36.3
+
+To fix this you could try to:",
+        );
+        assert_eq!(kinds(&found), [("version_conflict", None)]);
+        assert_eq!(
+            found[0].message,
+            "The user requested starlette==0.20.0; fastapi 0.110.0 depends on starlette<0.37.0 and >=0.36.3"
+        );
+
+        let found = issues(
+            "\
+  × No solution found when resolving dependencies:
+  ╰─▶ Because fastapi==0.110.0 depends on starlette>=0.36.3
+      and you require starlette==0.20.0, we can conclude that your requirements are unsatisfiable.",
+        );
+        assert_eq!(kinds(&found), [("version_conflict", None)]);
+        assert!(found[0].message.starts_with("╰─▶ Because fastapi==0.110.0"));
+    }
+
+    #[test]
+    fn names_the_wheel_that_needs_a_compiler() {
+        let found = issues(
+            "\
+  Building wheel for psycopg2 (setup.py): finished with status 'error'
+  Failed building wheel for psycopg2
+  error: command 'gcc' failed: No such file or directory
+ERROR: Failed building wheel for psycopg2",
+        );
+        assert_eq!(kinds(&found), [("missing_compiler", Some("psycopg2"))]);
+        assert_eq!(
+            found[0].message,
+            "psycopg2 needs to be compiled from source but no compiler was found"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_last_pip_error() {
+        let found = issues("Collecting fastapi\nERROR: first\nERROR: Something unexpected");
+        assert_eq!(kinds(&found), [("unknown", None)]);
+        assert_eq!(found[0].message, "ERROR: Something unexpected");
+        assert_eq!(issues("")[0].message, "pip exited with an error");
+    }
+}
@@ -0,0 +1,118 @@
+use std::process::ExitStatus;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::db;
+use crate::notify;
+use crate::ProcessManager;
+
+const SERVICE_EXITED_EVENT: &str = "service-exited";
+const REAP_INTERVAL: Duration = Duration::from_secs(2);
+const HISTORY_SIZE: usize = 200;
+
+#[derive(Clone, serde::Serialize)]
+pub struct ServiceExit {
+    service: String,
+    pid: u32,
+    code: Option<i32>,
+    // "stopped" by the user, "exited" cleanly on its own, or "crashed"
+    reason: &'static str,
+    at: String,
+}
+
+#[derive(Clone, serde::Serialize)]
+struct ServiceExited<'a> {
+    project_path: &'a str,
+    service_type: &'a str,
+    pid: u32,
+    code: Option<i32>,
+    reason: &'static str,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn record(
+    state: &ProcessManager,
+    key: &str,
+    pid: u32,
+    status: Option<ExitStatus>,
+    stopped: bool,
+) -> ServiceExit {
+    let reason = match status {
+        _ if stopped => "stopped",
+        Some(status) if status.success() => "exited",
+        _ => "crashed",
+    };
+    let mut history = state.history.lock();
+    if history.len() == HISTORY_SIZE {
+        history.pop_front();
+    }
+    let exit = ServiceExit {
+        service: key.to_string(),
+        pid,
+        code: status.and_then(|s| s.code()),
+        reason,
+        at: db::format_timestamp(now()),
+    };
+    history.push_back(exit.clone());
+    exit
+}
+
+// Waits on every tracked child so exited ones don't linger as zombies, then drops them
+// from the process map and records how they ended
+pub fn start(app: AppHandle) {
+    thread::spawn(move || loop {
+        thread::sleep(REAP_INTERVAL);
+        let state = app.state::<ProcessManager>();
+        let exited: Vec<(String, u32, ExitStatus)> = {
+            let mut processes = state.processes.lock();
+            let exited: Vec<(String, u32, ExitStatus)> = processes
+                .iter_mut()
+                .filter_map(|(key, child)| {
+                    let status = child.try_wait().ok()??;
+                    Some((key.clone(), child.id(), status))
+                })
+                .collect();
+            for (key, _, _) in &exited {
+                processes.remove(key);
+            }
+            exited
+        };
+        if exited.is_empty() {
+            continue;
+        }
+
+        for (key, pid, status) in exited {
+            let exit = record(&state, &key, pid, Some(status), false);
+            // Lets the UI mark the service as stopped or crashed without polling
+            let (project_path, service_type) = key.rsplit_once(':').unwrap_or((&key, ""));
+            let _ = app.emit(
+                SERVICE_EXITED_EVENT,
+                ServiceExited {
+                    project_path,
+                    service_type,
+                    pid,
+                    code: exit.code,
+                    reason: exit.reason,
+                },
+            );
+            if status.success() {
+                tracing::info!(service = %key, pid, "service exited");
+            } else {
+                notify::service_crashed(&app, &key, status.code());
+            }
+        }
+        crate::tray::refresh(&app);
+    });
+}
+
+#[tauri::command]
+pub fn get_service_history(state: State<ProcessManager>) -> Vec<ServiceExit> {
+    state.history.lock().iter().cloned().collect()
+}
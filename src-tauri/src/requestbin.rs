@@ -0,0 +1,185 @@
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, VecDeque};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+use crate::db;
+use crate::error::{Error, ErrorKind};
+use crate::localhttp::{self, Response};
+use crate::task;
+
+const REQUEST_EVENT: &str = "request-bin-request";
+// Requests kept per bin for get_request_bin_requests; older ones are dropped
+const KEPT_REQUESTS: usize = 200;
+const MAX_BODY_BYTES: usize = 5 * 1024 * 1024;
+
+static BINS: Mutex<BTreeMap<u16, Bin>> = Mutex::new(BTreeMap::new());
+
+struct Bin {
+    stop: Arc<AtomicBool>,
+    requests: Arc<Mutex<VecDeque<CapturedRequest>>>,
+}
+
+#[derive(serde::Serialize)]
+pub struct RequestBin {
+    port: u16,
+    url: String,
+    status: u16,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct CapturedRequest {
+    id: u64,
+    port: u16,
+    method: String,
+    path: String,
+    query: Option<String>,
+    headers: Vec<(String, String)>,
+    // Lossy UTF-8 when `binary` is set
+    body: String,
+    body_size: usize,
+    binary: bool,
+    // Over the size limit, so the body wasn't read
+    truncated: bool,
+    received_at: String,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn capture(id: u64, port: u16, request: &localhttp::Request) -> CapturedRequest {
+    let body = String::from_utf8(request.body.clone());
+    CapturedRequest {
+        id,
+        port,
+        method: request.method.clone(),
+        path: request.path().to_string(),
+        query: request
+            .target
+            .split_once('?')
+            .map(|(_, query)| query.to_string()),
+        headers: request.headers.clone(),
+        binary: body.is_err(),
+        body: body.unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()),
+        body_size: request.body.len(),
+        truncated: request.truncated,
+        received_at: db::format_timestamp(now()),
+    }
+}
+
+pub fn stop_all() {
+    for (port, bin) in std::mem::take(&mut *BINS.lock()) {
+        localhttp::shutdown(&bin.stop, port);
+    }
+}
+
+#[tauri::command]
+pub async fn start_request_bin(
+    app: AppHandle,
+    // A free port is picked when none is given
+    port: Option<u16>,
+    // What every request is answered with; 200 and {"ok": true} by default
+    status: Option<u16>,
+    response_body: Option<String>,
+) -> Result<RequestBin, Error> {
+    task::blocking(move || {
+        let status = status.unwrap_or(200);
+        if !(200..600).contains(&status) {
+            return Err(Error::invalid(
+                "invalid_status",
+                format!("Invalid response status: {}", status),
+            )
+            .with("status", status));
+        }
+        if let Some(port) = port.filter(|port| BINS.lock().contains_key(port)) {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                "request_bin_running",
+                format!("A request bin is already listening on port {}", port),
+            )
+            .with("port", port));
+        }
+        let listener = TcpListener::bind(("127.0.0.1", port.unwrap_or(0))).map_err(|e| {
+            Error::new(
+                ErrorKind::Conflict,
+                "port_in_use",
+                format!("Could not listen on port {}: {}", port.unwrap_or(0), e),
+            )
+        })?;
+        let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+
+        let body = response_body.unwrap_or_else(|| r#"{"ok": true}"#.to_string());
+        let bin = Bin {
+            stop: Arc::new(AtomicBool::new(false)),
+            requests: Arc::new(Mutex::new(VecDeque::new())),
+        };
+        let requests = bin.requests.clone();
+        let next_id = AtomicU64::new(1);
+        localhttp::serve(
+            listener,
+            MAX_BODY_BYTES,
+            bin.stop.clone(),
+            move |_| Response::json(status, body.clone()),
+            move |result| match result {
+                Ok((request, _)) => {
+                    let captured = capture(next_id.fetch_add(1, Ordering::SeqCst), port, request);
+                    let _ = app.emit(REQUEST_EVENT, &captured);
+                    let mut requests = requests.lock();
+                    if requests.len() == KEPT_REQUESTS {
+                        requests.pop_front();
+                    }
+                    requests.push_back(captured);
+                }
+                Err(e) => tracing::debug!(port, "request bin connection failed: {}", e),
+            },
+        );
+        BINS.lock().insert(port, bin);
+        tracing::info!(port, status, "started request bin");
+        Ok(RequestBin {
+            port,
+            url: format!("http://127.0.0.1:{}", port),
+            status,
+        })
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn stop_request_bin(port: u16) -> Result<String, Error> {
+    task::blocking(move || {
+        let Some(bin) = BINS.lock().remove(&port) else {
+            return Err(Error::not_found(
+                "request_bin_not_found",
+                format!("No request bin is listening on port {}", port),
+            )
+            .with("port", port));
+        };
+        localhttp::shutdown(&bin.stop, port);
+        Ok(format!("Request bin on port {} stopped", port))
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn get_request_bin_requests(port: u16) -> Result<Vec<CapturedRequest>, Error> {
+    task::blocking(move || {
+        let bins = BINS.lock();
+        let bin = bins.get(&port).ok_or_else(|| {
+            Error::not_found(
+                "request_bin_not_found",
+                format!("No request bin is listening on port {}", port),
+            )
+            .with("port", port)
+        })?;
+        let requests = bin.requests.lock().iter().cloned().collect();
+        Ok(requests)
+    })
+    .await
+}
@@ -0,0 +1,236 @@
+use std::fs;
+use std::path::Path;
+use std::process::Child;
+
+use postgres::{Client, NoTls};
+use tauri::{AppHandle, Manager};
+
+use crate::backup::{self, BackupInfo};
+use crate::db::{self, TableSchema};
+use crate::error::Error;
+use crate::migrations::{self, MigrationReport};
+use crate::paths;
+use crate::projects;
+use crate::reaper;
+use crate::seed::{self, SeedReport};
+use crate::task;
+use crate::ProcessManager;
+
+#[derive(serde::Serialize)]
+pub struct ResetReport {
+    stopped: Vec<String>,
+    backup: Option<BackupInfo>,
+    migrations: Option<MigrationReport>,
+    seeded: Vec<SeedReport>,
+    warnings: Vec<String>,
+}
+
+// The token is the project folder name, typed back by the user like a repo-delete prompt
+fn expected_token(project_path: &Path) -> String {
+    project_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+// Parents before children so foreign keys always have rows to point at
+fn seed_order(schemas: &[TableSchema]) -> Vec<String> {
+    let mut pending: Vec<&TableSchema> = schemas
+        .iter()
+        .filter(|s| s.name != "alembic_version")
+        .collect();
+    let mut order: Vec<String> = Vec::new();
+    while !pending.is_empty() {
+        let before = pending.len();
+        pending.retain(|schema| {
+            let ready = schema.foreign_keys.iter().all(|fk| {
+                fk.references_table == schema.name
+                    || order.contains(&fk.references_table)
+                    || !schemas.iter().any(|s| s.name == fk.references_table)
+            });
+            if ready {
+                order.push(schema.name.clone());
+            }
+            !ready
+        });
+        // Circular references: seed the rest in whatever order is left
+        if pending.len() == before {
+            order.extend(pending.drain(..).map(|s| s.name.clone()));
+        }
+    }
+    order
+}
+
+#[tauri::command]
+pub async fn reset_database(
+    app: AppHandle,
+    project_path: String,
+    confirm_token: String,
+    reseed: Option<usize>,
+) -> Result<ResetReport, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        let state = app.state::<ProcessManager>();
+        let path = Path::new(&project_path);
+        let expected = expected_token(path);
+        if expected.is_empty() || confirm_token != expected {
+            return Err(Error::invalid(
+                "confirmation_mismatch",
+                format!(
+                    "Confirmation token does not match; type \"{}\" to reset this database",
+                    expected
+                ),
+            )
+            .with("expected", &expected));
+        }
+
+        let (backend, url) = db::database_url(path);
+        let mut warnings = Vec::new();
+
+        // Running services hold the database open (and would recreate it half-way through)
+        // Keys are "<service dir>:<service>", and the service dirs sit below the root
+        let root = paths::key(&project_path);
+        let children: Vec<(String, Child)> = {
+            let mut processes = state.processes.lock();
+            let keys: Vec<String> = processes
+                .keys()
+                .filter(|k| {
+                    k.rsplit_once(':')
+                        .is_some_and(|(dir, _)| paths::within(&root, dir))
+                })
+                .cloned()
+                .collect();
+            keys.into_iter()
+                .filter_map(|key| processes.remove(&key).map(|child| (key, child)))
+                .collect()
+        };
+        let mut stopped = Vec::new();
+        for (key, mut child) in children {
+            let status = crate::kill_process(&mut child);
+            reaper::record(&state, &key, child.id(), status, true);
+            stopped.push(key.rsplit(':').next().unwrap_or(&key).to_string());
+        }
+
+        let backup = if let Some(db_path) = db::sqlite_path(&backend, &url) {
+            let backup = if db_path.exists() {
+                Some(backup::create_backup(&app, path, "pre-reset")?)
+            } else {
+                None
+            };
+            let name = db_path.to_string_lossy().into_owned();
+            for file in [
+                name.clone(),
+                format!("{}-wal", name),
+                format!("{}-shm", name),
+                format!("{}-journal", name),
+            ] {
+                if Path::new(&file).exists() {
+                    fs::remove_file(&file)
+                        .map_err(|e| format!("Failed to delete {}: {}", file, e))?;
+                }
+            }
+            backup
+        } else if let Some(pg_url) = db::postgres_url(&url) {
+            let backup = backup::create_backup(&app, path, "pre-reset")?;
+            let mut client = Client::connect(&pg_url, NoTls)?;
+            client
+                .batch_execute("DROP SCHEMA public CASCADE; CREATE SCHEMA public;")
+                .map_err(|e| format!("Failed to recreate schema: {}", e))?;
+            Some(backup)
+        } else {
+            return Err(Error::invalid(
+                "unsupported_database_url",
+                format!("Unsupported DATABASE_URL for reset: {}", url),
+            ));
+        };
+
+        let migrations = if backend.join("alembic.ini").exists() {
+            let report = migrations::run_migrations_blocking(
+                app.clone(),
+                backend.to_string_lossy().into_owned(),
+                None,
+            )?;
+            Some(report)
+        } else {
+            warnings.push(
+                "No alembic.ini found; tables are created the next time the backend starts"
+                    .to_string(),
+            );
+            None
+        };
+
+        let mut seeded = Vec::new();
+        if let Some(count) = reseed.filter(|c| *c > 0) {
+            if db::sqlite_path(&backend, &url).is_none() {
+                warnings.push("Reseeding is only supported for SQLite databases".to_string());
+            } else if migrations.is_none() {
+                warnings.push(
+                    "Skipped reseeding because no tables exist until the backend starts"
+                        .to_string(),
+                );
+            } else {
+                for table in seed_order(&db::introspect(&backend, &url)?) {
+                    match seed::seed_database_blocking(project_path.clone(), table.clone(), count) {
+                        Ok(report) => seeded.push(report),
+                        Err(e) => warnings.push(format!("Failed to seed {}: {}", table, e)),
+                    }
+                }
+            }
+        }
+
+        Ok(ResetReport {
+            stopped,
+            backup,
+            migrations,
+            seeded,
+            warnings,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schemas(sql: &str) -> Vec<TableSchema> {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(sql).unwrap();
+        db::sqlite_schemas(&conn).unwrap()
+    }
+
+    #[test]
+    fn seeds_parents_before_children() {
+        let schemas = schemas(
+            "CREATE TABLE order_items (id INTEGER PRIMARY KEY, order_id INTEGER REFERENCES orders(id), item_id INTEGER REFERENCES items(id));
+             CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER REFERENCES users(id));
+             CREATE TABLE items (id INTEGER PRIMARY KEY);
+             CREATE TABLE users (id INTEGER PRIMARY KEY, manager_id INTEGER REFERENCES users(id));
+             CREATE TABLE alembic_version (version_num TEXT PRIMARY KEY);",
+        );
+        let order = seed_order(&schemas);
+        let position = |table: &str| order.iter().position(|t| t == table).unwrap();
+
+        assert_eq!(order.len(), 4);
+        assert!(!order.contains(&"alembic_version".to_string()));
+        assert!(position("users") < position("orders"));
+        assert!(position("orders") < position("order_items"));
+        assert!(position("items") < position("order_items"));
+    }
+
+    #[test]
+    fn tolerates_cycles_and_missing_tables() {
+        let schemas = schemas(
+            "CREATE TABLE a (id INTEGER PRIMARY KEY, b_id INTEGER REFERENCES b(id));
+             CREATE TABLE b (id INTEGER PRIMARY KEY, a_id INTEGER REFERENCES a(id));
+             CREATE TABLE c (id INTEGER PRIMARY KEY, gone_id INTEGER REFERENCES gone(id));
+             CREATE TABLE d (id INTEGER PRIMARY KEY, a_id INTEGER REFERENCES a(id));",
+        );
+        let mut order = seed_order(&schemas);
+        // c doesn't wait for a table that doesn't exist; the cycle and what depends on it
+        // still get seeded
+        assert_eq!(order[0], "c");
+        order.sort();
+        assert_eq!(order, ["a", "b", "c", "d"]);
+    }
+}
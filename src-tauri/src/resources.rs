@@ -0,0 +1,172 @@
+use tauri::{AppHandle, Manager};
+
+use crate::compose;
+use crate::docker::{self, Engine};
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::task;
+use crate::ProcessManager;
+
+// Tab-separated so Docker and Podman, whose `{{json .}}` keys differ, parse the same way
+const STATS_FORMAT: &str =
+    "{{.Name}}\t{{.CPUPerc}}\t{{.MemUsage}}\t{{.MemPerc}}\t{{.NetIO}}\t{{.BlockIO}}\t{{.PIDs}}";
+
+#[derive(serde::Serialize)]
+pub struct ContainerStats {
+    // The compose stack or dev dependency the container belongs to
+    key: String,
+    project_path: String,
+    service: String,
+    container: String,
+    cpu_percent: f64,
+    memory_bytes: u64,
+    memory_limit_bytes: u64,
+    memory_percent: f64,
+    net_rx_bytes: u64,
+    net_tx_bytes: u64,
+    block_read_bytes: u64,
+    block_write_bytes: u64,
+    pids: u64,
+}
+
+struct Managed {
+    key: String,
+    project_path: String,
+    service: String,
+    container: String,
+}
+
+// "12.5MiB", "1.2kB", "0B" -> bytes; docker mixes binary and decimal units
+pub fn parse_size(text: &str) -> u64 {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let multiplier: f64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "kb" => 1e3,
+        "mb" => 1e6,
+        "gb" => 1e9,
+        "tb" => 1e12,
+        "kib" => 1024.0,
+        "mib" => 1024.0 * 1024.0,
+        "gib" => 1024.0 * 1024.0 * 1024.0,
+        "tib" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => 1.0,
+    };
+    (number.parse::<f64>().unwrap_or(0.0) * multiplier) as u64
+}
+
+// "12.5MiB / 7.6GiB" -> (used, limit)
+fn parse_pair(text: &str) -> (u64, u64) {
+    let (a, b) = text.split_once('/').unwrap_or((text, ""));
+    (parse_size(a), parse_size(b))
+}
+
+fn parse_percent(text: &str) -> f64 {
+    text.trim().trim_end_matches('%').parse().unwrap_or(0.0)
+}
+
+// Every running container devLLM manages: compose stack members and dev dependencies
+fn managed(app: &AppHandle, engine: Engine, project: Option<&str>) -> Vec<Managed> {
+    let state = app.state::<ProcessManager>();
+    let stacks = state.stacks.lock().clone();
+    let dependencies = state.dependencies.lock().clone();
+    let wanted = |path: &str| project.is_none_or(|p| paths::key(p) == paths::key(path));
+
+    let mut found = Vec::new();
+    for (key, stack) in stacks.into_iter().filter(|(_, s)| wanted(&s.project_path)) {
+        let Ok(containers) = compose::containers(engine, &stack) else {
+            continue;
+        };
+        for container in containers.into_iter().filter(|c| c.state == "running") {
+            found.push(Managed {
+                key: key.clone(),
+                project_path: stack.project_path.clone(),
+                service: container.service,
+                container: container.name,
+            });
+        }
+    }
+    for (key, dependency) in dependencies
+        .into_iter()
+        .filter(|(_, d)| wanted(&d.project_path))
+    {
+        found.push(Managed {
+            key,
+            project_path: dependency.project_path,
+            service: dependency.kind.to_string(),
+            container: dependency.container,
+        });
+    }
+    found
+}
+
+// One sample per container, for the resource dashboard to poll
+#[tauri::command]
+pub async fn get_container_stats(
+    app: AppHandle,
+    project_path: Option<String>,
+) -> Result<Vec<ContainerStats>, Error> {
+    task::blocking(move || {
+        let project_path = paths::optional_dir(project_path)?;
+        let state = app.state::<ProcessManager>();
+        if state.stacks.lock().is_empty() && state.dependencies.lock().is_empty() {
+            return Ok(Vec::new());
+        }
+        let engine = docker::require()?;
+        let managed = managed(&app, engine, project_path.as_deref());
+        if managed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut cmd = engine.command();
+        cmd.args(["stats", "--no-stream", "--format", STATS_FORMAT])
+            .args(managed.iter().map(|m| &m.container));
+        let output = exec::output(&mut cmd).map_err(|e| exec::run_failed(engine.program(), e))?;
+        // A container that stopped since it was listed fails the call for all of them,
+        // so whatever was printed is still used
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() && stdout.trim().is_empty() {
+            return Err(Error::tool_failed(
+                engine.program(),
+                format!(
+                    "Reading container stats failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            ));
+        }
+
+        let mut stats = Vec::new();
+        for line in stdout.lines() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [name, cpu, memory, memory_percent, net, block, pids] = fields[..] else {
+                continue;
+            };
+            let Some(owner) = managed.iter().find(|m| m.container == name) else {
+                continue;
+            };
+            let (memory_bytes, memory_limit_bytes) = parse_pair(memory);
+            let (net_rx_bytes, net_tx_bytes) = parse_pair(net);
+            let (block_read_bytes, block_write_bytes) = parse_pair(block);
+            stats.push(ContainerStats {
+                key: owner.key.clone(),
+                project_path: owner.project_path.clone(),
+                service: owner.service.clone(),
+                container: owner.container.clone(),
+                cpu_percent: parse_percent(cpu),
+                memory_bytes,
+                memory_limit_bytes,
+                memory_percent: parse_percent(memory_percent),
+                net_rx_bytes,
+                net_tx_bytes,
+                block_read_bytes,
+                block_write_bytes,
+                pids: pids.trim().parse().unwrap_or(0),
+            });
+        }
+        Ok(stats)
+    })
+    .await
+}
@@ -0,0 +1,366 @@
+use include_dir::{include_dir, Dir, DirEntry};
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use crate::cancel::{self, CancelToken};
+use crate::error::Error;
+use crate::paths;
+
+// Scaffold content lives in templates/ as plain files. `{{name}}` inserts a variable as
+// is; `{{name|format}}` escapes it for the file it lands in. `{{#name}}...{{/name}}`
+// keeps its contents only when the variable is non-empty.
+pub static FULLSTACK: Dir = include_dir!("$CARGO_MANIFEST_DIR/templates/fullstack");
+// Optional pieces layered over the fullstack template
+pub static E2E: Dir = include_dir!("$CARGO_MANIFEST_DIR/templates/e2e");
+pub static GRPC: Dir = include_dir!("$CARGO_MANIFEST_DIR/templates/grpc");
+
+fn escape(value: &str, format: &str) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match (format, c) {
+            ("html" | "jsx", '&') => out.push_str("&amp;"),
+            ("html" | "jsx", '<') => out.push_str("&lt;"),
+            ("html" | "jsx", '>') => out.push_str("&gt;"),
+            ("html", '"') => out.push_str("&quot;"),
+            // Braces would open a JSX expression
+            ("jsx", '{') => out.push_str("&#123;"),
+            ("jsx", '}') => out.push_str("&#125;"),
+            // Contents of a double-quoted Python, TOML or JSON string
+            ("py" | "toml" | "json", '"' | '\\') => {
+                out.push('\\');
+                out.push(c);
+            }
+            ("py" | "toml" | "json", c) if c.is_control() => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            ("html" | "jsx" | "py" | "toml" | "json", c) => out.push(c),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+fn sections(content: &str, vars: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("{{#") {
+        let after = &rest[start + 3..];
+        let section = after.find("}}").and_then(|end| {
+            let name = &after[..end];
+            let close = format!("{{{{/{}}}}}", name);
+            let body = &after[end + 2..];
+            let body_end = body.find(&close)?;
+            let enabled = vars.iter().any(|(n, v)| *n == name && !v.is_empty());
+            Some((enabled, &body[..body_end], &body[body_end + close.len()..]))
+        });
+        match section {
+            Some((enabled, body, remaining)) => {
+                out.push_str(&rest[..start]);
+                if enabled {
+                    out.push_str(body);
+                }
+                rest = remaining;
+            }
+            None => {
+                out.push_str(&rest[..start + 3]);
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// Only known variables are substituted, so JSX `{{ ... }}` and the like pass through untouched
+pub fn render(content: &str, vars: &[(&str, &str)]) -> String {
+    let content = sections(content, vars);
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content.as_str();
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let replaced = after.find("}}").and_then(|end| {
+            let (name, format) = match after[..end].split_once('|') {
+                Some((name, format)) => (name, Some(format)),
+                None => (&after[..end], None),
+            };
+            let value = vars.iter().find(|(n, _)| *n == name)?.1;
+            let value = match format {
+                Some(format) => escape(value, format)?,
+                None => value.to_string(),
+            };
+            Some((value, end + 2))
+        });
+        match replaced {
+            Some((value, consumed)) => {
+                out.push_str(&value);
+                rest = &after[consumed..];
+            }
+            None => {
+                out.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// npm and PyPI both accept lowercase ASCII letters, digits and single dashes; anything
+// else (spaces, quotes, emoji, accents) would make package.json or pyproject.toml invalid
+pub fn package_slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug: String = slug.chars().take(200).collect();
+    match slug.trim_end_matches('-') {
+        "" => "app".to_string(),
+        slug => slug.to_string(),
+    }
+}
+
+fn files<'a>(dir: &'a Dir<'a>, out: &mut Vec<&'a include_dir::File<'a>>) {
+    for entry in dir.entries() {
+        match entry {
+            DirEntry::Dir(dir) => files(dir, out),
+            DirEntry::File(file) => out.push(file),
+        }
+    }
+}
+
+// Files for one generated directory, collected before anything touches the disk
+pub struct Tree {
+    root: PathBuf,
+    files: Vec<(PathBuf, String)>,
+}
+
+impl Tree {
+    pub fn new(root: PathBuf) -> Self {
+        Tree {
+            root,
+            files: Vec::new(),
+        }
+    }
+
+    // `relative` uses '/' separators, e.g. "src/api/client.ts"
+    pub fn add(&mut self, relative: &str, content: impl Into<String>) {
+        self.files.push((self.root.join(relative), content.into()));
+    }
+
+    // Renders every file under `subdir` of a template into this tree, except `skip`
+    pub fn add_template(
+        &mut self,
+        template: &Dir,
+        subdir: &str,
+        vars: &[(&str, &str)],
+        skip: &[&str],
+    ) -> Result<(), String> {
+        let dir = template
+            .get_dir(subdir)
+            .ok_or_else(|| format!("Template has no {} directory", subdir))?;
+        let mut found = Vec::new();
+        files(dir, &mut found);
+        for file in found {
+            let relative = file
+                .path()
+                .strip_prefix(subdir)
+                .map_err(|e| e.to_string())?;
+            let name = relative.to_string_lossy().replace('\\', "/");
+            if skip.contains(&name.as_str()) {
+                continue;
+            }
+            let content = file
+                .contents_utf8()
+                .ok_or_else(|| format!("Template file {} is not UTF-8", name))?;
+            self.add(&name, render(content, vars));
+        }
+        Ok(())
+    }
+
+    fn write(&self, cancel: &CancelToken, written: &mut Written) -> Result<(), Error> {
+        // Each directory is created once, before any of its files
+        let dirs: BTreeSet<&Path> = self
+            .files
+            .iter()
+            .filter_map(|(path, _)| path.parent())
+            .chain([self.root.as_path()])
+            .collect();
+        for dir in dirs {
+            let mut missing: Vec<PathBuf> = dir
+                .ancestors()
+                .take_while(|a| !a.exists())
+                .map(Path::to_path_buf)
+                .collect();
+            fs::create_dir_all(paths::extended(dir))
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+            missing.reverse();
+            written.dirs.extend(missing);
+        }
+
+        for (path, content) in &self.files {
+            if cancel.is_cancelled() {
+                return Err(cancel::cancelled());
+            }
+            let target = paths::extended(path);
+            let previous = fs::read(&target).ok();
+            let file = File::create(&target)
+                .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+            written.files.push((target, previous));
+            let mut writer = BufWriter::new(file);
+            writer
+                .write_all(content.as_bytes())
+                .and_then(|_| writer.flush())
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+}
+
+// What a scaffold put on disk, so a failed or cancelled one can be undone
+#[derive(Default)]
+struct Written {
+    // Only directories that didn't exist before, parents first
+    dirs: Vec<PathBuf>,
+    // With the content each file had before, if it existed
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl Written {
+    fn roll_back(self) {
+        for (path, previous) in self.files.into_iter().rev() {
+            let _ = match previous {
+                Some(content) => fs::write(&path, content),
+                None => fs::remove_file(&path),
+            };
+        }
+        // remove_dir only removes empty directories, so nothing else is touched
+        for dir in self.dirs.into_iter().rev() {
+            let _ = fs::remove_dir(paths::extended(&dir));
+        }
+    }
+}
+
+// Trees are independent, so each gets its own thread; slow disks and network drives
+// then pay for the largest tree rather than the sum of all of them. Either every tree
+// is written or, on an error or cancellation, none of them is.
+pub fn write_all(trees: &[Tree], cancel: &CancelToken) -> Result<(), Error> {
+    let started = std::time::Instant::now();
+    let results: Vec<(Written, Result<(), Error>)> = thread::scope(|scope| {
+        let handles: Vec<_> = trees
+            .iter()
+            .map(|tree| {
+                scope.spawn(move || {
+                    let mut written = Written::default();
+                    let result = tree.write(cancel, &mut written);
+                    (written, result)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle.join().unwrap_or_else(|_| {
+                    (
+                        Written::default(),
+                        Err(Error::from("Scaffolding thread panicked")),
+                    )
+                })
+            })
+            .collect()
+    });
+
+    let (written, results): (Vec<Written>, Vec<Result<(), Error>>) = results.into_iter().unzip();
+    if let Err(e) = results.into_iter().collect::<Result<Vec<()>, Error>>() {
+        tracing::warn!("scaffolding stopped, rolling back: {}", e);
+        written.into_iter().for_each(Written::roll_back);
+        return Err(e);
+    }
+
+    tracing::debug!(
+        files = trees.iter().map(|t| t.files.len()).sum::<usize>(),
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        "scaffolded"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_plain_and_formatted_variables() {
+        let vars = [("name", r#"Tom & "Jerry" <app> {x} \ok"#)];
+        assert_eq!(
+            render("# {{name}}", &vars),
+            r#"# Tom & "Jerry" <app> {x} \ok"#
+        );
+        assert_eq!(
+            render("<title>{{name|html}}</title>", &vars),
+            r"<title>Tom &amp; &quot;Jerry&quot; &lt;app&gt; {x} \ok</title>"
+        );
+        assert_eq!(
+            render("<h1>{{name|jsx}}</h1>", &vars),
+            r#"<h1>Tom &amp; "Jerry" &lt;app&gt; &#123;x&#125; \ok</h1>"#
+        );
+        for format in ["py", "toml", "json"] {
+            assert_eq!(
+                render(&format!("name = \"{{{{name|{}}}}}\"", format), &vars),
+                r#"name = "Tom & \"Jerry\" <app> {x} \\ok""#
+            );
+        }
+        assert_eq!(
+            render("\"{{name|json}}\"", &[("name", "a\tb\nc")]),
+            r#""a\u0009b\u000ac""#
+        );
+    }
+
+    #[test]
+    fn leaves_unknown_placeholders_alone() {
+        let vars = [("name", "app")];
+        assert_eq!(render("{{other}} {{name}}", &vars), "{{other}} app");
+        // An unknown format is not substituted rather than inserted unescaped
+        assert_eq!(render("{{name|yaml}}", &vars), "{{name|yaml}}");
+        assert_eq!(
+            render("style={{ {color: 'red'} }}", &vars),
+            "style={{ {color: 'red'} }}"
+        );
+        assert_eq!(render("{{name", &vars), "{{name");
+    }
+
+    #[test]
+    fn sections_follow_their_variable() {
+        let template = "a{{#db}}\nDATABASE_URL={{db}}\n{{/db}}b";
+        assert_eq!(
+            render(template, &[("db", "postgres")]),
+            "a\nDATABASE_URL=postgres\nb"
+        );
+        assert_eq!(render(template, &[("db", "")]), "ab");
+        assert_eq!(render(template, &[]), "ab");
+        assert_eq!(
+            render("{{#x}}1{{/x}}{{#y}}2{{/y}}", &[("x", "on"), ("y", "")]),
+            "1"
+        );
+        // Without a closing tag the text is kept as it is
+        assert_eq!(render("{{#db}}open", &[("db", "x")]), "{{#db}}open");
+    }
+
+    #[test]
+    fn package_slugs() {
+        assert_eq!(package_slug("My App"), "my-app");
+        assert_eq!(package_slug("  --Hello,  World!!  "), "hello-world");
+        assert_eq!(package_slug("Café \"Quotes\""), "caf-quotes");
+        assert_eq!(package_slug("project_2"), "project-2");
+        assert_eq!(package_slug("🚀🚀"), "app");
+        assert_eq!(package_slug(""), "app");
+        assert_eq!(package_slug(&"a".repeat(300)).len(), 200);
+    }
+}
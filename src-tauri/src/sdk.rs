@@ -0,0 +1,759 @@
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::openapi::{self, Operation};
+use crate::paths;
+use crate::scaffold;
+use crate::task;
+
+const DEFAULT_BACKEND_URL: &str = "http://127.0.0.1:8000";
+const SDK_DIR: &str = "sdk";
+const TS_HEADER: &str =
+    "// Generated by devLLM from the project's OpenAPI spec. Regenerate instead of editing.\n";
+const PY_HEADER: &str =
+    "# Generated by devLLM from the project's OpenAPI spec. Regenerate instead of editing.\n";
+// Hard keywords only; match, case and type are fine as names
+const PY_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+#[derive(serde::Serialize)]
+pub struct SdkReport {
+    language: &'static str,
+    path: String,
+    files: Vec<String>,
+    operations: usize,
+    schemas: usize,
+    // "backend" or "last_sync", as in openapi::current_spec
+    spec_source: &'static str,
+    warnings: Vec<String>,
+}
+
+// One operation, with what both generators need already resolved
+struct Call<'a> {
+    operation: Operation<'a>,
+    // operationId, or "get /items" when the spec has none
+    name: String,
+    path_params: Vec<(&'a str, &'a Value)>,
+    // (name, schema, required)
+    query: Vec<(&'a str, &'a Value, bool)>,
+    // (schema, required)
+    body: Option<(&'a Value, bool)>,
+    response: Option<&'a Value>,
+}
+
+fn calls<'a>(spec: &'a Value, warnings: &mut Vec<String>) -> Vec<Call<'a>> {
+    let mut calls = Vec::new();
+    for operation in openapi::operations(spec) {
+        let mut path_params = Vec::new();
+        let mut query = Vec::new();
+        for param in openapi::parameters(spec, &operation) {
+            let Some(name) = param["name"].as_str() else {
+                continue;
+            };
+            match param["in"].as_str() {
+                Some("path") => path_params.push((name, &param["schema"])),
+                Some("query") => query.push((name, &param["schema"], param["required"] == true)),
+                _ => {}
+            }
+        }
+        let request_body = &operation.op["requestBody"];
+        let body = if request_body.is_null() {
+            None
+        } else {
+            match openapi::json_schema(spec, request_body) {
+                Some(schema) => Some((
+                    schema,
+                    openapi::resolve(spec, request_body)["required"] == true,
+                )),
+                None => {
+                    warnings.push(format!(
+                        "{}: only JSON request bodies are supported, skipped",
+                        operation.label()
+                    ));
+                    continue;
+                }
+            }
+        };
+        calls.push(Call {
+            name: operation.op["operationId"]
+                .as_str()
+                .map(String::from)
+                .unwrap_or_else(|| format!("{} {}", operation.method, operation.path)),
+            response: openapi::success_response(spec, operation.op),
+            operation,
+            path_params,
+            query,
+            body,
+        });
+    }
+    calls
+}
+
+// Appends 2, 3, ... to names already taken
+fn unique(names: &mut BTreeSet<String>, base: String) -> String {
+    let mut name = base.clone();
+    let mut n = 2;
+    while !names.insert(name.clone()) {
+        name = format!("{}{}", base, n);
+        n += 1;
+    }
+    name
+}
+
+fn typescript_client(calls: &[Call], base_url: &str) -> String {
+    let mut out = String::from(TS_HEADER);
+    out.push_str("import type * as Types from './types.js';\n");
+    out.push_str(
+        r#"
+export class ApiError extends Error {
+  readonly status: number;
+  readonly body: unknown;
+
+  constructor(status: number, body: unknown) {
+    super(`HTTP ${status}`);
+    this.status = status;
+    this.body = body;
+  }
+}
+
+export interface ClientOptions {
+  baseUrl?: string;
+  headers?: Record<string, string>;
+  fetch?: typeof fetch;
+}
+
+export class ApiClient {
+  private readonly baseUrl: string;
+  private readonly headers: Record<string, string>;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: ClientOptions = {}) {
+    this.baseUrl = (options.baseUrl ?? 'BASE_URL').replace(/\/$/, '');
+    this.headers = options.headers ?? {};
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  private async request<T>(
+    method: string,
+    path: string,
+    query?: Record<string, unknown>,
+    body?: unknown,
+  ): Promise<T> {
+    const url = new URL(this.baseUrl + path);
+    for (const [key, value] of Object.entries(query ?? {})) {
+      if (value === undefined || value === null) continue;
+      for (const item of Array.isArray(value) ? value : [value]) {
+        url.searchParams.append(key, String(item));
+      }
+    }
+    const headers: Record<string, string> = { Accept: 'application/json', ...this.headers };
+    if (body !== undefined) headers['Content-Type'] = 'application/json';
+    const response = await this.fetchImpl(url, {
+      method,
+      headers,
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    let data: unknown = text || undefined;
+    try {
+      data = text ? JSON.parse(text) : undefined;
+    } catch {
+      // Not JSON; the raw text is kept
+    }
+    if (!response.ok) throw new ApiError(response.status, data);
+    return data as T;
+  }
+"#
+        .replace("BASE_URL", base_url)
+        .as_str(),
+    );
+
+    let mut names = BTreeSet::new();
+    for call in calls {
+        let name = unique(&mut names, openapi::camel(&call.name));
+        let mut args = Vec::new();
+        let mut url = call.operation.path.to_string();
+        for (param, schema) in &call.path_params {
+            let arg = openapi::camel(param);
+            url = url.replace(
+                &format!("{{{}}}", param),
+                &format!("${{encodeURIComponent(String({}))}}", arg),
+            );
+            args.push(format!("{}: {}", arg, openapi::ts_type(schema, "Types.")));
+        }
+        if let Some((schema, required)) = call.body {
+            args.push(format!(
+                "body{}: {}",
+                if required { "" } else { "?" },
+                openapi::ts_type(schema, "Types.")
+            ));
+        }
+        if !call.query.is_empty() {
+            let fields: Vec<String> = call
+                .query
+                .iter()
+                .map(|(param, schema, required)| {
+                    format!(
+                        "{}{}: {}",
+                        Value::from(*param),
+                        if *required { "" } else { "?" },
+                        openapi::ts_type(schema, "Types.")
+                    )
+                })
+                .collect();
+            let required = call.query.iter().any(|(_, _, required)| *required);
+            args.push(format!(
+                "query{}: {{ {} }}",
+                if required { "" } else { "?" },
+                fields.join("; ")
+            ));
+        }
+        let response = call
+            .response
+            .map(|schema| openapi::ts_type(schema, "Types."))
+            .unwrap_or_else(|| "unknown".to_string());
+        let query = if call.query.is_empty() {
+            "undefined"
+        } else {
+            "query"
+        };
+        let mut request_args = vec![
+            format!("'{}'", call.operation.method.to_uppercase()),
+            format!("`{}`", url),
+        ];
+        if call.body.is_some() {
+            request_args.push(query.to_string());
+            request_args.push("body".to_string());
+        } else if !call.query.is_empty() {
+            request_args.push(query.to_string());
+        }
+        out.push_str(&format!(
+            "\n  // {}\n  {}({}): Promise<{}> {{\n    return this.request<{}>({});\n  }}\n",
+            call.operation.label(),
+            name,
+            args.join(", "),
+            response,
+            response,
+            request_args.join(", ")
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn typescript(
+    spec: &Value,
+    calls: &[Call],
+    slug: &str,
+    version: &str,
+    base_url: &str,
+) -> Vec<(String, String)> {
+    let package = serde_json::json!({
+        "name": format!("{}-client", slug),
+        "version": version,
+        "private": true,
+        "type": "module",
+        "main": "dist/index.js",
+        "types": "dist/index.d.ts",
+        "scripts": { "build": "tsc" },
+        "devDependencies": { "typescript": "~5.8.3" },
+    });
+    let tsconfig = serde_json::json!({
+        "compilerOptions": {
+            "target": "ES2020",
+            "lib": ["ES2020", "DOM"],
+            "module": "NodeNext",
+            "moduleResolution": "NodeNext",
+            "declaration": true,
+            "outDir": "dist",
+            "strict": true,
+            "skipLibCheck": true,
+        },
+        "include": ["src"],
+    });
+    let mut types = openapi::types_source(spec, TS_HEADER);
+    if types == TS_HEADER {
+        // Keeps types.ts a module when the spec has no schemas
+        types.push_str("export {};\n");
+    }
+    vec![
+        ("package.json".to_string(), format!("{:#}\n", package)),
+        ("tsconfig.json".to_string(), format!("{:#}\n", tsconfig)),
+        ("src/types.ts".to_string(), types),
+        (
+            "src/client.ts".to_string(),
+            typescript_client(calls, base_url),
+        ),
+        (
+            "src/index.ts".to_string(),
+            format!(
+                "{}export * from './types.js';\nexport * from './client.js';\n",
+                TS_HEADER
+            ),
+        ),
+    ]
+}
+
+// "getItems", "get /items/{id}" -> "get_items", "get_items_id"
+fn snake(name: &str) -> String {
+    let mut out = String::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase()
+                && previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+            {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else if !out.is_empty() && !out.ends_with('_') {
+            out.push('_');
+        }
+        previous = Some(c);
+    }
+    let out = out.trim_end_matches('_').to_string();
+    if out.is_empty() {
+        "call".to_string()
+    } else if out.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", out)
+    } else if PY_KEYWORDS.contains(&out.as_str()) {
+        format!("{}_", out)
+    } else {
+        out
+    }
+}
+
+fn py_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !PY_KEYWORDS.contains(&name)
+}
+
+fn py_literal(value: &Value) -> String {
+    match value {
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Null => "None".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn py_union(types: impl IntoIterator<Item = String>) -> String {
+    let mut seen = Vec::new();
+    for t in types {
+        if !seen.contains(&t) {
+            seen.push(t);
+        }
+    }
+    if seen.is_empty() {
+        "Any".to_string()
+    } else {
+        seen.join(" | ")
+    }
+}
+
+// The Python counterpart of openapi::ts_type
+fn py_type(schema: &Value, prefix: &str) -> String {
+    if let Some(name) = openapi::ref_name(schema) {
+        return format!("{}{}", prefix, openapi::type_name(name));
+    }
+    if let Some(values) = schema["enum"].as_array() {
+        let literals: Vec<String> = values.iter().map(py_literal).collect();
+        return format!("Literal[{}]", literals.join(", "));
+    }
+    if let Some(value) = schema.get("const") {
+        return format!("Literal[{}]", py_literal(value));
+    }
+    for key in ["anyOf", "oneOf"] {
+        if let Some(parts) = schema[key].as_array() {
+            return py_union(parts.iter().map(|part| py_type(part, prefix)));
+        }
+    }
+    // Python types have no intersection; a single part is used as is
+    if let Some(parts) = schema["allOf"].as_array() {
+        return match parts.as_slice() {
+            [part] => py_type(part, prefix),
+            _ => "dict[str, Any]".to_string(),
+        };
+    }
+    let base = match &schema["type"] {
+        Value::Array(types) => py_union(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|t| py_primitive(schema, t, prefix)),
+        ),
+        Value::String(t) => py_primitive(schema, t, prefix),
+        _ if schema.get("properties").is_some() => "dict[str, Any]".to_string(),
+        _ => "Any".to_string(),
+    };
+    if schema["nullable"] == true {
+        format!("{} | None", base)
+    } else {
+        base
+    }
+}
+
+fn py_primitive(schema: &Value, t: &str, prefix: &str) -> String {
+    match t {
+        "string" if schema["format"] == "binary" => "bytes".to_string(),
+        "string" => "str".to_string(),
+        "integer" => "int".to_string(),
+        "number" => "float".to_string(),
+        "boolean" => "bool".to_string(),
+        "null" => "None".to_string(),
+        "array" => format!("list[{}]", py_type(&schema["items"], prefix)),
+        "object" => match &schema["additionalProperties"] {
+            Value::Object(_) => format!(
+                "dict[str, {}]",
+                py_type(&schema["additionalProperties"], prefix)
+            ),
+            _ => "dict[str, Any]".to_string(),
+        },
+        _ => "Any".to_string(),
+    }
+}
+
+fn python_models(spec: &Value) -> String {
+    let mut out = String::from(PY_HEADER);
+    out.push_str("from __future__ import annotations\n\n");
+    out.push_str(
+        "from typing import Any, Literal, NotRequired, TypeAlias, TypedDict  # noqa: F401\n",
+    );
+    for (name, schema) in spec["components"]["schemas"]
+        .as_object()
+        .into_iter()
+        .flatten()
+    {
+        let name = openapi::type_name(name);
+        let required: BTreeSet<&str> = schema["required"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let properties: Vec<(&String, String)> = schema["properties"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(field, property)| {
+                let t = py_type(property, "");
+                if required.contains(field.as_str()) {
+                    (field, t)
+                } else {
+                    (field, format!("NotRequired[{}]", t))
+                }
+            })
+            .collect();
+        let plain_object = !properties.is_empty()
+            && ["anyOf", "oneOf", "allOf", "enum"]
+                .iter()
+                .all(|key| schema.get(*key).is_none());
+        out.push_str("\n\n");
+        if !plain_object {
+            // Quoted, so aliases may refer to models defined further down
+            out.push_str(&format!(
+                "{}: TypeAlias = {}\n",
+                name,
+                Value::from(py_type(schema, ""))
+            ));
+        } else if properties.iter().all(|(field, _)| py_identifier(field)) {
+            out.push_str(&format!("class {}(TypedDict):\n", name));
+            if let Some(description) = schema["description"].as_str() {
+                out.push_str(&format!("    {}\n\n", py_docstring(description)));
+            }
+            for (field, t) in properties {
+                out.push_str(&format!("    {}: {}\n", field, t));
+            }
+        } else {
+            // Keys like "first-name" need the functional syntax
+            let fields: Vec<String> = properties
+                .iter()
+                .map(|(field, t)| {
+                    format!(
+                        "{}: {}",
+                        Value::from(field.as_str()),
+                        Value::from(t.as_str())
+                    )
+                })
+                .collect();
+            out.push_str(&format!(
+                "{} = TypedDict({}, {{{}}})\n",
+                name,
+                Value::from(name.as_str()),
+                fields.join(", ")
+            ));
+        }
+    }
+    out
+}
+
+fn py_docstring(text: &str) -> String {
+    let text = text
+        .trim()
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\\\"\\\"");
+    format!("\"\"\"{}\"\"\"", text)
+}
+
+fn python_client(calls: &[Call], base_url: &str) -> String {
+    let mut out = String::from(PY_HEADER);
+    out.push_str(
+        r#"from __future__ import annotations
+
+import json
+from typing import Any
+from urllib.error import HTTPError
+from urllib.parse import quote, urlencode
+from urllib.request import Request, urlopen
+
+from . import models
+
+
+class ApiError(Exception):
+    def __init__(self, status: int, body: Any):
+        super().__init__(f"HTTP {status}: {body}")
+        self.status = status
+        self.body = body
+
+
+def _path(value: Any) -> str:
+    return quote(str(value), safe="")
+
+
+def _query_value(value: Any) -> Any:
+    return str(value).lower() if isinstance(value, bool) else value
+
+
+class Client:
+    def __init__(
+        self,
+        base_url: str = "BASE_URL",
+        headers: dict[str, str] | None = None,
+        timeout: float = 30.0,
+    ):
+        self.base_url = base_url.rstrip("/")
+        self.headers = dict(headers or {})
+        self.timeout = timeout
+
+    def _request(
+        self,
+        method: str,
+        path: str,
+        query: dict[str, Any] | None = None,
+        body: Any = None,
+    ) -> Any:
+        url = self.base_url + path
+        params = [
+            (key, _query_value(item))
+            for key, value in (query or {}).items()
+            if value is not None
+            for item in (value if isinstance(value, list) else [value])
+        ]
+        if params:
+            url += "?" + urlencode(params)
+        headers = {"Accept": "application/json", **self.headers}
+        data = None
+        if body is not None:
+            data = json.dumps(body).encode()
+            headers["Content-Type"] = "application/json"
+        request = Request(url, data=data, headers=headers, method=method)
+        try:
+            with urlopen(request, timeout=self.timeout) as response:
+                content = response.read()
+        except HTTPError as e:
+            raw = e.read().decode(errors="replace")
+            try:
+                detail = json.loads(raw)
+            except ValueError:
+                detail = raw
+            raise ApiError(e.code, detail) from None
+        return json.loads(content) if content else None
+"#
+        .replace("BASE_URL", base_url)
+        .as_str(),
+    );
+
+    let mut names = BTreeSet::new();
+    for call in calls {
+        let name = unique(&mut names, snake(&call.name));
+        let mut args = vec!["self".to_string()];
+        let mut url = call.operation.path.to_string();
+        for (param, schema) in &call.path_params {
+            let arg = snake(param);
+            url = url.replace(&format!("{{{}}}", param), &format!("{{_path({})}}", arg));
+            args.push(format!("{}: {}", arg, py_type(schema, "models.")));
+        }
+        if let Some((schema, required)) = call.body {
+            let t = py_type(schema, "models.");
+            args.push(if required {
+                format!("body: {}", t)
+            } else {
+                format!("body: {} | None = None", t)
+            });
+        }
+        let mut query = Vec::new();
+        if !call.query.is_empty() {
+            args.push("*".to_string());
+            // Required keyword arguments first, for readability
+            let mut params: Vec<&(&str, &Value, bool)> = call.query.iter().collect();
+            params.sort_by_key(|(_, _, required)| !required);
+            for (param, schema, required) in params {
+                let arg = snake(param);
+                let t = py_type(schema, "models.");
+                args.push(if *required {
+                    format!("{}: {}", arg, t)
+                } else {
+                    format!("{}: {} | None = None", arg, t)
+                });
+                query.push(format!("{}: {}", Value::from(*param), arg));
+            }
+        }
+        let response = call
+            .response
+            .map(|schema| py_type(schema, "models."))
+            .unwrap_or_else(|| "Any".to_string());
+        let path = if call.path_params.is_empty() {
+            Value::from(url).to_string()
+        } else {
+            format!("f{}", Value::from(url))
+        };
+        let mut request_args = vec![
+            Value::from(call.operation.method.to_uppercase()).to_string(),
+            path,
+        ];
+        if !query.is_empty() {
+            request_args.push(format!("query={{{}}}", query.join(", ")));
+        }
+        if call.body.is_some() {
+            request_args.push("body=body".to_string());
+        }
+        let summary = match call.operation.op["summary"].as_str() {
+            Some(summary) => format!("{}: {}", call.operation.label(), summary),
+            None => call.operation.label(),
+        };
+        out.push_str(&format!(
+            "\n    def {}({}) -> {}:\n        {}\n        return self._request({})\n",
+            name,
+            args.join(", "),
+            response,
+            py_docstring(&summary),
+            request_args.join(", ")
+        ));
+    }
+    out
+}
+
+fn python(
+    spec: &Value,
+    calls: &[Call],
+    slug: &str,
+    version: &str,
+    title: &str,
+    base_url: &str,
+) -> Vec<(String, String)> {
+    let package = format!("{}_client", slug.replace('-', "_"));
+    let pyproject = format!(
+        "[build-system]\nrequires = [\"setuptools>=61\"]\nbuild-backend = \"setuptools.build_meta\"\n\n\
+         [project]\nname = \"{}-client\"\nversion = \"{}\"\ndescription = {}\nrequires-python = \">=3.11\"\n\
+         dependencies = []\n",
+        slug,
+        version,
+        Value::from(format!("Python client for {}", title)),
+    );
+    vec![
+        ("pyproject.toml".to_string(), pyproject),
+        (
+            format!("{}/__init__.py", package),
+            format!(
+                "{}from . import models\nfrom .client import ApiError, Client\n\n__all__ = [\"ApiError\", \"Client\", \"models\"]\n",
+                PY_HEADER
+            ),
+        ),
+        (format!("{}/models.py", package), python_models(spec)),
+        (
+            format!("{}/client.py", package),
+            python_client(calls, base_url),
+        ),
+        (format!("{}/py.typed", package), String::new()),
+    ]
+}
+
+#[tauri::command]
+pub async fn generate_sdk(
+    project_path: String,
+    language: String,
+    // A directory; defaults to sdk/<language> in the project
+    dest: Option<String>,
+) -> Result<SdkReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let language = match language.to_lowercase().as_str() {
+            "python" | "py" => "python",
+            "typescript" | "ts" => "typescript",
+            _ => {
+                return Err(Error::invalid(
+                    "unsupported_language",
+                    format!("Unsupported SDK language: {}", language),
+                )
+                .with("language", &language))
+            }
+        };
+
+        let (spec_source, spec) = openapi::current_spec(root)?;
+        let base_url =
+            openapi::backend_url(root).unwrap_or_else(|_| DEFAULT_BACKEND_URL.to_string());
+        let title = spec["info"]["title"]
+            .as_str()
+            .filter(|t| !t.trim().is_empty())
+            .map(String::from)
+            .unwrap_or_else(|| {
+                root.file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "API".to_string())
+            });
+        let version = spec["info"]["version"].as_str().unwrap_or("0.1.0");
+        let slug = scaffold::package_slug(&title);
+
+        let mut warnings = Vec::new();
+        let calls = calls(&spec, &mut warnings);
+        let files = match language {
+            "python" => python(&spec, &calls, &slug, version, &title, &base_url),
+            _ => typescript(&spec, &calls, &slug, version, &base_url),
+        };
+
+        let dir = match dest {
+            Some(dest) => PathBuf::from(paths::new_path(&dest)?),
+            None => root.join(SDK_DIR).join(language),
+        };
+        for (file, content) in &files {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, content)?;
+        }
+
+        tracing::info!(%project_path, language, operations = calls.len(), "generated SDK");
+        Ok(SdkReport {
+            language,
+            path: dir.to_string_lossy().into_owned(),
+            files: files.into_iter().map(|(file, _)| file).collect(),
+            operations: calls.len(),
+            schemas: spec["components"]["schemas"]
+                .as_object()
+                .map_or(0, |schemas| schemas.len()),
+            spec_source,
+            warnings,
+        })
+    })
+    .await
+}
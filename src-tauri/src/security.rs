@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+use tauri::AppHandle;
+
+use crate::deps;
+use crate::error::Error;
+use crate::exec;
+use crate::node::{self, PackageManager};
+use crate::paths;
+use crate::projects;
+use crate::python;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct Vulnerability {
+    service: &'static str,
+    ecosystem: &'static str,
+    package: String,
+    installed_version: Option<String>,
+    severity: String,
+    advisory: String,
+    title: Option<String>,
+    url: Option<String>,
+    fix_version: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct AuditReport {
+    vulnerabilities: Vec<Vulnerability>,
+    severity_counts: BTreeMap<String, u32>,
+    fixes_applied: bool,
+    errors: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn audit_dependencies(
+    app: AppHandle,
+    project_path: String,
+    fix: Option<bool>,
+) -> Result<AuditReport, Error> {
+    task::blocking(move || {
+        let fix = fix.unwrap_or(false);
+        // Fixing rewrites manifests and lockfiles, so only inside a registered project
+        let project_path = if fix {
+            projects::project_dir(&app, &project_path)?
+        } else {
+            paths::existing_dir(&project_path)?
+        };
+        let path = Path::new(&project_path);
+
+        let (frontend, backend) = deps::service_dirs(path);
+        let mut vulnerabilities = Vec::new();
+        let mut errors = Vec::new();
+
+        if let Some(frontend) = frontend {
+            let result = if fix { node_fix(&frontend) } else { Ok(()) };
+            match result.and_then(|_| node_audit(&frontend)) {
+                Ok(found) => vulnerabilities.extend(found),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if let Some(backend) = backend {
+            match python_audit(&backend, fix) {
+                Ok(found) => vulnerabilities.extend(found),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        let mut severity_counts = BTreeMap::new();
+        for v in &vulnerabilities {
+            *severity_counts.entry(v.severity.clone()).or_insert(0) += 1;
+        }
+
+        Ok(AuditReport {
+            vulnerabilities,
+            severity_counts,
+            fixes_applied: fix,
+            errors,
+        })
+    })
+    .await
+}
+
+fn audit_manager(path: &Path) -> PackageManager {
+    match node::detect_package_manager(path) {
+        PackageManager::Pnpm => PackageManager::Pnpm,
+        _ => PackageManager::Npm,
+    }
+}
+
+fn node_fix(path: &Path) -> Result<(), String> {
+    let pm = audit_manager(path);
+    let mut cmd = node::manager_command(path, pm).map_err(|e| e.to_string())?;
+    cmd.current_dir(path);
+    if pm == PackageManager::Pnpm {
+        cmd.args(["audit", "--fix"]);
+    } else {
+        cmd.args(["audit", "fix"]);
+    }
+
+    let output = exec::output(&mut cmd)
+        .map_err(|e| format!("Failed to run {} audit fix: {}", pm.program(), e))?;
+    if !output.status.success() && output.stdout.is_empty() {
+        return Err(format!(
+            "{} audit fix failed: {}",
+            pm.program(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+fn node_audit(path: &Path) -> Result<Vec<Vulnerability>, String> {
+    let pm = audit_manager(path);
+    let mut cmd = node::manager_command(path, pm).map_err(|e| e.to_string())?;
+    cmd.args(["audit", "--json"]).current_dir(path);
+
+    // audit exits non-zero whenever it finds something
+    let output = exec::output(&mut cmd)
+        .map_err(|e| format!("Failed to run {} audit: {}", pm.program(), e))?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|_| {
+        format!(
+            "{} audit failed: {}",
+            pm.program(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+    })?;
+
+    let mut found = Vec::new();
+
+    // npm 7+ report: vulnerabilities keyed by package, advisories inlined in `via`
+    if let Some(entries) = json["vulnerabilities"].as_object() {
+        for (package, entry) in entries {
+            let fix_version = match &entry["fixAvailable"] {
+                serde_json::Value::Object(fix) if fix["name"] == package.as_str() => {
+                    fix["version"].as_str().map(String::from)
+                }
+                _ => None,
+            };
+
+            for via in entry["via"].as_array().into_iter().flatten() {
+                if !via.is_object() {
+                    continue;
+                }
+                found.push(Vulnerability {
+                    service: "frontend",
+                    ecosystem: "npm",
+                    package: package.clone(),
+                    installed_version: deps::installed_node_version(path, package),
+                    severity: via["severity"].as_str().unwrap_or("unknown").to_string(),
+                    advisory: via["url"]
+                        .as_str()
+                        .and_then(|u| u.rsplit('/').next())
+                        .map(String::from)
+                        .unwrap_or_else(|| via["source"].to_string()),
+                    title: via["title"].as_str().map(String::from),
+                    url: via["url"].as_str().map(String::from),
+                    fix_version: fix_version.clone(),
+                });
+            }
+        }
+    }
+
+    // pnpm (and npm 6) report: flat advisories map
+    if let Some(advisories) = json["advisories"].as_object() {
+        for (id, advisory) in advisories {
+            let package = advisory["module_name"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            found.push(Vulnerability {
+                service: "frontend",
+                ecosystem: "npm",
+                installed_version: advisory["findings"][0]["version"]
+                    .as_str()
+                    .map(String::from),
+                package,
+                severity: advisory["severity"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                advisory: advisory["github_advisory_id"]
+                    .as_str()
+                    .map(String::from)
+                    .unwrap_or_else(|| id.clone()),
+                title: advisory["title"].as_str().map(String::from),
+                url: advisory["url"].as_str().map(String::from),
+                fix_version: advisory["patched_versions"].as_str().map(String::from),
+            });
+        }
+    }
+
+    Ok(found)
+}
+
+fn python_audit(path: &Path, fix: bool) -> Result<Vec<Vulnerability>, String> {
+    let python = python::venv_python(path)
+        .ok_or_else(|| "Backend has no virtual environment".to_string())?;
+
+    let mut cmd = Command::new(python);
+    cmd.args(["-m", "pip_audit", "-f", "json", "--progress-spinner", "off"])
+        .current_dir(path);
+    if fix {
+        cmd.arg("--fix");
+    }
+
+    let output = exec::output(&mut cmd).map_err(|e| format!("Failed to run pip-audit: {}", e))?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("No module named pip_audit") {
+        return Err("pip-audit is not installed in the backend environment; add it with `pip install pip-audit`".to_string());
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|_| format!("pip-audit failed: {}", stderr.trim()))?;
+
+    let mut found = Vec::new();
+    for dep in json["dependencies"].as_array().into_iter().flatten() {
+        for vuln in dep["vulns"].as_array().into_iter().flatten() {
+            found.push(Vulnerability {
+                service: "backend",
+                ecosystem: "pypi",
+                package: dep["name"].as_str().unwrap_or_default().to_string(),
+                installed_version: dep["version"].as_str().map(String::from),
+                // pip-audit's JSON carries no severity rating
+                severity: "unknown".to_string(),
+                advisory: vuln["id"].as_str().unwrap_or_default().to_string(),
+                title: vuln["description"].as_str().map(String::from),
+                url: None,
+                fix_version: vuln["fix_versions"][0].as_str().map(String::from),
+            });
+        }
+    }
+
+    Ok(found)
+}
@@ -0,0 +1,378 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+use rusqlite::types::Value;
+use rusqlite::Connection;
+
+use crate::db::{self, ColumnInfo, TableSchema};
+use crate::error::{Error, ErrorKind};
+use crate::projects;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct SeedReport {
+    table: String,
+    inserted: usize,
+    columns: Vec<String>,
+}
+
+const MAX_SEED_ROWS: usize = 10_000;
+
+const FIRST_NAMES: [&str; 16] = [
+    "Ada",
+    "Grace",
+    "Alan",
+    "Linus",
+    "Margaret",
+    "Dennis",
+    "Barbara",
+    "Ken",
+    "Frances",
+    "Guido",
+    "Radia",
+    "Tim",
+    "Hedy",
+    "Bjarne",
+    "Katherine",
+    "Edsger",
+];
+const LAST_NAMES: [&str; 16] = [
+    "Lovelace",
+    "Hopper",
+    "Turing",
+    "Torvalds",
+    "Hamilton",
+    "Ritchie",
+    "Liskov",
+    "Thompson",
+    "Allen",
+    "Rossum",
+    "Perlman",
+    "Berners-Lee",
+    "Lamarr",
+    "Stroustrup",
+    "Johnson",
+    "Dijkstra",
+];
+const WORDS: [&str; 24] = [
+    "alpha", "bright", "cloud", "delta", "ember", "forest", "garden", "harbor", "island", "jasper",
+    "kettle", "lantern", "meadow", "nimbus", "orchid", "pepper", "quartz", "river", "summit",
+    "timber", "umber", "velvet", "willow", "zephyr",
+];
+const CITIES: [&str; 8] = [
+    "Lisbon",
+    "Osaka",
+    "Toronto",
+    "Nairobi",
+    "Berlin",
+    "Austin",
+    "Melbourne",
+    "Bogotá",
+];
+const COUNTRIES: [&str; 8] = [
+    "Portugal",
+    "Japan",
+    "Canada",
+    "Kenya",
+    "Germany",
+    "United States",
+    "Australia",
+    "Colombia",
+];
+
+// xorshift64*: plenty for demo data, no extra dependency
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x2545F4914F6CDD1D);
+        Rng(seed | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n.max(1)
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len() as u64) as usize]
+    }
+
+    fn sentence(&mut self, words: usize) -> String {
+        let mut text: Vec<&str> = (0..words).map(|_| self.pick(&WORDS)).collect();
+        let first = text[0].to_string();
+        let capitalized = first[..1].to_uppercase() + &first[1..];
+        text[0] = &capitalized;
+        format!("{}.", text.join(" "))
+    }
+}
+
+// Picks a value from the column name first, then falls back to its declared type
+fn fake_value(rng: &mut Rng, column: &ColumnInfo, row: usize) -> Value {
+    let name = column.name.to_lowercase();
+    let data_type = column.data_type.to_uppercase();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let first = rng.pick(&FIRST_NAMES);
+    let last = rng.pick(&LAST_NAMES);
+
+    let text = if name.contains("email") {
+        format!("{}.{}{}@example.com", first, last, row).to_lowercase()
+    } else if name == "first_name" {
+        first.to_string()
+    } else if name == "last_name" || name == "surname" {
+        last.to_string()
+    } else if name.contains("username") || name == "login" {
+        format!("{}{}", first.to_lowercase(), row)
+    } else if name.contains("name") || name == "author" {
+        format!("{} {}", first, last)
+    } else if name.contains("title") || name.contains("subject") {
+        let words = 2 + rng.below(3) as usize;
+        rng.sentence(words).trim_end_matches('.').to_string()
+    } else if name.contains("description")
+        || name.contains("content")
+        || name.contains("body")
+        || name.contains("text")
+        || name.contains("note")
+        || name.contains("bio")
+    {
+        let words = 8 + rng.below(12) as usize;
+        rng.sentence(words)
+    } else if name.contains("url") || name.contains("website") {
+        format!("https://example.com/{}/{}", rng.pick(&WORDS), row)
+    } else if name.contains("phone") {
+        format!("+1-555-{:03}-{:04}", rng.below(1000), rng.below(10_000))
+    } else if name.contains("city") {
+        rng.pick(&CITIES).to_string()
+    } else if name.contains("country") {
+        rng.pick(&COUNTRIES).to_string()
+    } else if name.contains("status") {
+        rng.pick(&["active", "pending", "archived"]).to_string()
+    } else if name.ends_with("_at")
+        || name.ends_with("_on")
+        || name.contains("date")
+        || data_type.contains("DATE")
+        || data_type.contains("TIME")
+    {
+        // Spread over the last year so sorting and date filters have something to show
+        let timestamp = db::format_timestamp(now.saturating_sub(rng.below(365 * 86_400)));
+        if data_type == "DATE" {
+            timestamp[..10].to_string()
+        } else {
+            timestamp
+        }
+    } else if name.starts_with("is_") || name.starts_with("has_") || data_type.contains("BOOL") {
+        return Value::Integer(rng.below(2) as i64);
+    } else if name.contains("price")
+        || name.contains("amount")
+        || name.contains("total")
+        || data_type.contains("REAL")
+        || data_type.contains("FLOAT")
+        || data_type.contains("DOUBLE")
+        || data_type.contains("NUMERIC")
+        || data_type.contains("DECIMAL")
+    {
+        return Value::Real((rng.below(100_000) as f64) / 100.0);
+    } else if data_type.contains("INT") {
+        return Value::Integer(if name.contains("age") {
+            18 + rng.below(60) as i64
+        } else {
+            rng.below(1_000) as i64
+        });
+    } else if data_type.contains("BLOB") {
+        return Value::Null;
+    } else {
+        format!("{} {}", rng.pick(&WORDS), row)
+    };
+
+    // VARCHAR(n) limits aren't enforced by SQLite but are by the ORM on the way back
+    let limit = data_type.split_once('(').and_then(|(_, rest)| {
+        rest.trim_end_matches(')')
+            .split(',')
+            .next()?
+            .trim()
+            .parse::<usize>()
+            .ok()
+    });
+    Value::Text(match limit {
+        Some(limit) => text.chars().take(limit).collect(),
+        None => text,
+    })
+}
+
+fn unique_columns(schema: &TableSchema) -> HashSet<String> {
+    schema
+        .indexes
+        .iter()
+        .filter(|i| i.unique && i.columns.len() == 1)
+        .map(|i| i.columns[0].clone())
+        .collect()
+}
+
+fn referenced_ids(conn: &Connection, table: &str, column: &str) -> Result<Vec<Value>, String> {
+    let sql = format!(
+        "SELECT {} FROM {} LIMIT 1000",
+        db::quote_ident(column),
+        db::quote_ident(table)
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let ids = stmt
+        .query_map([], |row| row.get::<_, Value>(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(ids)
+}
+
+pub fn seed_database_blocking(
+    project_path: String,
+    table: String,
+    count: usize,
+) -> Result<SeedReport, Error> {
+    if count == 0 || count > MAX_SEED_ROWS {
+        return Err(Error::invalid(
+            "count_out_of_range",
+            format!("count must be between 1 and {}", MAX_SEED_ROWS),
+        )
+        .with("max", MAX_SEED_ROWS));
+    }
+
+    let db_path = db::project_sqlite(Path::new(&project_path))?;
+    if !db_path.exists() {
+        return Err(Error::not_found(
+            "database_missing",
+            format!(
+                "Database does not exist yet: {}; start the backend once to create it",
+                db_path.display()
+            ),
+        ));
+    }
+    let mut conn = Connection::open(&db_path)?;
+    let schema = db::table_schema(&conn, &table)?;
+    let unique = unique_columns(&schema);
+
+    // A single INTEGER PRIMARY KEY is SQLite's rowid alias and fills itself in
+    let pk_count = schema.columns.iter().filter(|c| c.primary_key).count();
+    let columns: Vec<&ColumnInfo> = schema
+        .columns
+        .iter()
+        .filter(|c| {
+            !(c.primary_key && pk_count == 1 && c.data_type.eq_ignore_ascii_case("INTEGER"))
+        })
+        .collect();
+    if columns.is_empty() {
+        return Err(Error::invalid(
+            "nothing_to_seed",
+            format!("{} has no columns to seed", table),
+        )
+        .with("table", &table));
+    }
+
+    let mut foreign_ids = Vec::new();
+    for column in &columns {
+        let fk = schema
+            .foreign_keys
+            .iter()
+            .find(|fk| fk.column == column.name);
+        let ids = match fk {
+            Some(fk) => {
+                let target = fk.references_column.as_deref().unwrap_or("id");
+                let ids = referenced_ids(&conn, &fk.references_table, target)?;
+                if ids.is_empty() && !column.nullable {
+                    return Err(Error::new(
+                        ErrorKind::Conflict,
+                        "empty_reference",
+                        format!(
+                            "{}.{} references {}, which has no rows; seed {} first",
+                            table, column.name, fk.references_table, fk.references_table
+                        ),
+                    )
+                    .with("table", &fk.references_table));
+                }
+                Some(ids)
+            }
+            None => None,
+        };
+        foreign_ids.push(ids);
+    }
+
+    let sql = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        db::quote_ident(&table),
+        columns
+            .iter()
+            .map(|c| db::quote_ident(&c.name))
+            .collect::<Vec<_>>()
+            .join(", "),
+        vec!["?"; columns.len()].join(", ")
+    );
+
+    let offset: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM {}", db::quote_ident(&table)),
+        [],
+        |row| row.get(0),
+    )?;
+
+    let mut rng = Rng::new();
+    let tx = conn.transaction()?;
+    {
+        let mut stmt = tx.prepare(&sql)?;
+        for i in 0..count {
+            let row = offset as usize + i + 1;
+            let values: Vec<Value> = columns
+                .iter()
+                .zip(&foreign_ids)
+                .map(|(column, ids)| match ids {
+                    Some(ids) if ids.is_empty() => Value::Null,
+                    Some(ids) => ids[rng.below(ids.len() as u64) as usize].clone(),
+                    None => match fake_value(&mut rng, column, row) {
+                        // Suffix the row number so unique columns never collide
+                        Value::Text(text)
+                            if unique.contains(&column.name)
+                                && !text.contains(&row.to_string()) =>
+                        {
+                            Value::Text(format!("{}-{}", text, row))
+                        }
+                        value => value,
+                    },
+                })
+                .collect();
+            stmt.execute(rusqlite::params_from_iter(values))
+                .map_err(|e| format!("Insert failed on row {}: {}", i + 1, e))?;
+        }
+    }
+    tx.commit()?;
+
+    Ok(SeedReport {
+        table,
+        inserted: count,
+        columns: columns.iter().map(|c| c.name.clone()).collect(),
+    })
+}
+
+#[tauri::command]
+pub async fn seed_database(
+    app: AppHandle,
+    project_path: String,
+    table: String,
+    count: usize,
+) -> Result<SeedReport, Error> {
+    task::blocking(move || {
+        let project_path = projects::project_dir(&app, &project_path)?;
+        seed_database_blocking(project_path, table, count)
+    })
+    .await
+}
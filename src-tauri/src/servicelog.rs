@@ -0,0 +1,131 @@
+use std::io::Read;
+use std::path::Path;
+use std::thread;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::error::Error;
+use crate::exec;
+use crate::logbuf::LogBuffer;
+use crate::paths;
+use crate::task;
+use crate::ProcessManager;
+
+pub const SERVICE_LOG_EVENT: &str = "service-log";
+// Output kept in memory per service for the log panel; older lines go to a spill file
+// under the app log directory
+const SERVICE_LOG_BYTES: usize = 1024 * 1024;
+
+#[derive(Clone, serde::Serialize)]
+struct ServiceLogLine<'a> {
+    project_path: &'a str,
+    service_type: &'a str,
+    stream: &'static str,
+    line: &'a str,
+}
+
+// Sends one line of service output to the log panel and keeps it in the service's buffer
+pub fn push(
+    app: &AppHandle,
+    key: &str,
+    project_path: &str,
+    service_type: &str,
+    stream: &'static str,
+    line: String,
+) {
+    let _ = app.emit(
+        SERVICE_LOG_EVENT,
+        ServiceLogLine {
+            project_path,
+            service_type,
+            stream,
+            line: &line,
+        },
+    );
+    let state = app.state::<ProcessManager>();
+    state
+        .logs
+        .lock()
+        .entry(key.to_string())
+        .or_insert_with(|| {
+            let name = Path::new(project_path)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let spill = exec::spill_path(app, &format!("{}-{}", name, service_type));
+            LogBuffer::new(SERVICE_LOG_BYTES, spill)
+        })
+        .push(line);
+}
+
+// A restarted service starts with an empty panel and a new spill file; dropping the
+// old buffer flushes and closes its spill file
+pub fn reset(app: &AppHandle, key: &str) {
+    app.state::<ProcessManager>().logs.lock().remove(key);
+}
+
+// A follow() callback pushing every line under one service
+pub fn to_panel(
+    key: &str,
+    project_path: &str,
+    service_type: &str,
+) -> impl Fn(&AppHandle, &'static str, String) + Send + 'static {
+    let (key, project_path, service_type) = (
+        key.to_string(),
+        project_path.to_string(),
+        service_type.to_string(),
+    );
+    move |app, stream, line| push(app, &key, &project_path, &service_type, stream, line)
+}
+
+// Reads a service's output stream on its own thread until it closes
+pub fn follow(
+    app: &AppHandle,
+    reader: impl Read + Send + 'static,
+    stream: &'static str,
+    on_line: impl Fn(&AppHandle, &'static str, String) + Send + 'static,
+) {
+    let app = app.clone();
+    thread::spawn(move || {
+        exec::read_lines(reader, |line| {
+            on_line(&app, stream, line);
+            true
+        })
+    });
+}
+
+// Keyed like processes, so "compose" and "mock" work as service types too
+fn key(project_path: &str, service_type: &str) -> Result<String, Error> {
+    let project_path = paths::existing_dir(project_path)?;
+    Ok(format!("{}:{}", paths::key(&project_path), service_type))
+}
+
+#[tauri::command]
+pub async fn get_service_logs(
+    app: AppHandle,
+    project_path: String,
+    service_type: String,
+) -> Result<Vec<String>, Error> {
+    task::blocking(move || {
+        let key = key(&project_path, &service_type)?;
+        let state = app.state::<ProcessManager>();
+        let logs = state.logs.lock();
+        Ok(logs.get(&key).map(LogBuffer::lines).unwrap_or_default())
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn clear_service_logs(
+    app: AppHandle,
+    project_path: String,
+    service_type: String,
+) -> Result<(), Error> {
+    task::blocking(move || {
+        let key = key(&project_path, &service_type)?;
+        if let Some(logs) = app.state::<ProcessManager>().logs.lock().get_mut(&key) {
+            logs.clear();
+        }
+        Ok(())
+    })
+    .await
+}
@@ -0,0 +1,244 @@
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use tauri::{AppHandle, Manager};
+
+use crate::error::Error;
+use crate::logging::{self, LogState};
+use crate::shortcuts;
+use crate::task;
+use crate::telemetry;
+use crate::versioned::{self, Migration};
+
+const SETTINGS_FILE: &str = "settings.json";
+// Append a step here whenever the stored format changes
+const SETTINGS_MIGRATIONS: &[Migration] = &[versioned::initial];
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // None means "detect from the project's lockfile"
+    pub package_manager: Option<String>,
+    pub editor: Option<String>,
+    pub shell: Option<String>,
+    pub llm_provider: Option<String>,
+    pub llm_model: Option<String>,
+    pub log_level: String,
+    pub log_retention_days: u32,
+    pub notifications: NotificationSettings,
+    pub shortcuts: ShortcutSettings,
+    pub telemetry: TelemetrySettings,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    pub service_crashed: bool,
+    pub install_finished: bool,
+    pub service_healthy: bool,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        NotificationSettings {
+            service_crashed: true,
+            install_finished: true,
+            service_healthy: true,
+        }
+    }
+}
+
+// Accelerators such as "CmdOrCtrl+Alt+R"; an empty string disables the shortcut
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ShortcutSettings {
+    pub restart_backend: String,
+    pub stop_all: String,
+}
+
+impl Default for ShortcutSettings {
+    fn default() -> Self {
+        ShortcutSettings {
+            restart_backend: "CmdOrCtrl+Alt+R".to_string(),
+            stop_all: "CmdOrCtrl+Alt+S".to_string(),
+        }
+    }
+}
+
+// Both off until the user opts in; uploading also needs recording
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct TelemetrySettings {
+    pub record: bool,
+    pub upload: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            package_manager: None,
+            editor: None,
+            shell: None,
+            llm_provider: None,
+            llm_model: None,
+            log_level: "info".to_string(),
+            log_retention_days: 14,
+            notifications: NotificationSettings::default(),
+            shortcuts: ShortcutSettings::default(),
+            telemetry: TelemetrySettings::default(),
+        }
+    }
+}
+
+impl Settings {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(pm) = &self.package_manager {
+            if !["npm", "pnpm", "yarn", "bun"].contains(&pm.as_str()) {
+                return Err(format!("Unknown package manager: {}", pm));
+            }
+        }
+        if !logging::LEVELS.contains(&self.log_level.as_str()) {
+            return Err(format!("Unknown log level: {}", self.log_level));
+        }
+        if self.log_retention_days == 0 {
+            return Err("log_retention_days must be at least 1".to_string());
+        }
+        if self.telemetry.upload && !self.telemetry.record {
+            return Err("Telemetry upload requires telemetry recording".to_string());
+        }
+        shortcuts::validate(&self.shortcuts)
+    }
+}
+
+pub struct SettingsState {
+    path: Option<PathBuf>,
+    // Read on first use rather than while the window is coming up
+    settings: OnceLock<Mutex<Settings>>,
+}
+
+impl SettingsState {
+    pub fn new(app: &AppHandle) -> Self {
+        let path = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join(SETTINGS_FILE));
+        SettingsState {
+            path,
+            settings: OnceLock::new(),
+        }
+    }
+
+    // A missing or unreadable file falls back to defaults rather than failing the caller
+    fn loaded(&self) -> &Mutex<Settings> {
+        self.settings.get_or_init(|| {
+            let settings = match &self.path {
+                Some(p) => match versioned::load(p, SETTINGS_MIGRATIONS, "settings") {
+                    Ok(settings) => settings.unwrap_or_default(),
+                    Err(e) => {
+                        tracing::warn!("Ignoring settings: {}", e);
+                        // A newer build's file stays put; save refuses to overwrite it
+                        if versioned::written_by_newer(p, SETTINGS_MIGRATIONS).is_none() {
+                            versioned::preserve_unreadable(p);
+                        }
+                        Settings::default()
+                    }
+                },
+                None => Settings::default(),
+            };
+            Mutex::new(settings)
+        })
+    }
+
+    pub fn get(&self) -> Settings {
+        self.loaded().lock().clone()
+    }
+
+    // Picks up a settings file replaced on disk, e.g. by an import
+    pub fn reload(&self, app: &AppHandle, logs: &LogState) -> Result<Settings, String> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or_else(|| "App data directory is unavailable".to_string())?;
+        let loaded: Settings =
+            versioned::load(path, SETTINGS_MIGRATIONS, "settings")?.unwrap_or_default();
+        loaded.validate()?;
+
+        let mut settings = self.loaded().lock();
+        if loaded.shortcuts != settings.shortcuts {
+            shortcuts::apply(app, &loaded.shortcuts)?;
+        }
+        logs.set_level(&loaded.log_level)?;
+        logs.prune(loaded.log_retention_days);
+        telemetry::set_recording(loaded.telemetry.record);
+        *settings = loaded.clone();
+        Ok(loaded)
+    }
+
+    fn save(&self, settings: &Settings) -> Result<(), String> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or_else(|| "App data directory is unavailable".to_string())?;
+        versioned::save(path, settings, SETTINGS_MIGRATIONS, "settings")
+    }
+}
+
+#[tauri::command]
+pub async fn get_settings(app: AppHandle) -> Result<Settings, Error> {
+    task::blocking(move || Ok(app.state::<SettingsState>().get())).await
+}
+
+// Merges the given keys into the current settings; null resets a key to its default
+#[tauri::command]
+pub async fn update_settings(
+    app: AppHandle,
+    changes: serde_json::Value,
+) -> Result<Settings, Error> {
+    task::blocking(move || {
+        let state = app.state::<SettingsState>();
+        let logs = app.state::<LogState>();
+        let changes = changes.as_object().ok_or_else(|| {
+            Error::invalid("invalid_settings", "Settings changes must be an object")
+        })?;
+
+        let mut settings = state.loaded().lock();
+        let mut merged = serde_json::to_value(&*settings)?;
+        let defaults = serde_json::to_value(Settings::default())?;
+        for (key, value) in changes {
+            if merged.get(key).is_none() {
+                return Err(
+                    Error::invalid("unknown_setting", format!("Unknown setting: {}", key))
+                        .with("key", key),
+                );
+            }
+            let value = if value.is_null() {
+                defaults[key].clone()
+            } else {
+                value.clone()
+            };
+            merged[key] = value;
+        }
+
+        let updated: Settings = serde_json::from_value(merged)?;
+        updated.validate()?;
+        if updated.shortcuts != settings.shortcuts {
+            // Put the old shortcuts back if the OS refuses one of the new ones
+            if let Err(e) = shortcuts::apply(&app, &updated.shortcuts) {
+                let _ = shortcuts::apply(&app, &settings.shortcuts);
+                return Err(e.into());
+            }
+        }
+        state.save(&updated)?;
+        telemetry::set_recording(updated.telemetry.record);
+        if updated.log_level != settings.log_level {
+            logs.set_level(&updated.log_level)?;
+        }
+        if updated.log_retention_days != settings.log_retention_days {
+            logs.prune(updated.log_retention_days);
+        }
+        *settings = updated.clone();
+        Ok(updated)
+    })
+    .await
+}
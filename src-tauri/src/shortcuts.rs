@@ -0,0 +1,116 @@
+use parking_lot::Mutex;
+use std::path::Path;
+use std::str::FromStr;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::error::Error;
+use crate::paths;
+use crate::settings::ShortcutSettings;
+use crate::task;
+use crate::tray;
+use crate::watch;
+use crate::ProcessManager;
+
+// The project shown in the window, which "restart backend" acts on
+#[derive(Default)]
+pub struct ActiveProject(Mutex<Option<String>>);
+
+#[derive(Clone, Copy)]
+enum Action {
+    RestartBackend,
+    StopAll,
+}
+
+fn bindings(shortcuts: &ShortcutSettings) -> Vec<(Action, &str)> {
+    [
+        (Action::RestartBackend, shortcuts.restart_backend.as_str()),
+        (Action::StopAll, shortcuts.stop_all.as_str()),
+    ]
+    .into_iter()
+    // An empty accelerator turns the shortcut off
+    .filter(|(_, accelerator)| !accelerator.trim().is_empty())
+    .collect()
+}
+
+pub fn validate(shortcuts: &ShortcutSettings) -> Result<(), String> {
+    let mut seen: Vec<Shortcut> = Vec::new();
+    for (_, accelerator) in bindings(shortcuts) {
+        let shortcut = Shortcut::from_str(accelerator)
+            .map_err(|e| format!("Invalid shortcut {}: {}", accelerator, e))?;
+        if seen.contains(&shortcut) {
+            return Err(format!("Shortcut {} is assigned twice", accelerator));
+        }
+        seen.push(shortcut);
+    }
+    Ok(())
+}
+
+// Backend process key of the active project, or the only running backend when none is set
+fn backend_key(app: &AppHandle) -> Option<String> {
+    let active = app.state::<ActiveProject>().0.lock().clone();
+    let state = app.state::<ProcessManager>();
+    let launches = state.launches.lock();
+    let backends: Vec<&String> = launches
+        .iter()
+        .filter(|(_, launch)| launch.service_type == "backend")
+        .filter(|(_, launch)| {
+            active
+                .as_ref()
+                .is_none_or(|root| Path::new(&launch.project_path).starts_with(root))
+        })
+        .map(|(key, _)| key)
+        .collect();
+    match backends.as_slice() {
+        [key] => Some(key.to_string()),
+        _ => None,
+    }
+}
+
+fn run(app: &AppHandle, action: Action) {
+    match action {
+        Action::RestartBackend => match backend_key(app) {
+            Some(key) => {
+                tracing::info!(service = %key, "restarting backend from global shortcut");
+                tray::service_action(app, "restart", &key);
+            }
+            None => tracing::warn!("No backend to restart for the current project"),
+        },
+        Action::StopAll => {
+            tracing::info!("stopping all services from global shortcut");
+            tray::stop_all(&app.state::<ProcessManager>());
+        }
+    }
+    tray::refresh(app);
+}
+
+// Replaces whatever was registered before, so it is also used when settings change
+pub fn apply(app: &AppHandle, shortcuts: &ShortcutSettings) -> Result<(), String> {
+    let global = app.global_shortcut();
+    global.unregister_all().map_err(|e| e.to_string())?;
+    for (action, accelerator) in bindings(shortcuts) {
+        global
+            .on_shortcut(accelerator, move |app, _, event| {
+                if event.state == ShortcutState::Pressed {
+                    run(app, action);
+                }
+            })
+            // Usually another application already owns the combination
+            .map_err(|e| format!("Could not register {}: {}", accelerator, e))?;
+    }
+    Ok(())
+}
+
+// The active project is also the one whose detection data is pushed on file changes
+#[tauri::command]
+pub async fn set_active_project(app: AppHandle, project_path: Option<String>) -> Result<(), Error> {
+    task::blocking(move || {
+        let project_path = paths::optional_dir(project_path)?;
+        if let Err(e) = watch::watch(&app, project_path.clone()) {
+            tracing::warn!("{}", e);
+        }
+        *app.state::<ActiveProject>().0.lock() = project_path;
+        Ok(())
+    })
+    .await
+}
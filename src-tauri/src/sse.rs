@@ -0,0 +1,181 @@
+use std::io::{BufRead, BufReader, ErrorKind as IoErrorKind};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+use crate::error::Error;
+use crate::task;
+
+const SSE_EVENT: &str = "sse-event";
+const DEFAULT_MAX_EVENTS: usize = 20;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, serde::Serialize)]
+pub struct SseEvent {
+    // "message" when the server sent no event field, as in the browser's EventSource
+    event: String,
+    data: String,
+    id: Option<String>,
+    retry: Option<u64>,
+    // Since the response headers arrived
+    elapsed_ms: u64,
+}
+
+#[derive(serde::Serialize)]
+pub struct SseReport {
+    url: String,
+    status: u16,
+    content_type: Option<String>,
+    events: Vec<SseEvent>,
+    // "max_events", "timeout" or "closed" by the server
+    ended: &'static str,
+    duration_ms: u64,
+    // Why the stream doesn't look like SSE, e.g. a wrong content type
+    warnings: Vec<String>,
+}
+
+// One event under construction; a blank line dispatches it
+#[derive(Default)]
+struct Pending {
+    event: Option<String>,
+    data: Vec<String>,
+    id: Option<String>,
+    retry: Option<u64>,
+}
+
+impl Pending {
+    // Applies one line; comments (":") and unknown fields are ignored like EventSource does
+    fn line(&mut self, line: &str) {
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            "id" => self.id = Some(value.to_string()),
+            "retry" => self.retry = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    fn take(&mut self, elapsed: Duration) -> Option<SseEvent> {
+        let pending = std::mem::take(self);
+        if pending.data.is_empty() && pending.event.is_none() {
+            return None;
+        }
+        Some(SseEvent {
+            event: pending.event.unwrap_or_else(|| "message".to_string()),
+            data: pending.data.join("\n"),
+            id: pending.id,
+            retry: pending.retry,
+            elapsed_ms: elapsed.as_millis() as u64,
+        })
+    }
+}
+
+#[tauri::command]
+pub async fn test_sse(
+    app: AppHandle,
+    url: String,
+    max_events: Option<usize>,
+    timeout_secs: Option<u64>,
+) -> Result<SseReport, Error> {
+    task::blocking(move || {
+        let valid = reqwest::Url::parse(&url)
+            .is_ok_and(|parsed| matches!(parsed.scheme(), "http" | "https"));
+        if !valid {
+            return Err(
+                Error::invalid("invalid_url", format!("Invalid SSE URL: {}", url))
+                    .with("url", &url),
+            );
+        }
+        let max_events = max_events.unwrap_or(DEFAULT_MAX_EVENTS).max(1);
+        let timeout = timeout_secs
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_TIMEOUT);
+
+        let started = Instant::now();
+        // The client timeout covers the whole body, which bounds how long we listen
+        let response = reqwest::blocking::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?
+            .get(&url)
+            .header("Accept", "text/event-stream")
+            .header("Cache-Control", "no-cache")
+            .send()
+            .map_err(|e| {
+                Error::not_found("sse_unreachable", format!("Could not reach {}: {}", url, e))
+                    .with("url", &url)
+            })?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        let mut warnings = Vec::new();
+        if !response.status().is_success() {
+            warnings.push(format!("The server answered {}", response.status()));
+        }
+        if !content_type
+            .as_deref()
+            .is_some_and(|t| t.starts_with("text/event-stream"))
+        {
+            warnings.push(format!(
+                "Content-Type is {}, not text/event-stream",
+                content_type.as_deref().unwrap_or("missing")
+            ));
+        }
+
+        let headers_at = Instant::now();
+        let mut reader = BufReader::new(response);
+        let mut pending = Pending::default();
+        let mut events = Vec::new();
+        let mut line = String::new();
+        let ended = loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) => break "closed",
+                Ok(_) => {}
+                Err(e) if matches!(e.kind(), IoErrorKind::TimedOut | IoErrorKind::WouldBlock) => {
+                    break "timeout"
+                }
+                // reqwest reports its own deadline as an opaque error
+                Err(e) if started.elapsed() >= timeout => {
+                    tracing::debug!(%url, "SSE read ended at the deadline: {}", e);
+                    break "timeout";
+                }
+                Err(e) => {
+                    warnings.push(format!("Stream ended with an error: {}", e));
+                    break "closed";
+                }
+            }
+            let text = line.trim_end_matches(['\r', '\n']);
+            if !text.is_empty() {
+                pending.line(text);
+                continue;
+            }
+            if let Some(event) = pending.take(headers_at.elapsed()) {
+                let _ = app.emit(SSE_EVENT, &event);
+                events.push(event);
+                if events.len() >= max_events {
+                    break "max_events";
+                }
+            }
+        };
+
+        tracing::info!(%url, events = events.len(), ended, "tested SSE endpoint");
+        Ok(SseReport {
+            url,
+            status,
+            content_type,
+            events,
+            ended,
+            duration_ms: started.elapsed().as_millis() as u64,
+            warnings,
+        })
+    })
+    .await
+}
@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use crate::db::{self, TableSchema};
+use crate::error::Error;
+use crate::paths;
+use crate::task;
+
+#[derive(serde::Serialize)]
+pub struct TableStats {
+    name: String,
+    rows: i64,
+    // Large Postgres tables report the planner's estimate instead of COUNT(*)
+    approximate: bool,
+    size_bytes: Option<u64>,
+    last_modified: Option<String>,
+    last_modified_column: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct DataOverview {
+    driver: &'static str,
+    total_rows: i64,
+    size_bytes: Option<u64>,
+    file_modified: Option<String>,
+    tables: Vec<TableStats>,
+}
+
+// Exact counts are cheap below this; above it the estimate is close enough for a card
+const EXACT_COUNT_LIMIT: i64 = 100_000;
+
+// Neither database tracks per-table modification times, so use the usual audit columns
+fn timestamp_column(schema: &TableSchema) -> Option<String> {
+    ["updated_at", "modified_at", "created_at"]
+        .iter()
+        .find(|name| schema.columns.iter().any(|c| c.name == **name))
+        .map(|name| name.to_string())
+}
+
+fn sqlite_overview(db_path: &Path, schemas: &[TableSchema]) -> Result<DataOverview, Error> {
+    let conn = db::open_readonly(db_path)?;
+    let mut tables = Vec::new();
+    for schema in schemas {
+        let table = db::quote_ident(&schema.name);
+        let rows: i64 = conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+            row.get(0)
+        })?;
+        // dbstat covers the table and its indexes
+        let size_bytes = conn
+            .query_row(
+                "SELECT SUM(pgsize) FROM dbstat WHERE name = ?1 OR name IN (SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = ?1)",
+                [&schema.name],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .ok()
+            .flatten()
+            .map(|size| size as u64);
+        let column = timestamp_column(schema);
+        let last_modified = column.as_ref().and_then(|column| {
+            conn.query_row(
+                &format!("SELECT MAX({}) FROM {}", db::quote_ident(column), table),
+                [],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .ok()
+            .flatten()
+        });
+        tables.push(TableStats {
+            name: schema.name.clone(),
+            rows,
+            approximate: false,
+            size_bytes,
+            last_modified,
+            last_modified_column: column,
+        });
+    }
+
+    let meta = fs::metadata(db_path).ok();
+    let file_modified = meta
+        .as_ref()
+        .and_then(|m| m.modified().ok())
+        .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+        .map(|d| db::format_timestamp(d.as_secs()));
+    Ok(DataOverview {
+        driver: "sqlite",
+        total_rows: tables.iter().map(|t| t.rows).sum(),
+        size_bytes: meta.map(|m| m.len()),
+        file_modified,
+        tables,
+    })
+}
+
+fn postgres_overview(url: &str, schemas: &[TableSchema]) -> Result<DataOverview, Error> {
+    let mut client = db::pg_connect(url)?;
+    let mut tables = Vec::new();
+    for schema in schemas {
+        let table = db::quote_ident(&schema.name);
+        let row = client
+            .query_one(
+                "SELECT COALESCE(s.n_live_tup, 0), pg_total_relation_size(c.oid) FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace LEFT JOIN pg_stat_user_tables s ON s.relid = c.oid WHERE n.nspname = 'public' AND c.relname = $1",
+                &[&schema.name],
+            )?;
+        let estimate: i64 = row.get(0);
+        let size: i64 = row.get(1);
+
+        let approximate = estimate >= EXACT_COUNT_LIMIT;
+        let rows = if approximate {
+            estimate
+        } else {
+            client
+                .query_one(&format!("SELECT COUNT(*) FROM {}", table), &[])?
+                .get(0)
+        };
+        let column = timestamp_column(schema);
+        let last_modified = column.as_ref().and_then(|column| {
+            client
+                .query_one(
+                    &format!(
+                        "SELECT MAX({})::text FROM {}",
+                        db::quote_ident(column),
+                        table
+                    ),
+                    &[],
+                )
+                .ok()
+                .and_then(|row| row.get::<_, Option<String>>(0))
+        });
+        tables.push(TableStats {
+            name: schema.name.clone(),
+            rows,
+            approximate,
+            size_bytes: Some(size as u64),
+            last_modified,
+            last_modified_column: column,
+        });
+    }
+
+    let size: i64 = client
+        .query_one("SELECT pg_database_size(current_database())", &[])?
+        .get(0);
+    Ok(DataOverview {
+        driver: "postgres",
+        total_rows: tables.iter().map(|t| t.rows).sum(),
+        size_bytes: Some(size as u64),
+        file_modified: None,
+        tables,
+    })
+}
+
+#[tauri::command]
+pub async fn get_table_stats(project_path: String) -> Result<DataOverview, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let (backend, url) = db::database_url(Path::new(&project_path));
+        if let Some(db_path) = db::sqlite_path(&backend, &url) {
+            if !db_path.exists() {
+                return Err(Error::not_found(
+                    "database_missing",
+                    format!(
+                        "Database does not exist yet: {}; start the backend once to create it",
+                        db_path.display()
+                    ),
+                ));
+            }
+        }
+
+        let schemas: Vec<TableSchema> = db::introspect(&backend, &url)?
+            .into_iter()
+            .filter(|s| s.name != "alembic_version")
+            .collect();
+        if let Some(db_path) = db::sqlite_path(&backend, &url) {
+            Ok(sqlite_overview(&db_path, &schemas)?)
+        } else if let Some(pg_url) = db::postgres_url(&url) {
+            Ok(postgres_overview(&pg_url, &schemas)?)
+        } else {
+            Err(Error::invalid(
+                "unsupported_database_url",
+                format!("Unsupported DATABASE_URL: {}", url),
+            ))
+        }
+    })
+    .await
+}
@@ -0,0 +1,13 @@
+use crate::error::Error;
+
+// Runs a command's filesystem and process work on the blocking pool, so neither the
+// main thread nor the async runtime's workers stall while it runs
+pub async fn blocking<T, F>(f: F) -> Result<T, Error>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, Error> + Send + 'static,
+{
+    tauri::async_runtime::spawn_blocking(f)
+        .await
+        .map_err(|e| Error::from(format!("Background task failed: {}", e)))?
+}
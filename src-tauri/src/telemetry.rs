@@ -0,0 +1,229 @@
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Manager};
+
+use crate::db;
+use crate::error::Error;
+use crate::settings::SettingsState;
+use crate::task;
+use crate::versioned::{self, Migration};
+
+const TELEMETRY_FILE: &str = "telemetry.json";
+const TELEMETRY_MIGRATIONS: &[Migration] = &[versioned::initial];
+const FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+const UPLOAD_INTERVAL_SECS: u64 = 24 * 60 * 60;
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(10);
+// Set for release builds; without it nothing is ever uploaded
+const ENDPOINT: Option<&str> = option_env!("DEVLLM_TELEMETRY_URL");
+
+static TELEMETRY_PATH: OnceLock<PathBuf> = OnceLock::new();
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static USAGE: Mutex<Usage> = Mutex::new(Usage {
+    since: 0,
+    counts: BTreeMap::new(),
+    last_upload: None,
+    dirty: false,
+});
+
+// Only command names and how often they ran; no paths, arguments or identifiers
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct Usage {
+    since: u64,
+    counts: BTreeMap<String, u64>,
+    last_upload: Option<u64>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct TelemetryPayload {
+    app_version: &'static str,
+    os: &'static str,
+    arch: &'static str,
+    period_start: String,
+    period_end: String,
+    counts: BTreeMap<String, u64>,
+}
+
+#[derive(serde::Serialize)]
+pub struct TelemetryPreview {
+    recording: bool,
+    upload_enabled: bool,
+    endpoint: Option<&'static str>,
+    last_upload: Option<String>,
+    // Exactly the body the next upload would send
+    payload: TelemetryPayload,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn payload(usage: &Usage) -> TelemetryPayload {
+    TelemetryPayload {
+        app_version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        period_start: db::format_timestamp(usage.since),
+        period_end: db::format_timestamp(now()),
+        counts: usage.counts.clone(),
+    }
+}
+
+fn flush(usage: &mut Usage) {
+    let Some(path) = TELEMETRY_PATH.get() else {
+        return;
+    };
+    match versioned::save(path, &*usage, TELEMETRY_MIGRATIONS, "usage counts") {
+        Ok(()) => usage.dirty = false,
+        Err(e) => tracing::warn!("Failed to save usage counts: {}", e),
+    }
+}
+
+fn clear(usage: &mut Usage) {
+    usage.counts.clear();
+    usage.since = now();
+    usage.dirty = false;
+    if let Some(path) = TELEMETRY_PATH.get() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn upload(endpoint: &str, payload: &TelemetryPayload) -> Result<(), String> {
+    reqwest::blocking::Client::builder()
+        .timeout(UPLOAD_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?
+        .post(endpoint)
+        .json(payload)
+        .send()
+        .and_then(|response| response.error_for_status())
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+fn upload_due(app: &AppHandle) {
+    let Some(endpoint) = ENDPOINT else {
+        return;
+    };
+    let consent = app
+        .try_state::<SettingsState>()
+        .is_some_and(|settings| settings.get().telemetry.upload);
+    if !consent {
+        return;
+    }
+
+    let body = {
+        let usage = USAGE.lock();
+        let due = usage
+            .last_upload
+            .is_none_or(|last| now().saturating_sub(last) >= UPLOAD_INTERVAL_SECS);
+        if !due || usage.counts.is_empty() {
+            return;
+        }
+        payload(&usage)
+    };
+
+    match upload(endpoint, &body) {
+        Ok(()) => {
+            let mut usage = USAGE.lock();
+            // Counts recorded during the upload stay for the next period
+            for (command, sent) in &body.counts {
+                if let Some(count) = usage.counts.get_mut(command) {
+                    *count = count.saturating_sub(*sent);
+                }
+            }
+            usage.counts.retain(|_, count| *count > 0);
+            usage.since = now();
+            usage.last_upload = Some(now());
+            flush(&mut usage);
+            tracing::info!("uploaded usage counts");
+        }
+        Err(e) => tracing::debug!("Usage upload failed: {}", e),
+    }
+}
+
+pub fn init(app: &AppHandle, recording: bool) {
+    if let Ok(dir) = app.path().app_data_dir() {
+        let path = dir.join(TELEMETRY_FILE);
+        // Counts that can't be read are simply started over
+        let stored: Option<Usage> =
+            match versioned::load(&path, TELEMETRY_MIGRATIONS, "usage counts") {
+                Ok(stored) => stored,
+                Err(e) => {
+                    tracing::debug!("Ignoring usage counts: {}", e);
+                    None
+                }
+            };
+        *USAGE.lock() = stored.unwrap_or_else(|| Usage {
+            since: now(),
+            ..Usage::default()
+        });
+        let _ = TELEMETRY_PATH.set(path);
+    }
+    RECORDING.store(recording, Ordering::Relaxed);
+
+    let app = app.clone();
+    thread::spawn(move || loop {
+        thread::sleep(FLUSH_INTERVAL);
+        let mut usage = USAGE.lock();
+        if usage.dirty {
+            flush(&mut usage);
+        }
+        drop(usage);
+        upload_due(&app);
+    });
+}
+
+// Turning recording off also drops whatever was collected so far
+pub fn set_recording(recording: bool) {
+    RECORDING.store(recording, Ordering::Relaxed);
+    if !recording {
+        clear(&mut USAGE.lock());
+    }
+}
+
+pub fn record(command: &str) {
+    if !RECORDING.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut usage = USAGE.lock();
+    *usage.counts.entry(command.to_string()).or_insert(0) += 1;
+    usage.dirty = true;
+}
+
+#[tauri::command]
+pub async fn get_telemetry_preview(app: AppHandle) -> Result<TelemetryPreview, Error> {
+    task::blocking(move || {
+        let upload = app.state::<SettingsState>().get().telemetry.upload;
+        let usage = USAGE.lock();
+        Ok(TelemetryPreview {
+            recording: RECORDING.load(Ordering::Relaxed),
+            upload_enabled: upload && ENDPOINT.is_some(),
+            endpoint: ENDPOINT,
+            last_upload: usage.last_upload.map(db::format_timestamp),
+            payload: payload(&usage),
+        })
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn purge_telemetry() -> Result<(), Error> {
+    task::blocking(|| {
+        let mut usage = USAGE.lock();
+        clear(&mut usage);
+        tracing::info!("purged usage counts");
+        Ok(())
+    })
+    .await
+}
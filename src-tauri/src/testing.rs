@@ -0,0 +1,700 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+use crate::autostart;
+use crate::coverage::{self, CoverageReport};
+use crate::deps;
+use crate::error::Error;
+use crate::exec::{self, StreamedOutput};
+use crate::paths;
+use crate::python;
+use crate::task;
+use crate::tools;
+
+const TEST_EVENT: &str = "test-output";
+
+#[derive(serde::Serialize)]
+pub struct TestCase {
+    name: String,
+    file: Option<String>,
+    // "passed", "failed" or "skipped"
+    status: &'static str,
+    duration_ms: Option<u64>,
+    message: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct TestReport {
+    service: String,
+    runner: &'static str,
+    success: bool,
+    exit_code: Option<i32>,
+    duration_ms: u128,
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+    tests: Vec<TestCase>,
+    coverage: Option<CoverageReport>,
+    // Why the run produced no results, e.g. a collection or config error
+    error: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Runner {
+    Pytest,
+    Vitest,
+    Jest,
+    Playwright,
+}
+
+impl Runner {
+    fn name(self) -> &'static str {
+        match self {
+            Runner::Pytest => "pytest",
+            Runner::Vitest => "vitest",
+            Runner::Jest => "jest",
+            Runner::Playwright => "playwright",
+        }
+    }
+}
+
+fn node_runner(dir: &Path) -> Result<Runner, Error> {
+    let content = fs::read_to_string(dir.join("package.json"))?;
+    let json: serde_json::Value = serde_json::from_str(&content)?;
+    let declared = |name: &str| {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .any(|section| json[section].get(name).is_some())
+    };
+    if declared("vitest") {
+        Ok(Runner::Vitest)
+    } else if declared("jest") {
+        Ok(Runner::Jest)
+    } else {
+        Err(Error::not_found(
+            "no_test_runner",
+            "The frontend has neither vitest nor jest as a dependency",
+        ))
+    }
+}
+
+// Machine-readable results go to temp files; the console reporter keeps streaming progress
+fn temp_path(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    std::env::temp_dir().join(format!("devllm-{}-{}", name, nanos))
+}
+
+fn node_tool(dir: &Path, tool: &str) -> Result<Command, Error> {
+    let bin = tools::node_bin(dir, tool).ok_or_else(|| tools::not_installed(tool, "frontend"))?;
+    Ok(exec::tool_command(&bin.to_string_lossy()))
+}
+
+// With `coverage`, the run also writes coverage data into that directory: through
+// coverage.py for pytest and c8 for the node runners
+fn test_command(
+    dir: &Path,
+    runner: Runner,
+    filter: Option<&str>,
+    report: &Path,
+    coverage: Option<&Path>,
+) -> Result<Command, Error> {
+    let mut cmd = if runner == Runner::Pytest {
+        let python = python::venv_python(dir)
+            .ok_or_else(|| Error::not_found("no_venv", "Backend has no virtual environment"))?;
+        let mut cmd = Command::new(python);
+        if let Some(coverage) = coverage {
+            if !python::installed_distributions(dir).contains_key("coverage") {
+                return Err(tools::not_installed("coverage", "backend"));
+            }
+            cmd.args(["-m", "coverage", "run"]).arg(format!(
+                "--data-file={}",
+                coverage.join(".coverage").display()
+            ));
+        }
+        cmd.args(["-m", "pytest", "-v", "-rA", "--durations=0", "--color=no"]);
+        if let Some(filter) = filter {
+            cmd.args(["-k", filter]);
+        }
+        cmd
+    } else {
+        let bin = tools::node_bin(dir, runner.name())
+            .ok_or_else(|| tools::not_installed(runner.name(), "frontend"))?;
+        let mut cmd = match coverage {
+            Some(coverage) => {
+                let mut cmd = node_tool(dir, "c8")?;
+                cmd.args(["--reporter=json", "--reports-dir"])
+                    .arg(coverage)
+                    .arg(&bin);
+                cmd
+            }
+            None => exec::tool_command(&bin.to_string_lossy()),
+        };
+        let output = format!("{}", report.display());
+        if runner == Runner::Vitest {
+            cmd.args(["run", "--reporter=default", "--reporter=json"])
+                .arg(format!("--outputFile.json={}", output));
+        } else {
+            cmd.args(["--ci", "--json"])
+                .arg(format!("--outputFile={}", output));
+        }
+        if let Some(filter) = filter {
+            cmd.args(["-t", filter]);
+        }
+        cmd.env("FORCE_COLOR", "0");
+        cmd
+    };
+    cmd.current_dir(dir);
+    Ok(cmd)
+}
+
+fn collect_coverage(dir: &Path, runner: Runner, coverage: &Path) -> Option<CoverageReport> {
+    if runner == Runner::Pytest {
+        let json_path = coverage.join("coverage.json");
+        let mut cmd = Command::new(python::venv_python(dir)?);
+        cmd.args(["-m", "coverage", "json", "-q"])
+            .arg(format!(
+                "--data-file={}",
+                coverage.join(".coverage").display()
+            ))
+            .arg("-o")
+            .arg(&json_path)
+            .current_dir(dir);
+        let output = exec::output(&mut cmd).ok()?;
+        if !output.status.success() {
+            tracing::warn!(
+                "coverage json failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return None;
+        }
+        let json = serde_json::from_str(&fs::read_to_string(json_path).ok()?).ok()?;
+        Some(coverage::from_coverage_py(dir, &json))
+    } else {
+        let content = fs::read_to_string(coverage.join("coverage-final.json")).ok()?;
+        Some(coverage::from_istanbul(
+            dir,
+            &serde_json::from_str(&content).ok()?,
+        ))
+    }
+}
+
+fn pytest_status(word: &str) -> Option<&'static str> {
+    match word {
+        "PASSED" | "XPASS" => Some("passed"),
+        "FAILED" | "ERROR" => Some("failed"),
+        "SKIPPED" | "XFAIL" => Some("skipped"),
+        _ => None,
+    }
+}
+
+fn pytest_case(node_id: &str) -> TestCase {
+    let (file, name) = match node_id.split_once("::") {
+        Some((file, name)) => (Some(file.to_string()), name.to_string()),
+        None => (None, node_id.to_string()),
+    };
+    TestCase {
+        name,
+        file,
+        status: "passed",
+        duration_ms: None,
+        message: None,
+    }
+}
+
+// `-v` gives one "<node id> STATUS [ 50%]" line per test, `-rA` the failure messages and
+// `--durations=0` the call times
+fn parse_pytest(lines: &[String]) -> Vec<TestCase> {
+    let mut cases: BTreeMap<String, TestCase> = BTreeMap::new();
+    let mut order: Vec<String> = Vec::new();
+
+    for line in lines {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [node_id, status, ..] if node_id.contains("::") => {
+                let Some(status) = pytest_status(status) else {
+                    continue;
+                };
+                let case = cases.entry(node_id.to_string()).or_insert_with(|| {
+                    order.push(node_id.to_string());
+                    pytest_case(node_id)
+                });
+                // A teardown error follows the PASSED line of the same test
+                if case.status != "failed" {
+                    case.status = status;
+                }
+            }
+            [time, "call", node_id] => {
+                let secs = time.strip_suffix('s').and_then(|t| t.parse::<f64>().ok());
+                if let (Some(secs), Some(case)) = (secs, cases.get_mut(*node_id)) {
+                    case.duration_ms = Some((secs * 1000.0).round() as u64);
+                }
+            }
+            _ => {}
+        }
+
+        // "FAILED tests/test_api.py::test_login - AssertionError: assert 401 == 200"
+        for prefix in ["FAILED ", "ERROR "] {
+            let Some((node_id, message)) = line
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.split_once(" - "))
+            else {
+                continue;
+            };
+            if let Some(case) = cases.get_mut(node_id) {
+                case.message = Some(message.trim().to_string());
+            }
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|id| cases.remove(&id))
+        .collect()
+}
+
+// Playwright's json reporter nests describe blocks as suites; each spec holds one test per
+// browser project
+fn parse_playwright(suite: &serde_json::Value, titles: &[String], cases: &mut Vec<TestCase>) {
+    let mut titles = titles.to_vec();
+    // The outermost suite is the file itself
+    if let Some(title) = suite["title"].as_str().filter(|_| !suite["file"].is_null()) {
+        if !titles.is_empty() || title != suite["file"].as_str().unwrap_or_default() {
+            titles.push(title.to_string());
+        }
+    }
+
+    for spec in suite["specs"].as_array().into_iter().flatten() {
+        let tests = spec["tests"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for test in tests {
+            let mut name = titles.clone();
+            name.push(spec["title"].as_str().unwrap_or_default().to_string());
+            let mut name = name.join(" › ");
+            if tests.len() > 1 {
+                name = format!(
+                    "{} [{}]",
+                    name,
+                    test["projectName"].as_str().unwrap_or_default()
+                );
+            }
+            let last = test["results"]
+                .as_array()
+                .and_then(|results| results.last());
+            cases.push(TestCase {
+                name,
+                file: spec["file"].as_str().map(String::from),
+                status: match test["status"].as_str() {
+                    Some("expected") | Some("flaky") => "passed",
+                    Some("skipped") => "skipped",
+                    _ => "failed",
+                },
+                duration_ms: last.and_then(|r| r["duration"].as_u64()),
+                message: last
+                    .and_then(|r| r["error"]["message"].as_str())
+                    .map(|m| m.trim().to_string()),
+            });
+        }
+    }
+
+    for child in suite["suites"].as_array().into_iter().flatten() {
+        parse_playwright(child, &titles, cases);
+    }
+}
+
+// vitest's json reporter follows jest's --json format
+fn parse_jest_json(dir: &Path, json: &serde_json::Value) -> Vec<TestCase> {
+    let mut cases = Vec::new();
+    for suite in json["testResults"].as_array().into_iter().flatten() {
+        let file = suite["name"]
+            .as_str()
+            .map(|name| paths::relative(dir, name));
+        let assertions = suite["assertionResults"].as_array();
+
+        // A file that fails to load has no assertions, only a suite-level message
+        if assertions.is_none_or(|a| a.is_empty()) {
+            if suite["status"] == "failed" {
+                cases.push(TestCase {
+                    name: file.clone().unwrap_or_default(),
+                    file: file.clone(),
+                    status: "failed",
+                    duration_ms: None,
+                    message: suite["message"].as_str().map(|m| m.trim().to_string()),
+                });
+            }
+            continue;
+        }
+
+        for test in assertions.into_iter().flatten() {
+            let status = match test["status"].as_str() {
+                Some("passed") => "passed",
+                Some("failed") => "failed",
+                _ => "skipped",
+            };
+            let messages: Vec<&str> = test["failureMessages"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|m| m.as_str())
+                .collect();
+            cases.push(TestCase {
+                name: test["fullName"]
+                    .as_str()
+                    .or(test["title"].as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                file: file.clone(),
+                status,
+                duration_ms: test["duration"].as_f64().map(|d| d.round() as u64),
+                message: (!messages.is_empty()).then(|| messages.join("\n")),
+            });
+        }
+    }
+    cases
+}
+
+fn summarize(
+    service: String,
+    runner: Runner,
+    output: &StreamedOutput,
+    started: Instant,
+    tests: Vec<TestCase>,
+    coverage: Option<CoverageReport>,
+) -> TestReport {
+    let count = |status: &str| tests.iter().filter(|t| t.status == status).count();
+    let (passed, failed, skipped) = (count("passed"), count("failed"), count("skipped"));
+    // pytest exits 5 when nothing matched the filter, which isn't a failure
+    let success = failed == 0 && (output.status.success() || output.status.code() == Some(5));
+    let error = if success || failed > 0 {
+        None
+    } else {
+        output
+            .lines
+            .iter()
+            .rev()
+            .find(|l| l.contains("Error") || l.starts_with("ERROR"))
+            .or(output.lines.last())
+            .map(|l| l.trim().to_string())
+    };
+
+    TestReport {
+        service,
+        runner: runner.name(),
+        success,
+        exit_code: output.status.code(),
+        duration_ms: started.elapsed().as_millis(),
+        passed,
+        failed,
+        skipped,
+        tests,
+        coverage,
+        error,
+    }
+}
+
+#[tauri::command]
+pub async fn run_tests(
+    app: AppHandle,
+    project_path: String,
+    service: String,
+    filter: Option<String>,
+    coverage: Option<bool>,
+) -> Result<TestReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let dir = deps::service_dir(Path::new(&project_path), &service)?;
+        let runner = if dir.join("package.json").exists() {
+            node_runner(&dir)?
+        } else {
+            Runner::Pytest
+        };
+        let filter = filter.filter(|f| !f.trim().is_empty());
+
+        let report = temp_path(&format!("{}.json", runner.name()));
+        let coverage_dir = coverage.unwrap_or(false).then(|| temp_path("coverage"));
+        if let Some(coverage_dir) = &coverage_dir {
+            fs::create_dir_all(coverage_dir)?;
+        }
+        let mut cmd = test_command(
+            &dir,
+            runner,
+            filter.as_deref(),
+            &report,
+            coverage_dir.as_deref(),
+        )?;
+        let started = Instant::now();
+        let operation = format!("tests:{}:{}", project_path, service);
+        let output = exec::run_streamed(&app, TEST_EVENT, &operation, &mut cmd)
+            .map_err(|e| exec::run_failed(runner.name(), e))?;
+
+        let tests = if runner == Runner::Pytest {
+            parse_pytest(&output.lines)
+        } else {
+            let json = fs::read_to_string(&report)
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok());
+            let _ = fs::remove_file(&report);
+            json.map(|json| parse_jest_json(&dir, &json))
+                .unwrap_or_default()
+        };
+
+        let coverage = coverage_dir.and_then(|coverage_dir| {
+            let collected = collect_coverage(&dir, runner, &coverage_dir);
+            let _ = fs::remove_dir_all(&coverage_dir);
+            collected
+        });
+
+        let report = summarize(service, runner, &output, started, tests, coverage);
+        tracing::info!(
+            %project_path,
+            service = %report.service,
+            passed = report.passed,
+            failed = report.failed,
+            skipped = report.skipped,
+            "ran tests"
+        );
+        Ok(report)
+    })
+    .await
+}
+
+// Brings both services up through autostart's readiness checks, then runs Playwright
+// against the frontend
+#[tauri::command]
+pub async fn run_e2e(
+    app: AppHandle,
+    project_path: String,
+    filter: Option<String>,
+) -> Result<TestReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let root = Path::new(&project_path);
+        let dir = deps::service_dir(root, "frontend")?;
+        if !dir.join("playwright.config.ts").exists() && !dir.join("playwright.config.js").exists()
+        {
+            return Err(Error::not_found(
+                "no_e2e_setup",
+                "The frontend has no Playwright config; create the project with E2E tests enabled",
+            ));
+        }
+        let bin = tools::node_bin(&dir, "playwright")
+            .ok_or_else(|| tools::not_installed("@playwright/test", "frontend"))?;
+        autostart::ensure_running(&app, root)?;
+
+        let project = crate::detect_project_blocking(project_path.clone())?;
+        let report = temp_path("playwright.json");
+        let mut cmd = exec::tool_command(&bin.to_string_lossy());
+        cmd.args(["test", "--reporter=list,json"])
+            .env("PLAYWRIGHT_JSON_OUTPUT_NAME", &report)
+            .env("FORCE_COLOR", "0")
+            .current_dir(&dir);
+        if let Some(port) = project.frontend_port {
+            cmd.env("E2E_BASE_URL", format!("http://127.0.0.1:{}", port));
+        }
+        if let Some(filter) = filter.filter(|f| !f.trim().is_empty()) {
+            cmd.args(["-g", &filter]);
+        }
+
+        let started = Instant::now();
+        let operation = format!("e2e:{}", project_path);
+        let output = exec::run_streamed(&app, TEST_EVENT, &operation, &mut cmd)
+            .map_err(|e| exec::run_failed("playwright", e))?;
+
+        let mut tests = Vec::new();
+        let json: Option<serde_json::Value> = fs::read_to_string(&report)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok());
+        let _ = fs::remove_file(&report);
+        for suite in json.iter().flat_map(|j| j["suites"].as_array()).flatten() {
+            parse_playwright(suite, &[], &mut tests);
+        }
+
+        let report = summarize(
+            "frontend".to_string(),
+            Runner::Playwright,
+            &output,
+            started,
+            tests,
+            None,
+        );
+        tracing::info!(
+            %project_path,
+            passed = report.passed,
+            failed = report.failed,
+            "ran e2e tests"
+        );
+        Ok(report)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn summary(cases: &[TestCase]) -> Vec<(&str, Option<&str>, &str)> {
+        cases
+            .iter()
+            .map(|c| (c.name.as_str(), c.file.as_deref(), c.status))
+            .collect()
+    }
+
+    #[test]
+    fn parses_verbose_pytest_output() {
+        let lines: Vec<String> = [
+            "============================= test session starts ==============================",
+            "tests/test_api.py::test_health PASSED                                    [ 25%]",
+            "tests/test_api.py::test_health ERROR                                     [ 25%]",
+            "tests/test_api.py::test_login FAILED                                     [ 50%]",
+            "tests/test_api.py::test_slow SKIPPED (needs db)                          [ 75%]",
+            "tests/test_api.py::TestItems::test_create XFAIL                          [100%]",
+            "============================= slowest durations ==============================",
+            "0.25s call     tests/test_api.py::test_login",
+            "0.01s setup    tests/test_api.py::test_login",
+            "=========================== short test summary info ============================",
+            "SKIPPED [1] tests/test_api.py:10: needs db",
+            "FAILED tests/test_api.py::test_login - AssertionError: assert 401 == 200",
+            "ERROR tests/test_api.py::test_health - RuntimeError: db still open",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        let cases = parse_pytest(&lines);
+        assert_eq!(
+            summary(&cases),
+            [
+                ("test_health", Some("tests/test_api.py"), "failed"),
+                ("test_login", Some("tests/test_api.py"), "failed"),
+                ("test_slow", Some("tests/test_api.py"), "skipped"),
+                (
+                    "TestItems::test_create",
+                    Some("tests/test_api.py"),
+                    "skipped"
+                ),
+            ]
+        );
+        assert_eq!(
+            cases[0].message.as_deref(),
+            Some("RuntimeError: db still open")
+        );
+        assert_eq!(
+            cases[1].message.as_deref(),
+            Some("AssertionError: assert 401 == 200")
+        );
+        assert_eq!(cases[1].duration_ms, Some(250));
+        assert_eq!(cases[2].duration_ms, None);
+    }
+
+    #[test]
+    fn parses_jest_and_vitest_json() {
+        let report = json!({
+            "testResults": [
+                {
+                    "name": "/app/frontend/src/App.test.tsx",
+                    "status": "failed",
+                    "assertionResults": [
+                        {
+                            "fullName": "App renders the title",
+                            "title": "renders the title",
+                            "status": "passed",
+                            "duration": 12.4,
+                            "failureMessages": []
+                        },
+                        {
+                            "title": "submits the form",
+                            "status": "failed",
+                            "duration": 3,
+                            "failureMessages": ["Expected 1", "Received 2"]
+                        },
+                        { "fullName": "App is todo", "status": "todo" }
+                    ]
+                },
+                {
+                    "name": "/app/frontend/src/broken.test.ts",
+                    "status": "failed",
+                    "message": "  SyntaxError: Unexpected token  ",
+                    "assertionResults": []
+                },
+                { "name": "/app/frontend/src/empty.test.ts", "status": "passed" }
+            ]
+        });
+
+        let cases = parse_jest_json(Path::new("/app/frontend"), &report);
+        assert_eq!(
+            summary(&cases),
+            [
+                ("App renders the title", Some("src/App.test.tsx"), "passed"),
+                ("submits the form", Some("src/App.test.tsx"), "failed"),
+                ("App is todo", Some("src/App.test.tsx"), "skipped"),
+                ("src/broken.test.ts", Some("src/broken.test.ts"), "failed"),
+            ]
+        );
+        assert_eq!(cases[0].duration_ms, Some(12));
+        assert_eq!(cases[1].message.as_deref(), Some("Expected 1\nReceived 2"));
+        assert_eq!(
+            cases[3].message.as_deref(),
+            Some("SyntaxError: Unexpected token")
+        );
+    }
+
+    #[test]
+    fn parses_nested_playwright_suites() {
+        let report = json!({
+            "title": "login.spec.ts",
+            "file": "login.spec.ts",
+            "specs": [],
+            "suites": [{
+                "title": "login",
+                "file": "login.spec.ts",
+                "specs": [{
+                    "title": "rejects a bad password",
+                    "file": "login.spec.ts",
+                    "tests": [
+                        {
+                            "projectName": "chromium",
+                            "status": "expected",
+                            "results": [{ "duration": 812 }]
+                        },
+                        {
+                            "projectName": "firefox",
+                            "status": "unexpected",
+                            "results": [
+                                { "duration": 100 },
+                                { "duration": 900, "error": { "message": "Timed out " } }
+                            ]
+                        }
+                    ]
+                }]
+            }]
+        });
+
+        let mut cases = Vec::new();
+        parse_playwright(&report, &[], &mut cases);
+        assert_eq!(
+            summary(&cases),
+            [
+                (
+                    "login › rejects a bad password [chromium]",
+                    Some("login.spec.ts"),
+                    "passed"
+                ),
+                (
+                    "login › rejects a bad password [firefox]",
+                    Some("login.spec.ts"),
+                    "failed"
+                ),
+            ]
+        );
+        assert_eq!(cases[1].duration_ms, Some(900));
+        assert_eq!(cases[1].message.as_deref(), Some("Timed out"));
+    }
+}
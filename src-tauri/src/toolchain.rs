@@ -0,0 +1,545 @@
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use crate::deps::{self, INSTALL_EVENT};
+use crate::error::Error;
+use crate::exec;
+use crate::paths;
+use crate::task;
+
+// Each tool with the commands that may provide it, in order of preference. Many Linux
+// and macOS installs only ship python3/pip3, and Windows may only have the `py` launcher
+const PYTHON: &[&str] = if cfg!(windows) {
+    &["python", "python3", "py"]
+} else {
+    &["python", "python3"]
+};
+const TOOLS: &[(&str, &[&str])] = &[
+    ("node", &["node"]),
+    ("npm", &["npm"]),
+    ("pnpm", &["pnpm"]),
+    ("python", PYTHON),
+    ("pip", &["pip", "pip3"]),
+    ("git", &["git"]),
+    ("docker", &["docker"]),
+];
+
+#[derive(serde::Serialize)]
+pub struct ToolCheck {
+    name: &'static str,
+    // The command that answered, e.g. "python3" when there is no plain `python`
+    program: Option<&'static str>,
+    found: bool,
+    version: Option<String>,
+    path: Option<String>,
+    all_paths: Vec<String>,
+    required: Option<String>,
+    satisfies_requirement: Option<bool>,
+    problems: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct DoctorReport {
+    tools: Vec<ToolCheck>,
+    missing_path_entries: Vec<String>,
+    healthy: bool,
+}
+
+// Every match for `name` on PATH, in lookup order
+pub fn which_all(name: &str) -> Vec<PathBuf> {
+    let extensions: Vec<String> = if cfg!(windows) {
+        env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+            .split(';')
+            .filter(|e| !e.is_empty())
+            .map(|e| e.to_lowercase())
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+
+    let mut found = Vec::new();
+    let mut seen = HashSet::new();
+    for dir in env::split_paths(&env::var_os("PATH").unwrap_or_default()) {
+        for ext in &extensions {
+            let candidate = dir.join(format!("{}{}", name, ext));
+            if candidate.is_file() && seen.insert(candidate.clone()) {
+                found.push(candidate);
+            }
+        }
+    }
+    found
+}
+
+// "v20.11.0", "git version 2.43.0.windows.1", "pip 24.0 from ..." -> leading dotted number
+pub fn parse_version(text: &str) -> Option<String> {
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .map(|w| w.trim_start_matches('v'))
+        .find(|w| w.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|w| {
+            w.split('.')
+                .take_while(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+}
+
+pub fn tool_version(name: &str) -> Option<String> {
+    let output = exec::output(exec::tool_command(name).arg("--version")).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = if output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    } else {
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+    parse_version(&text)
+}
+
+fn numeric_parts(version: &str) -> Vec<u64> {
+    version
+        .trim_start_matches('v')
+        .split('.')
+        .map_while(|p| p.parse().ok())
+        .collect()
+}
+
+fn compare(a: &[u64], b: &[u64]) -> std::cmp::Ordering {
+    let len = a.len().max(b.len());
+    let pad = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+    (0..len)
+        .map(|i| pad(a, i).cmp(&pad(b, i)))
+        .find(|o| o.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
+// Understands the subset of semver/PEP 440 ranges projects put in engines,
+// requires-python, .nvmrc and .python-version ("20", ">=3.10,<4", "^18.2", "~=3.11")
+pub fn satisfies(version: &str, requirement: &str) -> Option<bool> {
+    let have = numeric_parts(version);
+    if have.is_empty() {
+        return None;
+    }
+
+    let mut checked = false;
+    for clause in requirement.split([',', ' ']).filter(|c| !c.is_empty()) {
+        let (op, wanted) = ["~=", ">=", "<=", "==", ">", "<", "=", "^", "~"]
+            .iter()
+            .find_map(|op| clause.strip_prefix(op).map(|rest| (*op, rest)))
+            .unwrap_or(("", clause));
+        let want = numeric_parts(wanted.trim_end_matches(".*").trim_end_matches(".x"));
+        if want.is_empty() {
+            continue;
+        }
+        checked = true;
+
+        let ord = compare(&have, &want);
+        let ok = match op {
+            ">=" => ord.is_ge(),
+            ">" => ord.is_gt(),
+            "<=" => ord.is_le(),
+            "<" => ord.is_lt(),
+            "^" => ord.is_ge() && have[0] == want[0],
+            "~" | "~=" => {
+                let prefix = want.len().saturating_sub(1).max(1);
+                ord.is_ge() && have.len() >= prefix && have[..prefix] == want[..prefix]
+            }
+            // Bare and exact versions match on the components given ("20" matches 20.11.0)
+            _ => have.len() >= want.len() && have[..want.len()] == want[..],
+        };
+        if !ok {
+            return Some(false);
+        }
+    }
+
+    checked.then_some(true)
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.lines().next().unwrap_or("").trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+pub fn node_requirement(frontend: &Path) -> Option<String> {
+    let package = fs::read_to_string(frontend.join("package.json"))
+        .ok()
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok());
+    if let Some(engine) = package.as_ref().and_then(|p| p["engines"]["node"].as_str()) {
+        return Some(engine.to_string());
+    }
+
+    [".nvmrc", ".node-version"]
+        .iter()
+        .find_map(|f| read_trimmed(&frontend.join(f)))
+}
+
+pub fn python_requirement(backend: &Path) -> Option<String> {
+    if let Some(version) = read_trimmed(&backend.join(".python-version")) {
+        return Some(version);
+    }
+
+    let pyproject = fs::read_to_string(backend.join("pyproject.toml")).ok()?;
+    pyproject
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("requires-python"))
+        .and_then(|rest| rest.split('"').nth(1))
+        .map(String::from)
+}
+
+fn check_tool(
+    name: &'static str,
+    programs: &[&'static str],
+    required: Option<String>,
+) -> ToolCheck {
+    let mut problems = Vec::new();
+
+    // The first alias that runs wins; failing that, the first one on PATH is reported
+    let candidates: Vec<(&'static str, Vec<PathBuf>)> = programs
+        .iter()
+        .map(|program| (*program, which_all(program)))
+        .filter(|(_, paths)| !paths.is_empty())
+        .collect();
+    let resolved = candidates
+        .iter()
+        .find_map(|(program, paths)| tool_version(program).map(|v| (*program, paths, Some(v))))
+        .or_else(|| {
+            candidates
+                .first()
+                .map(|(program, paths)| (*program, paths, None))
+        });
+    let (program, all_paths, version) = match resolved {
+        Some((program, paths, version)) => (Some(program), paths.clone(), version),
+        None => (None, Vec::new(), None),
+    };
+
+    match program {
+        None => problems.push(format!("{} was not found on PATH", name)),
+        Some(program) if version.is_none() => {
+            problems.push(format!("{} is on PATH but failed to run", program))
+        }
+        Some(_) => {}
+    }
+
+    let first = all_paths.first().map(|p| p.to_string_lossy().into_owned());
+    if let Some(first) = &first {
+        if name == "python" && first.contains("WindowsApps") {
+            problems.push("python resolves to the Microsoft Store alias; install Python from python.org or disable the App Execution Alias".to_string());
+        }
+    }
+
+    let distinct_dirs: HashSet<_> = all_paths.iter().filter_map(|p| p.parent()).collect();
+    if distinct_dirs.len() > 1 {
+        problems.push(format!(
+            "{} copies of {} on PATH; the first one wins",
+            distinct_dirs.len(),
+            name
+        ));
+    }
+
+    let satisfies_requirement = match (&version, &required) {
+        (Some(v), Some(r)) => satisfies(v, r),
+        _ => None,
+    };
+    if satisfies_requirement == Some(false) {
+        problems.push(format!(
+            "{} {} does not satisfy the project requirement {}",
+            name,
+            version.as_deref().unwrap_or("?"),
+            required.as_deref().unwrap_or("?")
+        ));
+    }
+
+    ToolCheck {
+        name,
+        program,
+        found: !all_paths.is_empty(),
+        version,
+        path: first,
+        all_paths: all_paths
+            .iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect(),
+        required,
+        satisfies_requirement,
+        problems,
+    }
+}
+
+#[tauri::command]
+pub async fn doctor(project_path: Option<String>) -> Result<DoctorReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::optional_dir(project_path)?;
+        let (frontend, backend) = match &project_path {
+            Some(p) => {
+                let path = Path::new(p);
+                if !path.exists() {
+                    return Err(Error::path_not_found(p));
+                }
+                deps::service_dirs(path)
+            }
+            None => (None, None),
+        };
+
+        let tools: Vec<ToolCheck> = TOOLS
+            .iter()
+            .map(|(name, programs)| {
+                let required = match *name {
+                    "node" => frontend.as_deref().and_then(node_requirement),
+                    "python" => backend.as_deref().and_then(python_requirement),
+                    _ => None,
+                };
+                check_tool(name, programs, required)
+            })
+            .collect();
+
+        let missing_path_entries: Vec<String> =
+            env::split_paths(&env::var_os("PATH").unwrap_or_default())
+                .filter(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .collect();
+
+        // pnpm and docker are optional; everything else is needed to scaffold and run
+        let healthy = tools.iter().all(|t| {
+            matches!(t.name, "pnpm" | "docker")
+                || (t.found && t.version.is_some() && t.satisfies_requirement != Some(false))
+        });
+
+        Ok(DoctorReport {
+            tools,
+            missing_path_entries,
+            healthy,
+        })
+    })
+    .await
+}
+
+#[derive(serde::Serialize)]
+pub struct VersionManagers {
+    node: Vec<&'static str>,
+    python: Vec<&'static str>,
+}
+
+#[derive(serde::Serialize)]
+pub struct RuntimeInstallReport {
+    runtime: &'static str,
+    manager: &'static str,
+    version: String,
+    success: bool,
+    exit_code: Option<i32>,
+    duration_ms: u128,
+    output_tail: Vec<String>,
+}
+
+// nvm on Unix is a shell function sourced from $NVM_DIR rather than a binary
+fn unix_nvm_script() -> Option<PathBuf> {
+    if cfg!(windows) {
+        return None;
+    }
+    let dir = env::var_os("NVM_DIR")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".nvm")))?;
+    let script = dir.join("nvm.sh");
+    script.exists().then_some(script)
+}
+
+fn node_managers() -> Vec<&'static str> {
+    let mut found: Vec<&'static str> = ["fnm", "volta"]
+        .into_iter()
+        .filter(|m| !which_all(m).is_empty())
+        .collect();
+    if (cfg!(windows) && !which_all("nvm").is_empty()) || unix_nvm_script().is_some() {
+        found.push("nvm");
+    }
+    found
+}
+
+fn python_managers() -> Vec<&'static str> {
+    ["uv", "pyenv"]
+        .into_iter()
+        .filter(|m| !which_all(m).is_empty())
+        .collect()
+}
+
+// Version managers want a concrete version or prefix, not a range: ">=3.10,<4" -> "3.10".
+// The result goes on the manager's command line, so it can't look like an option.
+fn install_target(requirement: &str) -> Result<String, Error> {
+    let target = requirement
+        .split([',', ' '])
+        .map(|c| c.trim_start_matches(|ch: char| "<>=^~!".contains(ch)))
+        .find(|c| c.starts_with(|ch: char| ch.is_ascii_digit()))
+        .map(|c| c.trim_end_matches(".*").trim_end_matches(".x").to_string())
+        .unwrap_or_else(|| requirement.trim().to_string());
+    let valid = !target.is_empty()
+        && !target.starts_with('-')
+        && target
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".+-".contains(c));
+    if !valid {
+        return Err(Error::invalid(
+            "invalid_version",
+            format!("{} is not a version that can be installed", requirement),
+        )
+        .with("version", requirement));
+    }
+    Ok(target)
+}
+
+fn pick_manager(
+    available: Vec<&'static str>,
+    requested: Option<String>,
+    runtime: &str,
+) -> Result<&'static str, String> {
+    match requested {
+        Some(requested) => available
+            .into_iter()
+            .find(|m| *m == requested)
+            .ok_or_else(|| format!("{} is not installed", requested)),
+        None => available.into_iter().next().ok_or_else(|| {
+            format!(
+                "No {} version manager found; install one of: {}",
+                runtime,
+                if runtime == "node" {
+                    "fnm, nvm, volta"
+                } else {
+                    "uv, pyenv"
+                }
+            )
+        }),
+    }
+}
+
+fn run_runtime_install(
+    app: &AppHandle,
+    runtime: &'static str,
+    manager: &'static str,
+    version: String,
+    mut cmd: Command,
+) -> Result<RuntimeInstallReport, Error> {
+    let started = Instant::now();
+    let operation = format!("runtime:{}:{}", runtime, version);
+    let output = exec::run_streamed(app, INSTALL_EVENT, &operation, &mut cmd)
+        .map_err(|e| exec::run_failed(manager, e))?;
+
+    let tail_start = output.lines.len().saturating_sub(20);
+    Ok(RuntimeInstallReport {
+        runtime,
+        manager,
+        version,
+        success: output.status.success(),
+        exit_code: output.status.code(),
+        duration_ms: started.elapsed().as_millis(),
+        output_tail: output.lines[tail_start..].to_vec(),
+    })
+}
+
+#[tauri::command]
+pub async fn detect_version_managers() -> Result<VersionManagers, Error> {
+    task::blocking(move || {
+        Ok(VersionManagers {
+            node: node_managers(),
+            python: python_managers(),
+        })
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn install_node_version(
+    app: AppHandle,
+    version: String,
+    manager: Option<String>,
+) -> Result<RuntimeInstallReport, Error> {
+    task::blocking(move || {
+        let manager = pick_manager(node_managers(), manager, "node")?;
+        let version = install_target(&version)?;
+
+        let cmd = match manager {
+            "fnm" => {
+                let mut cmd = exec::tool_command("fnm");
+                cmd.args(["install", &version]);
+                cmd
+            }
+            "volta" => {
+                let mut cmd = exec::tool_command("volta");
+                cmd.args(["install", &format!("node@{}", version)]);
+                cmd
+            }
+            _ => match unix_nvm_script() {
+                Some(script) => {
+                    let mut cmd = Command::new("bash");
+                    cmd.arg("-c")
+                        .arg(r#". "$1" && nvm install "$2""#)
+                        .arg("bash")
+                        .arg(script)
+                        .arg(&version);
+                    cmd
+                }
+                None => {
+                    let mut cmd = exec::tool_command("nvm");
+                    cmd.args(["install", &version]);
+                    cmd
+                }
+            },
+        };
+
+        run_runtime_install(&app, "node", manager, version, cmd)
+    })
+    .await
+}
+
+#[tauri::command]
+pub async fn install_python_version(
+    app: AppHandle,
+    version: String,
+    manager: Option<String>,
+) -> Result<RuntimeInstallReport, Error> {
+    task::blocking(move || {
+        let manager = pick_manager(python_managers(), manager, "python")?;
+        let version = install_target(&version)?;
+
+        let mut cmd = exec::tool_command(manager);
+        if manager == "uv" {
+            cmd.args(["python", "install", &version]);
+        } else {
+            // pyenv resolves a prefix like 3.12 to the latest patch release
+            cmd.args(["install", "--skip-existing", &version]);
+        }
+
+        run_runtime_install(&app, "python", manager, version, cmd)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_an_installable_version() {
+        let cases = [
+            ("20", "20"),
+            ("v20.11.0", "v20.11.0"),
+            (">=3.10,<4", "3.10"),
+            ("^18.2", "18.2"),
+            ("~=3.11", "3.11"),
+            ("3.12.*", "3.12"),
+            ("18.x", "18"),
+            ("lts", "lts"),
+            ("3.13.0rc1", "3.13.0rc1"),
+        ];
+        for (requirement, target) in cases {
+            assert_eq!(install_target(requirement).ok().as_deref(), Some(target));
+        }
+        for requirement in ["", "--help", "-v", "lts/*", "20; rm -rf ~", "$(id)"] {
+            assert!(install_target(requirement).is_err(), "{}", requirement);
+        }
+    }
+}
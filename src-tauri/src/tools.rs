@@ -0,0 +1,209 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::paths;
+use crate::python;
+use crate::task;
+use crate::toolchain;
+
+#[derive(serde::Serialize)]
+pub struct ToolRequirement {
+    tool: String,
+    command: String,
+    ecosystem: &'static str,
+    // "project" when installed in node_modules/.bin or the venv, "path" when only a
+    // global install makes it work; None when it can't be resolved at all
+    found_in: Option<&'static str>,
+    location: Option<String>,
+    // Package to add_dependency so the tool resolves inside the project
+    package: String,
+    dev: bool,
+}
+
+// Shells, runtimes and package managers are expected to come from the system
+const SYSTEM_COMMANDS: [&str; 28] = [
+    "node", "npm", "pnpm", "yarn", "bun", "npx", "corepack", "python", "python3", "py", "pip",
+    "uv", "poetry", "cd", "echo", "rm", "cp", "mv", "mkdir", "set", "export", "exit", "cmd", "sh",
+    "bash", "true", "start", "open",
+];
+
+// Wrappers whose next word is the actual tool
+const RUNNERS: [&[&str]; 7] = [
+    &["npx"],
+    &["pnpm", "exec"],
+    &["pnpm", "dlx"],
+    &["yarn", "exec"],
+    &["uv", "run"],
+    &["poetry", "run"],
+    &["corepack"],
+];
+
+fn node_package(tool: &str) -> (&str, bool) {
+    match tool {
+        "tsc" => ("typescript", true),
+        // Frameworks that serve the app are runtime dependencies
+        "next" | "nuxt" | "astro" => (tool, false),
+        _ => (tool, true),
+    }
+}
+
+fn python_package(tool: &str) -> (&str, bool) {
+    match tool {
+        "pytest" | "black" | "ruff" | "mypy" | "isort" | "flake8" | "coverage" => (tool, true),
+        "fastapi" => ("fastapi[standard]", false),
+        _ => (tool, false),
+    }
+}
+
+// The program each `&&` / `||` / `;` / `|` separated step of a command runs
+fn command_tools(command: &str) -> Vec<(String, String)> {
+    command
+        .split(['&', '|', ';'])
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .filter_map(|segment| {
+            let mut words: Vec<&str> = segment
+                .split_whitespace()
+                .skip_while(|w| w.contains('=') && !w.starts_with('-'))
+                .collect();
+            if words.first() == Some(&"cross-env") {
+                words = words
+                    .into_iter()
+                    .skip(1)
+                    .skip_while(|w| w.contains('='))
+                    .collect();
+            }
+            if let Some(runner) = RUNNERS.iter().find(|r| words.starts_with(r)) {
+                words.drain(..runner.len());
+                words.retain(|w| !w.starts_with('-'));
+            }
+            if words.len() >= 3
+                && ["python", "python3", "py"].contains(&words[0])
+                && words[1] == "-m"
+            {
+                words.drain(..2);
+            }
+
+            let program = words.first()?;
+            let name = program
+                .rsplit(['/', '\\'])
+                .next()
+                .unwrap_or(program)
+                .trim_end_matches(".exe")
+                .to_string();
+            if name.is_empty() || SYSTEM_COMMANDS.contains(&name.as_str()) {
+                return None;
+            }
+            Some((name, segment.to_string()))
+        })
+        .collect()
+}
+
+pub fn node_bin(path: &Path, tool: &str) -> Option<PathBuf> {
+    path.ancestors()
+        .map(|dir| dir.join("node_modules").join(".bin"))
+        .flat_map(|bin| [bin.join(tool), bin.join(format!("{}.cmd", tool))])
+        .find(|candidate| candidate.exists())
+}
+
+pub fn venv_bin(path: &Path, tool: &str) -> Option<PathBuf> {
+    python::venv_dir(path)
+        .map(|venv| python::venv_bin(&venv, tool))
+        .filter(|bin| bin.exists())
+}
+
+// The project's own install first, a global one as a fallback
+pub fn python_tool(path: &Path, tool: &str) -> Option<PathBuf> {
+    venv_bin(path, tool).or_else(|| toolchain::which_all(tool).into_iter().next())
+}
+
+// A dev tool the project should provide but doesn't
+pub fn not_installed(tool: &str, service: &str) -> Error {
+    Error::not_found(
+        "tool_not_installed",
+        format!("{} is not installed; add it to the {} first", tool, service),
+    )
+    .with("tool", tool)
+    .with("service", service)
+}
+
+fn resolve(path: &Path, tool: String, command: String, is_node: bool) -> ToolRequirement {
+    let in_project = if is_node {
+        node_bin(path, &tool)
+    } else {
+        venv_bin(path, &tool)
+    };
+    let on_path = toolchain::which_all(&tool).into_iter().next();
+
+    let (found_in, location) = match (in_project, on_path) {
+        (Some(p), _) => (Some("project"), Some(p)),
+        (None, Some(p)) => (Some("path"), Some(p)),
+        (None, None) => (None, None),
+    };
+    let (package, dev) = if is_node {
+        node_package(&tool)
+    } else {
+        python_package(&tool)
+    };
+
+    ToolRequirement {
+        package: package.to_string(),
+        dev,
+        ecosystem: if is_node { "npm" } else { "pypi" },
+        found_in,
+        location: location.map(|p| p.to_string_lossy().into_owned()),
+        tool,
+        command,
+    }
+}
+
+#[tauri::command]
+pub async fn check_service_tools(
+    service_path: String,
+    commands: Option<Vec<String>>,
+) -> Result<Vec<ToolRequirement>, Error> {
+    task::blocking(move || {
+        let service_path = paths::existing_dir(&service_path)?;
+        let path = Path::new(&service_path);
+        let is_node = path.join("package.json").exists();
+
+        // Without explicit commands, scan the package.json scripts or the default backend command
+        let commands = match commands {
+            Some(commands) => commands,
+            None if is_node => {
+                let content = fs::read_to_string(path.join("package.json"))?;
+                let json: serde_json::Value = serde_json::from_str(&content)?;
+                json["scripts"]
+                    .as_object()
+                    .map(|scripts| {
+                        scripts
+                            .values()
+                            .filter_map(|v| v.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+            None => vec![crate::backend_start_command(path, 8000)],
+        };
+
+        let mut found: Vec<ToolRequirement> = Vec::new();
+        for command in commands {
+            for (tool, segment) in command_tools(&command) {
+                if found.iter().any(|t| t.tool == tool) {
+                    continue;
+                }
+                found.push(resolve(path, tool, segment, is_node));
+            }
+        }
+
+        // Unresolvable tools first, then ones that only work through a global install
+        found.sort_by_key(|t| match t.found_in {
+            None => 0,
+            Some("path") => 1,
+            _ => 2,
+        });
+        Ok(found)
+    })
+    .await
+}
@@ -0,0 +1,190 @@
+use std::path::Path;
+use tauri::menu::{IsMenuItem, Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager, Wry};
+
+use crate::compose;
+use crate::devdeps;
+use crate::instance;
+use crate::mock;
+use crate::notify;
+use crate::requestbin;
+use crate::ProcessManager;
+
+const TRAY_ID: &str = "main";
+
+struct ServiceEntry {
+    key: String,
+    label: String,
+    running: bool,
+}
+
+// Services launched this session, with whether their process is still alive
+fn services(state: &ProcessManager) -> Vec<ServiceEntry> {
+    let launches = state.launches.lock().clone();
+    let mut processes = state.processes.lock();
+
+    let mut entries: Vec<ServiceEntry> = launches
+        .into_iter()
+        .map(|(key, launch)| {
+            let running = processes
+                .get_mut(&key)
+                .is_some_and(|child| matches!(child.try_wait(), Ok(None)));
+            let project = Path::new(&launch.project_path)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or(launch.project_path);
+            ServiceEntry {
+                key,
+                label: format!("{} · {}", project, launch.service_type),
+                running,
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| a.label.cmp(&b.label));
+    entries
+}
+
+fn build_menu(app: &AppHandle) -> tauri::Result<Menu<Wry>> {
+    let state = app.state::<ProcessManager>();
+    let entries = services(&state);
+
+    let mut submenus = Vec::new();
+    for entry in &entries {
+        let status = if entry.running { "running" } else { "stopped" };
+        let start = MenuItem::with_id(
+            app,
+            format!("start:{}", entry.key),
+            "Start",
+            !entry.running,
+            None::<&str>,
+        )?;
+        let stop = MenuItem::with_id(
+            app,
+            format!("stop:{}", entry.key),
+            "Stop",
+            entry.running,
+            None::<&str>,
+        )?;
+        let restart = MenuItem::with_id(
+            app,
+            format!("restart:{}", entry.key),
+            "Restart",
+            entry.running,
+            None::<&str>,
+        )?;
+        submenus.push(Submenu::with_items(
+            app,
+            format!("{} ({})", entry.label, status),
+            true,
+            &[&start, &stop, &restart],
+        )?);
+    }
+
+    let none = MenuItem::with_id(app, "none", "No services started", false, None::<&str>)?;
+    let separator = PredefinedMenuItem::separator(app)?;
+    let stop_all = MenuItem::with_id(
+        app,
+        "stop-all",
+        "Stop all",
+        entries.iter().any(|e| e.running),
+        None::<&str>,
+    )?;
+    let show = MenuItem::with_id(app, "show", "Show devLLM", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+
+    let mut items: Vec<&dyn IsMenuItem<Wry>> = Vec::new();
+    if submenus.is_empty() {
+        items.push(&none);
+    }
+    for submenu in &submenus {
+        items.push(submenu);
+    }
+    items.extend([&separator as &dyn IsMenuItem<Wry>, &stop_all, &show, &quit]);
+    Menu::with_items(app, &items)
+}
+
+pub fn stop_all(state: &ProcessManager) {
+    let keys: Vec<String> = state.processes.lock().keys().cloned().collect();
+    for key in keys {
+        let _ = crate::stop_process(state, &key);
+    }
+    compose::stop_all(state);
+    devdeps::stop_all(state);
+    mock::stop_all(state);
+    requestbin::stop_all();
+}
+
+// Start, stop or restart a previously launched service by its process key
+pub fn service_action(app: &AppHandle, action: &str, key: &str) {
+    let state = app.state::<ProcessManager>();
+    let launch = state.launches.lock().get(key).cloned();
+    if matches!(action, "stop" | "restart") {
+        let _ = crate::stop_process(&state, key);
+    }
+    if matches!(action, "start" | "restart") {
+        if let Some(launch) = launch {
+            // An exited process still holds its slot until it's cleared
+            let mut processes = state.processes.lock();
+            if processes
+                .get_mut(key)
+                .is_some_and(|child| !matches!(child.try_wait(), Ok(None)))
+            {
+                processes.remove(key);
+            }
+            drop(processes);
+            match crate::launch_service(app, &launch, false) {
+                Ok(_) if action == "restart" => notify::when_healthy(app, &launch),
+                Ok(_) => {}
+                Err(e) => tracing::warn!(service = %key, "{} failed: {}", action, e),
+            }
+        }
+    }
+}
+
+fn handle_menu_event(app: &AppHandle, event: MenuEvent) {
+    let state = app.state::<ProcessManager>();
+    let id = event.id().as_ref();
+    match id {
+        "show" => instance::focus_main(app),
+        "stop-all" => stop_all(&state),
+        "quit" => {
+            stop_all(&state);
+            app.exit(0);
+            return;
+        }
+        _ => {
+            let Some((action, key)) = id.split_once(':') else {
+                return;
+            };
+            service_action(app, action, key);
+        }
+    }
+    refresh(app);
+}
+
+pub fn init(app: &AppHandle) -> tauri::Result<()> {
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .tooltip("devLLM")
+        .menu(&build_menu(app)?)
+        .show_menu_on_left_click(true)
+        .on_menu_event(handle_menu_event);
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    Ok(())
+}
+
+// Rebuilds the menu so it reflects which services are running
+pub fn refresh(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    match build_menu(app) {
+        Ok(menu) => {
+            let _ = tray.set_menu(Some(menu));
+        }
+        Err(e) => tracing::warn!("Failed to rebuild tray menu: {}", e),
+    }
+}
@@ -0,0 +1,406 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::db;
+use crate::deps;
+use crate::error::Error;
+use crate::exec;
+use crate::manifest::{self, TypeCheckRun};
+use crate::paths;
+use crate::task;
+use crate::tools;
+
+// Runs kept in the manifest across all services
+const HISTORY_LIMIT: usize = 100;
+
+#[derive(serde::Serialize)]
+pub struct TypeDiagnostic {
+    file: String,
+    line: u64,
+    column: u64,
+    code: Option<String>,
+    // "error", "warning" or "note"
+    severity: String,
+    message: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct TypeCheckReport {
+    service: String,
+    checker: &'static str,
+    errors: usize,
+    warnings: usize,
+    diagnostics: Vec<TypeDiagnostic>,
+    // Earlier runs for this service, oldest first, ending with this one
+    history: Vec<TypeCheckRun>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// Continuation lines (indented in tsc, "note:" in mypy) extend the previous diagnostic
+fn append(diagnostics: &mut [TypeDiagnostic], line: &str) {
+    if let Some(last) = diagnostics.last_mut() {
+        last.message.push('\n');
+        last.message.push_str(line.trim());
+    }
+}
+
+// "src/App.tsx(12,5): error TS2322: Type 'string' is not assignable to type 'number'."
+fn parse_tsc(dir: &Path, stdout: &str) -> Vec<TypeDiagnostic> {
+    let mut found = Vec::new();
+    for line in stdout.lines() {
+        if line.starts_with(char::is_whitespace) {
+            append(&mut found, line);
+            continue;
+        }
+        let parsed = line.split_once("): ").and_then(|(location, rest)| {
+            let (file, position) = location.rsplit_once('(')?;
+            let (row, column) = position.split_once(',')?;
+            let (head, message) = rest.split_once(": ")?;
+            let (severity, code) = head.split_once(' ').unwrap_or((head, ""));
+            Some(TypeDiagnostic {
+                file: paths::relative(dir, file),
+                line: row.parse().ok()?,
+                column: column.parse().ok()?,
+                code: (!code.is_empty()).then(|| code.to_string()),
+                severity: severity.to_string(),
+                message: message.to_string(),
+            })
+        });
+        if let Some(diagnostic) = parsed {
+            found.push(diagnostic);
+        }
+    }
+    found
+}
+
+// "app/main.py:12:5: error: Incompatible types in assignment  [assignment]"
+fn parse_mypy(dir: &Path, stdout: &str) -> Vec<TypeDiagnostic> {
+    let mut found: Vec<TypeDiagnostic> = Vec::new();
+    for line in stdout.lines() {
+        let mut parts = line.splitn(4, ':');
+        let (Some(file), Some(row), Some(column), Some(rest)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let (Ok(row), Ok(column)) = (row.trim().parse::<u64>(), column.trim().parse::<u64>())
+        else {
+            continue;
+        };
+        let Some((severity, message)) = rest.trim().split_once(": ") else {
+            continue;
+        };
+        let file = paths::relative(dir, file);
+
+        if severity == "note"
+            && found
+                .last()
+                .is_some_and(|last| last.file == file && last.line == row)
+        {
+            append(&mut found, message);
+            continue;
+        }
+        let (message, code) = match message.rsplit_once("  [") {
+            Some((message, code)) if code.ends_with(']') => {
+                (message, Some(code.trim_end_matches(']').to_string()))
+            }
+            _ => (message, None),
+        };
+        found.push(TypeDiagnostic {
+            file,
+            line: row,
+            column,
+            code,
+            severity: severity.to_string(),
+            message: message.to_string(),
+        });
+    }
+    found
+}
+
+// pyright --outputjson; positions are zero-based
+fn parse_pyright(dir: &Path, json: &serde_json::Value) -> Vec<TypeDiagnostic> {
+    json["generalDiagnostics"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|d| TypeDiagnostic {
+            file: paths::relative(dir, d["file"].as_str().unwrap_or_default()),
+            line: d["range"]["start"]["line"].as_u64().unwrap_or(0) + 1,
+            column: d["range"]["start"]["character"].as_u64().unwrap_or(0) + 1,
+            code: d["rule"].as_str().map(String::from),
+            severity: match d["severity"].as_str() {
+                Some("information") => "note".to_string(),
+                Some(severity) => severity.to_string(),
+                None => "error".to_string(),
+            },
+            message: d["message"].as_str().unwrap_or_default().to_string(),
+        })
+        .collect()
+}
+
+fn wants_pyright(dir: &Path) -> bool {
+    dir.join("pyrightconfig.json").exists()
+        || fs::read_to_string(dir.join("pyproject.toml"))
+            .is_ok_and(|content| content.contains("[tool.pyright]"))
+}
+
+fn check_python(dir: &Path) -> Result<(&'static str, Vec<TypeDiagnostic>), Error> {
+    let mypy = tools::python_tool(dir, "mypy");
+    let pyright = tools::python_tool(dir, "pyright");
+    // mypy unless pyright is the only one installed or the project configures it
+    if let Some(pyright) = pyright.filter(|_| mypy.is_none() || wants_pyright(dir)) {
+        let mut cmd = Command::new(pyright);
+        cmd.arg("--outputjson").current_dir(dir);
+        let output = exec::output(&mut cmd).map_err(|e| exec::run_failed("pyright", e))?;
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|_| {
+            Error::tool_failed(
+                "pyright",
+                format!(
+                    "pyright failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            )
+        })?;
+        return Ok(("pyright", parse_pyright(dir, &json)));
+    }
+
+    let mypy = mypy.ok_or_else(|| tools::not_installed("mypy", "backend"))?;
+    let mut cmd = Command::new(mypy);
+    cmd.args([
+        ".",
+        "--show-column-numbers",
+        "--show-error-codes",
+        "--no-error-summary",
+        "--no-color-output",
+        "--no-pretty",
+    ])
+    .current_dir(dir);
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed("mypy", e))?;
+    // 1 means type errors were found; 2 is a crash or bad configuration
+    if output.status.code() == Some(2) {
+        return Err(Error::tool_failed(
+            "mypy",
+            format!(
+                "mypy failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ));
+    }
+    Ok((
+        "mypy",
+        parse_mypy(dir, &String::from_utf8_lossy(&output.stdout)),
+    ))
+}
+
+fn check_typescript(dir: &Path) -> Result<(&'static str, Vec<TypeDiagnostic>), Error> {
+    if !dir.join("tsconfig.json").exists() {
+        return Err(Error::not_found(
+            "no_tsconfig",
+            "The frontend has no tsconfig.json to type-check against",
+        ));
+    }
+    let bin = tools::node_bin(dir, "tsc").ok_or_else(|| tools::not_installed("tsc", "frontend"))?;
+    let mut cmd = exec::tool_command(&bin.to_string_lossy());
+    cmd.args(["--noEmit", "--pretty", "false"]).current_dir(dir);
+    let output = exec::output(&mut cmd).map_err(|e| exec::run_failed("tsc", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let diagnostics = parse_tsc(dir, &stdout);
+    if !output.status.success() && diagnostics.is_empty() {
+        return Err(Error::tool_failed(
+            "tsc",
+            format!("tsc failed: {}", stdout.trim()),
+        ));
+    }
+    Ok(("tsc", diagnostics))
+}
+
+#[tauri::command]
+pub async fn run_type_check(
+    project_path: String,
+    service: String,
+) -> Result<TypeCheckReport, Error> {
+    task::blocking(move || {
+        let project_path = paths::existing_dir(&project_path)?;
+        let dir = deps::service_dir(Path::new(&project_path), &service)?;
+
+        let (checker, diagnostics) = if dir.join("package.json").exists() {
+            check_typescript(&dir)?
+        } else {
+            check_python(&dir)?
+        };
+        let count = |severity: &str| {
+            diagnostics
+                .iter()
+                .filter(|d| d.severity == severity)
+                .count()
+        };
+        let (errors, warnings) = (count("error"), count("warning"));
+
+        let run = TypeCheckRun {
+            service: service.clone(),
+            checker: checker.to_string(),
+            errors,
+            warnings,
+            at: db::format_timestamp(now()),
+        };
+        let manifest = manifest::update(&manifest::project_root(&dir), |m| {
+            m.type_checks.push(run);
+            let excess = m.type_checks.len().saturating_sub(HISTORY_LIMIT);
+            m.type_checks.drain(..excess);
+        })?;
+        let history = manifest
+            .type_checks
+            .into_iter()
+            .filter(|r| r.service == service)
+            .collect();
+
+        tracing::info!(%project_path, %service, checker, errors, warnings, "ran type check");
+        Ok(TypeCheckReport {
+            service,
+            checker,
+            errors,
+            warnings,
+            diagnostics,
+            history,
+        })
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn summary(diagnostics: &[TypeDiagnostic]) -> Vec<(&str, u64, u64, Option<&str>, &str)> {
+        diagnostics
+            .iter()
+            .map(|d| {
+                let code = d.code.as_deref();
+                (d.file.as_str(), d.line, d.column, code, d.severity.as_str())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_tsc_output() {
+        let stdout = "\
+src/App.tsx(12,5): error TS2322: Type 'string' is not assignable to type 'number'.
+src/api.ts(3,10): error TS2345: Argument of type '{ id: string; }' is not assignable to parameter of type 'Item'.
+  Property 'name' is missing in type '{ id: string; }' but required in type 'Item'.
+/app/frontend/src/main.tsx(1,1): error TS6133: 'React' is declared but its value is never read.
+
+Found 3 errors in 3 files.
+";
+        let diagnostics = parse_tsc(Path::new("/app/frontend"), stdout);
+        assert_eq!(
+            summary(&diagnostics),
+            [
+                ("src/App.tsx", 12, 5, Some("TS2322"), "error"),
+                ("src/api.ts", 3, 10, Some("TS2345"), "error"),
+                ("src/main.tsx", 1, 1, Some("TS6133"), "error"),
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Type 'string' is not assignable to type 'number'."
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "Argument of type '{ id: string; }' is not assignable to parameter of type 'Item'.\n\
+             Property 'name' is missing in type '{ id: string; }' but required in type 'Item'."
+        );
+    }
+
+    #[test]
+    fn parses_mypy_output() {
+        let stdout = "\
+app/main.py:12:5: error: Incompatible types in assignment (expression has type \"str\", variable has type \"int\")  [assignment]
+app/main.py:12:5: note: Consider using a type annotation
+app/crud.py:30:1: note: By default the bodies of untyped functions are not checked
+app/crud.py:41:12: error: Name \"db\" is not defined  [name-defined]
+app/crud.py:44:1: error: Unexpected keyword argument
+Found 3 errors in 2 files (checked 5 source files)
+";
+        let diagnostics = parse_mypy(Path::new("/app/backend"), stdout);
+        assert_eq!(
+            summary(&diagnostics),
+            [
+                ("app/main.py", 12, 5, Some("assignment"), "error"),
+                ("app/crud.py", 30, 1, None, "note"),
+                ("app/crud.py", 41, 12, Some("name-defined"), "error"),
+                ("app/crud.py", 44, 1, None, "error"),
+            ]
+        );
+        // A note on the same line belongs to the error before it
+        assert_eq!(
+            diagnostics[0].message,
+            "Incompatible types in assignment (expression has type \"str\", variable has type \"int\")\n\
+             Consider using a type annotation"
+        );
+        assert_eq!(diagnostics[2].message, "Name \"db\" is not defined");
+    }
+
+    #[test]
+    fn parses_pyright_json() {
+        let json = json!({
+            "version": "1.1.350",
+            "generalDiagnostics": [
+                {
+                    "file": "/app/backend/app/main.py",
+                    "severity": "error",
+                    "message": "\"db\" is not defined",
+                    "range": {
+                        "start": { "line": 40, "character": 11 },
+                        "end": { "line": 40, "character": 13 }
+                    },
+                    "rule": "reportUndefinedVariable"
+                },
+                {
+                    "file": "/app/backend/app/crud.py",
+                    "severity": "information",
+                    "message": "Import cycles are not reported",
+                    "range": { "start": { "line": 0, "character": 0 } }
+                },
+                {
+                    "file": "/app/backend/app/crud.py",
+                    "severity": "warning",
+                    "message": "Variable is unused",
+                    "range": { "start": { "line": 9, "character": 4 } },
+                    "rule": "reportUnusedVariable"
+                }
+            ],
+            "summary": { "errorCount": 1 }
+        });
+        let diagnostics = parse_pyright(Path::new("/app/backend"), &json);
+        assert_eq!(
+            summary(&diagnostics),
+            [
+                (
+                    "app/main.py",
+                    41,
+                    12,
+                    Some("reportUndefinedVariable"),
+                    "error"
+                ),
+                ("app/crud.py", 1, 1, None, "note"),
+                (
+                    "app/crud.py",
+                    10,
+                    5,
+                    Some("reportUnusedVariable"),
+                    "warning"
+                ),
+            ]
+        );
+    }
+}
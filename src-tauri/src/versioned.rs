@@ -0,0 +1,226 @@
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+const VERSION_KEY: &str = "schema_version";
+
+// migrations[n] upgrades a version n document to version n + 1
+pub type Migration = fn(&mut Value) -> Result<(), String>;
+
+// Version 1 only adds schema_version to what unversioned builds wrote
+pub fn initial(_: &mut Value) -> Result<(), String> {
+    Ok(())
+}
+
+pub fn current_version(migrations: &[Migration]) -> u64 {
+    migrations.len() as u64
+}
+
+// Files written before versioning have no schema_version and count as version 0
+fn upgrade(value: &mut Value, migrations: &[Migration], what: &str) -> Result<u64, String> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| format!("Invalid {}: expected an object", what))?;
+    let version = object.get(VERSION_KEY).and_then(Value::as_u64).unwrap_or(0);
+    let current = current_version(migrations);
+    if version > current {
+        return Err(format!(
+            "{} was written by a newer devLLM (version {}, this build understands {})",
+            what, version, current
+        ));
+    }
+    for (step, migrate) in migrations.iter().enumerate().skip(version as usize) {
+        migrate(value)
+            .map_err(|e| format!("Failed to migrate {} from version {}: {}", what, step, e))?;
+        value[VERSION_KEY] = Value::from(step as u64 + 1);
+    }
+    Ok(version)
+}
+
+// Reads a JSON document, upgrading it in place; the pre-upgrade file is kept as
+// <file>.v<old>.bak so a bad migration never loses the original
+pub fn load<T: DeserializeOwned>(
+    path: &Path,
+    migrations: &[Migration],
+    what: &str,
+) -> Result<Option<T>, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {}: {}", what, e)),
+    };
+    let mut value: Value =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid {}: {}", what, e))?;
+    let version = upgrade(&mut value, migrations, what)?;
+
+    if version < current_version(migrations) {
+        let backup = path.with_extension(format!("json.v{}.bak", version));
+        fs::write(&backup, &content).map_err(|e| e.to_string())?;
+        write_atomic(
+            path,
+            &serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?,
+        )?;
+        tracing::info!(
+            path = %path.display(),
+            from = version,
+            to = current_version(migrations),
+            "migrated {}",
+            what
+        );
+    }
+
+    serde_json::from_value(value)
+        .map(Some)
+        .map_err(|e| format!("Invalid {}: {}", what, e))
+}
+
+// Serializes `data` stamped with the current schema version
+pub fn to_string<T: Serialize>(data: &T, migrations: &[Migration]) -> Result<String, String> {
+    let mut value = serde_json::to_value(data).map_err(|e| e.to_string())?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert(
+            VERSION_KEY.to_string(),
+            Value::from(current_version(migrations)),
+        );
+    }
+    serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
+}
+
+// The schema_version of a file on disk that this build is too old to understand
+pub fn written_by_newer(path: &Path, migrations: &[Migration]) -> Option<u64> {
+    let content = fs::read_to_string(path).ok()?;
+    let value: Value = serde_json::from_str(&content).ok()?;
+    value
+        .get(VERSION_KEY)
+        .and_then(Value::as_u64)
+        .filter(|version| *version > current_version(migrations))
+}
+
+// Writes `data` over `path`, unless a newer devLLM wrote the file there: saving would
+// drop whatever that version added, so the file is left for it instead
+pub fn save<T: Serialize>(
+    path: &Path,
+    data: &T,
+    migrations: &[Migration],
+    what: &str,
+) -> Result<(), String> {
+    if let Some(version) = written_by_newer(path, migrations) {
+        return Err(format!(
+            "Not saving {}: the file was written by a newer devLLM (version {}, this build understands {})",
+            what,
+            version,
+            current_version(migrations)
+        ));
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    write_atomic(path, &to_string(data, migrations)?)
+}
+
+// Write then rename so a crash never leaves a half-written file
+pub fn write_atomic(path: &Path, content: &str) -> Result<(), String> {
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, content).map_err(|e| e.to_string())?;
+    fs::rename(&tmp, path).map_err(|e| e.to_string())
+}
+
+// Moves aside a file that can't be loaded so falling back to defaults doesn't overwrite it
+pub fn preserve_unreadable(path: &Path) {
+    let aside = path.with_extension("json.unreadable");
+    match fs::rename(path, &aside) {
+        Ok(()) => tracing::warn!(path = %aside.display(), "kept unreadable file"),
+        Err(e) => {
+            tracing::warn!(path = %path.display(), "failed to set aside unreadable file: {}", e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rename_port(value: &mut Value) -> Result<(), String> {
+        let port = value
+            .as_object_mut()
+            .and_then(|object| object.remove("port"))
+            .ok_or("missing port")?;
+        value["api_port"] = port;
+        Ok(())
+    }
+
+    const MIGRATIONS: &[Migration] = &[initial, rename_port];
+
+    #[test]
+    fn upgrades_unversioned_documents() {
+        let mut value = json!({ "port": 8000 });
+        assert_eq!(upgrade(&mut value, MIGRATIONS, "test"), Ok(0));
+        assert_eq!(value, json!({ "api_port": 8000, "schema_version": 2 }));
+    }
+
+    #[test]
+    fn runs_only_the_missing_steps() {
+        let mut value = json!({ "port": 8000, "schema_version": 1 });
+        assert_eq!(upgrade(&mut value, MIGRATIONS, "test"), Ok(1));
+        assert_eq!(value, json!({ "api_port": 8000, "schema_version": 2 }));
+    }
+
+    #[test]
+    fn leaves_current_documents_alone() {
+        let mut value = json!({ "api_port": 8000, "schema_version": 2 });
+        assert_eq!(upgrade(&mut value, MIGRATIONS, "test"), Ok(2));
+        assert_eq!(value, json!({ "api_port": 8000, "schema_version": 2 }));
+    }
+
+    #[test]
+    fn refuses_newer_documents() {
+        let mut value = json!({ "api_port": 8000, "schema_version": 3 });
+        let err = upgrade(&mut value, MIGRATIONS, "test").unwrap_err();
+        assert!(err.contains("newer devLLM"), "{}", err);
+        assert_eq!(value, json!({ "api_port": 8000, "schema_version": 3 }));
+    }
+
+    #[test]
+    fn reports_the_failing_step() {
+        let mut value = json!({ "schema_version": 1 });
+        let err = upgrade(&mut value, MIGRATIONS, "test").unwrap_err();
+        assert!(err.contains("from version 1"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_non_objects() {
+        assert!(upgrade(&mut json!([1, 2]), MIGRATIONS, "test").is_err());
+    }
+
+    #[test]
+    fn save_leaves_newer_files_alone() {
+        let dir = std::env::temp_dir().join(format!("devllm-versioned-{}", std::process::id()));
+        let path = dir.join("test.json");
+        let newer = r#"{ "api_port": 8000, "extra": true, "schema_version": 3 }"#;
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, newer).unwrap();
+
+        assert_eq!(written_by_newer(&path, MIGRATIONS), Some(3));
+        let err = save(&path, &json!({ "api_port": 9000 }), MIGRATIONS, "test").unwrap_err();
+        assert!(err.contains("newer devLLM"), "{}", err);
+        assert_eq!(fs::read_to_string(&path).unwrap(), newer);
+
+        fs::write(&path, r#"{ "api_port": 8000, "schema_version": 2 }"#).unwrap();
+        assert_eq!(written_by_newer(&path, MIGRATIONS), None);
+        save(&path, &json!({ "api_port": 9000 }), MIGRATIONS, "test").unwrap();
+        let saved: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved, json!({ "api_port": 9000, "schema_version": 2 }));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stamps_the_current_version() {
+        let written = to_string(&json!({ "api_port": 8000 }), MIGRATIONS).unwrap();
+        let value: Value = serde_json::from_str(&written).unwrap();
+        assert_eq!(value["schema_version"], 2);
+    }
+}
@@ -0,0 +1,139 @@
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use parking_lot::Mutex;
+use std::path::Path;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+const PROJECT_DETECTED_EVENT: &str = "project-detected";
+const DEBOUNCE: Duration = Duration::from_millis(500);
+// Files detect_project reads, plus install directories whose appearance changes drift
+const WATCHED_FILES: [&str; 15] = [
+    "package.json",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "bun.lockb",
+    "vite.config.ts",
+    "vite.config.js",
+    ".env",
+    "requirements.txt",
+    "pyproject.toml",
+    "uv.lock",
+    "poetry.lock",
+    "main.py",
+    "node_modules",
+    ".venv",
+];
+
+struct Watched {
+    project_path: String,
+    // Dropping the debouncer stops the watch
+    _debouncer: Debouncer<RecommendedWatcher>,
+    // Last detection pushed or returned, so unchanged results aren't re-sent
+    last: Option<serde_json::Value>,
+}
+
+#[derive(Default)]
+pub struct DetectionWatch(Mutex<Option<Watched>>);
+
+#[derive(Clone, serde::Serialize)]
+struct ProjectDetected {
+    project_path: String,
+    project: serde_json::Value,
+}
+
+fn is_relevant(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| WATCHED_FILES.contains(&name))
+}
+
+// Returns true when the result differs from what the frontend last saw
+fn remember(app: &AppHandle, project_path: &str, project: &serde_json::Value) -> bool {
+    let state = app.state::<DetectionWatch>();
+    let mut watched = state.0.lock();
+    match watched.as_mut() {
+        Some(w) if w.project_path == project_path => {
+            let changed = w.last.as_ref() != Some(project);
+            w.last = Some(project.clone());
+            changed
+        }
+        _ => false,
+    }
+}
+
+fn refresh(app: &AppHandle, project_path: &str) {
+    let project = match crate::detect_project_blocking(project_path.to_string())
+        .map_err(|e| e.to_string())
+        .and_then(|p| serde_json::to_value(p).map_err(|e| e.to_string()))
+    {
+        Ok(project) => project,
+        Err(e) => {
+            tracing::debug!(path = %project_path, "detection refresh failed: {}", e);
+            return;
+        }
+    };
+    if remember(app, project_path, &project) {
+        tracing::debug!(path = %project_path, "project detection changed");
+        let _ = app.emit(
+            PROJECT_DETECTED_EVENT,
+            ProjectDetected {
+                project_path: project_path.to_string(),
+                project,
+            },
+        );
+    }
+}
+
+// Called with every detect_project result, so the watcher only pushes real changes
+pub fn record(app: &AppHandle, project_path: &str, project: &impl serde::Serialize) {
+    if let Ok(project) = serde_json::to_value(project) {
+        remember(app, project_path, &project);
+    }
+}
+
+// Watches the project root and its frontend/backend directories (not recursively, so
+// node_modules and .venv churn is ignored). None stops watching.
+pub fn watch(app: &AppHandle, project_path: Option<String>) -> Result<(), String> {
+    let state = app.state::<DetectionWatch>();
+    let mut watched = state.0.lock();
+    if watched.as_ref().map(|w| &w.project_path) == project_path.as_ref() {
+        return Ok(());
+    }
+    *watched = None;
+    let Some(project_path) = project_path else {
+        return Ok(());
+    };
+
+    let handle = app.clone();
+    let key = project_path.clone();
+    let mut debouncer = new_debouncer(DEBOUNCE, move |result: DebounceEventResult| match result {
+        Ok(events) if events.iter().any(|e| is_relevant(&e.path)) => refresh(&handle, &key),
+        Ok(_) => {}
+        Err(e) => tracing::debug!("project watch error: {}", e),
+    })
+    .map_err(|e| format!("Failed to watch {}: {}", project_path, e))?;
+
+    let base = Path::new(&project_path);
+    for dir in [
+        base.to_path_buf(),
+        base.join("frontend"),
+        base.join("backend"),
+    ] {
+        if dir.is_dir() {
+            debouncer
+                .watcher()
+                .watch(&dir, RecursiveMode::NonRecursive)
+                .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e))?;
+        }
+    }
+
+    tracing::debug!(path = %project_path, "watching project for detection changes");
+    *watched = Some(Watched {
+        project_path,
+        _debouncer: debouncer,
+        last: None,
+    });
+    Ok(())
+}
@@ -0,0 +1,17 @@
+import { test, expect } from "@playwright/test";
+
+test("backend reports healthy", async ({ page }) => {
+  await page.goto("/");
+  await expect(page.getByText("healthy", { exact: true })).toBeVisible();
+});
+
+test("adds an item to the list", async ({ page }) => {
+  const name = `Item ${Date.now()}`;
+
+  await page.goto("/");
+  await page.getByPlaceholder("Item name...").fill(name);
+  await page.getByRole("button", { name: "Add" }).click();
+
+  await expect(page.getByRole("listitem").filter({ hasText: name })).toBeVisible();
+  await expect(page.getByPlaceholder("Item name...")).toHaveValue("");
+});
@@ -0,0 +1,19 @@
+import { defineConfig, devices } from "@playwright/test";
+
+// devLLM starts both services before running these; E2E_BASE_URL points elsewhere
+export default defineConfig({
+  testDir: "./e2e",
+  fullyParallel: false,
+  retries: 0,
+  reporter: "list",
+  use: {
+    baseURL: process.env.E2E_BASE_URL || "http://127.0.0.1:{{frontend_port}}",
+    trace: "retain-on-failure",
+  },
+  projects: [
+    {
+      name: "chromium",
+      use: { ...devices["Desktop Chrome"] },
+    },
+  ],
+});
@@ -0,0 +1,38 @@
+from fastapi import FastAPI
+from fastapi.middleware.cors import CORSMiddleware
+from dotenv import load_dotenv
+
+from database import engine, Base
+from routes import events_router, items_router
+
+load_dotenv()
+
+# Create tables
+Base.metadata.create_all(bind=engine)
+
+app = FastAPI(title="{{project_name|py}}")
+
+app.add_middleware(
+    CORSMiddleware,
+    allow_origins=["*"],
+    allow_credentials=True,
+    allow_methods=["*"],
+    allow_headers=["*"],
+)
+
+app.include_router(items_router)
+app.include_router(events_router)
+
+@app.get("/health")
+async def health():
+    return {"status": "healthy"}
+
+@app.get("/")
+async def root():
+    return {"message": "Welcome to {{project_name|py}}"}
+{{#grpc}}
+# ItemService answers gRPC-web on this port too; everything else still reaches FastAPI
+from grpc_service import mount_grpc_web  # noqa: E402
+
+app = mount_grpc_web(app)
+{{/grpc}}
\ No newline at end of file
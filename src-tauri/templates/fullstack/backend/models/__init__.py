@@ -0,0 +1 @@
+from .item import Item
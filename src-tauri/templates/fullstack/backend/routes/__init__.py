@@ -0,0 +1,2 @@
+from .items import router as items_router
+from .events import router as events_router
@@ -0,0 +1,27 @@
+import asyncio
+import json
+from datetime import datetime, timezone
+
+from fastapi import APIRouter, Request
+from fastapi.responses import StreamingResponse
+
+router = APIRouter(prefix="/events", tags=["events"])
+
+# Server-Sent Events: one `event`/`data` block per message, separated by a blank line.
+# Streaming LLM tokens to the frontend works the same way, one token per message.
+@router.get("")
+async def stream_events(request: Request, count: int = 10, interval: float = 1.0):
+    async def generate():
+        for n in range(1, count + 1):
+            if await request.is_disconnected():
+                return
+            payload = {"n": n, "at": datetime.now(timezone.utc).isoformat()}
+            yield f"id: {n}\nevent: tick\ndata: {json.dumps(payload)}\n\n"
+            await asyncio.sleep(interval)
+        yield "event: done\ndata: {}\n\n"
+
+    return StreamingResponse(
+        generate(),
+        media_type="text/event-stream",
+        headers={"Cache-Control": "no-cache", "X-Accel-Buffering": "no"},
+    )
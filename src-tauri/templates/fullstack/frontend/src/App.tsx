@@ -0,0 +1,78 @@
+import { useState } from 'react';
+import { useApi, useMutation } from './hooks/useApi';
+import type { Item, CreateItem, HealthResponse } from './types';
+
+function App() {
+  const { data: health } = useApi<HealthResponse>('/health');
+  const { data: items, loading, error, refetch } = useApi<Item[]>('/items');
+  const { mutate: createItem, loading: creating } = useMutation<Item, CreateItem>('/items', 'post');
+
+  const [newItem, setNewItem] = useState('');
+
+  const handleCreate = async () => {
+    if (!newItem.trim()) return;
+    const result = await createItem({ name: newItem });
+    if (!result.error) {
+      setNewItem('');
+      refetch();
+    }
+  };
+
+  return (
+    <div className="min-h-screen bg-slate-900 p-8">
+      <div className="max-w-2xl mx-auto">
+        <div className="flex justify-between items-center mb-8">
+          <h1 className="text-3xl font-bold text-white">{{project_name|jsx}}</h1>
+          <span className={`px-3 py-1 rounded-full text-sm ${
+            health?.status === 'healthy' ? 'bg-green-500/20 text-green-400' : 'bg-red-500/20 text-red-400'
+          }`}>
+            {health?.status || 'checking...'}
+          </span>
+        </div>
+
+        <div className="bg-slate-800 rounded-lg p-6 mb-6">
+          <h2 className="text-lg font-semibold text-white mb-4">Add Item</h2>
+          <div className="flex gap-3">
+            <input
+              type="text"
+              value={newItem}
+              onChange={(e) => setNewItem(e.target.value)}
+              placeholder="Item name..."
+              className="flex-1 px-4 py-2 bg-slate-700 border border-slate-600 rounded-lg text-white placeholder-slate-400 focus:outline-none focus:border-blue-500"
+              onKeyDown={(e) => e.key === 'Enter' && handleCreate()}
+            />
+            <button
+              onClick={handleCreate}
+              disabled={creating}
+              className="px-6 py-2 bg-blue-600 hover:bg-blue-700 disabled:opacity-50 text-white rounded-lg font-medium transition-colors"
+            >
+              {creating ? 'Adding...' : 'Add'}
+            </button>
+          </div>
+        </div>
+
+        <div className="bg-slate-800 rounded-lg p-6">
+          <h2 className="text-lg font-semibold text-white mb-4">Items</h2>
+          {loading ? (
+            <p className="text-slate-400">Loading...</p>
+          ) : error ? (
+            <p className="text-red-400">{error}</p>
+          ) : items?.length === 0 ? (
+            <p className="text-slate-400">No items yet. Add one above!</p>
+          ) : (
+            <ul className="space-y-2">
+              {items?.map((item) => (
+                <li key={item.id} className="flex justify-between items-center p-3 bg-slate-700/50 rounded-lg">
+                  <span className="text-white">{item.name}</span>
+                  <span className="text-slate-500 text-sm">{new Date(item.created_at).toLocaleDateString()}</span>
+                </li>
+              ))}
+            </ul>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default App;
@@ -0,0 +1,50 @@
+const API_URL = import.meta.env.VITE_API_URL || 'http://127.0.0.1:8000';
+
+export interface ApiResponse<T> {
+  data: T | null;
+  error: string | null;
+}
+
+async function request<T>(
+  endpoint: string,
+  options: RequestInit = {}
+): Promise<ApiResponse<T>> {
+  try {
+    const response = await fetch(`${API_URL}${endpoint}`, {
+      headers: {
+        'Content-Type': 'application/json',
+        ...options.headers,
+      },
+      ...options,
+    });
+
+    if (!response.ok) {
+      const error = await response.text();
+      return { data: null, error: error || `HTTP ${response.status}` };
+    }
+
+    const data = await response.json();
+    return { data, error: null };
+  } catch (err) {
+    return { data: null, error: err instanceof Error ? err.message : 'Unknown error' };
+  }
+}
+
+export const api = {
+  get: <T>(endpoint: string) => request<T>(endpoint),
+
+  post: <T>(endpoint: string, body: unknown) =>
+    request<T>(endpoint, {
+      method: 'POST',
+      body: JSON.stringify(body),
+    }),
+
+  put: <T>(endpoint: string, body: unknown) =>
+    request<T>(endpoint, {
+      method: 'PUT',
+      body: JSON.stringify(body),
+    }),
+
+  delete: <T>(endpoint: string) =>
+    request<T>(endpoint, { method: 'DELETE' }),
+};
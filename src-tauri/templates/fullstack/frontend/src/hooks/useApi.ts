@@ -0,0 +1,54 @@
+import { useState, useEffect, useCallback } from 'react';
+import { api } from '../api/client';
+
+interface UseApiState<T> {
+  data: T | null;
+  loading: boolean;
+  error: string | null;
+}
+
+export function useApi<T>(endpoint: string) {
+  const [state, setState] = useState<UseApiState<T>>({
+    data: null,
+    loading: true,
+    error: null,
+  });
+
+  const fetchData = useCallback(async () => {
+    setState(prev => ({ ...prev, loading: true, error: null }));
+    const { data, error } = await api.get<T>(endpoint);
+    setState({ data, loading: false, error });
+  }, [endpoint]);
+
+  useEffect(() => {
+    fetchData();
+  }, [fetchData]);
+
+  return { ...state, refetch: fetchData };
+}
+
+export function useMutation<T, B = unknown>(endpoint: string, method: 'post' | 'put' | 'delete' = 'post') {
+  const [state, setState] = useState<UseApiState<T>>({
+    data: null,
+    loading: false,
+    error: null,
+  });
+
+  const mutate = useCallback(async (body?: B) => {
+    setState(prev => ({ ...prev, loading: true, error: null }));
+
+    let result;
+    if (method === 'post') {
+      result = await api.post<T>(endpoint, body);
+    } else if (method === 'put') {
+      result = await api.put<T>(endpoint, body);
+    } else {
+      result = await api.delete<T>(endpoint);
+    }
+
+    setState({ data: result.data, loading: false, error: result.error });
+    return result;
+  }, [endpoint, method]);
+
+  return { ...state, mutate };
+}
@@ -0,0 +1,15 @@
+export interface Item {
+  id: number;
+  name: string;
+  description: string | null;
+  created_at: string;
+}
+
+export interface CreateItem {
+  name: string;
+  description?: string;
+}
+
+export interface HealthResponse {
+  status: string;
+}
@@ -0,0 +1,41 @@
+"""Generates the Python gRPC stubs from protos/.
+
+Runs automatically when the stubs are missing or older than a .proto file;
+run `python codegen.py` to regenerate by hand.
+"""
+from pathlib import Path
+
+ROOT = Path(__file__).parent
+PROTOS = ROOT / "protos"
+
+
+def _outputs(proto: Path):
+    return [ROOT / f"{proto.stem}_pb2.py", ROOT / f"{proto.stem}_pb2_grpc.py"]
+
+
+def stale():
+    for proto in PROTOS.glob("*.proto"):
+        mtime = proto.stat().st_mtime
+        if any(not out.exists() or out.stat().st_mtime < mtime for out in _outputs(proto)):
+            return True
+    return False
+
+
+def generate():
+    from grpc_tools import protoc
+
+    for proto in PROTOS.glob("*.proto"):
+        code = protoc.main([
+            "grpc_tools.protoc",
+            f"--proto_path={PROTOS}",
+            f"--python_out={ROOT}",
+            f"--pyi_out={ROOT}",
+            f"--grpc_python_out={ROOT}",
+            str(proto),
+        ])
+        if code != 0:
+            raise RuntimeError(f"protoc failed for {proto.name}")
+
+
+if __name__ == "__main__":
+    generate()
@@ -0,0 +1,29 @@
+"""Native gRPC server for non-browser clients (grpcurl, other services).
+
+The browser talks gRPC-web to the main app instead, see grpc_service.mount_grpc_web.
+"""
+import asyncio
+import os
+
+import grpc
+from dotenv import load_dotenv
+
+from database import Base, engine
+from grpc_service import ItemService, items_pb2_grpc
+
+load_dotenv()
+Base.metadata.create_all(bind=engine)
+
+
+async def serve():
+    port = os.getenv("GRPC_PORT", "50051")
+    server = grpc.aio.server()
+    items_pb2_grpc.add_ItemServiceServicer_to_server(ItemService(), server)
+    server.add_insecure_port(f"127.0.0.1:{port}")
+    await server.start()
+    print(f"gRPC server listening on 127.0.0.1:{port}")
+    await server.wait_for_termination()
+
+
+if __name__ == "__main__":
+    asyncio.run(serve())
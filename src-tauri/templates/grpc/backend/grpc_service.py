@@ -0,0 +1,67 @@
+import grpc
+
+import codegen
+
+if codegen.stale():
+    codegen.generate()
+
+import items_pb2  # noqa: E402
+import items_pb2_grpc  # noqa: E402
+from database import SessionLocal  # noqa: E402
+from models import Item  # noqa: E402
+
+
+def _to_message(item):
+    return items_pb2.Item(
+        id=item.id,
+        name=item.name,
+        description=item.description,
+        created_at=item.created_at.isoformat() if item.created_at else "",
+    )
+
+
+def _not_found(context):
+    context.set_code(grpc.StatusCode.NOT_FOUND)
+    context.set_details("Item not found")
+    return items_pb2.Item()
+
+
+class ItemService(items_pb2_grpc.ItemServiceServicer):
+    async def ListItems(self, request, context):
+        with SessionLocal() as db:
+            items = db.query(Item).order_by(Item.created_at.desc()).all()
+            return items_pb2.ListItemsResponse(items=[_to_message(i) for i in items])
+
+    async def GetItem(self, request, context):
+        with SessionLocal() as db:
+            item = db.get(Item, request.id)
+            return _to_message(item) if item else _not_found(context)
+
+    async def CreateItem(self, request, context):
+        with SessionLocal() as db:
+            description = request.description if request.HasField("description") else None
+            item = Item(name=request.name, description=description)
+            db.add(item)
+            db.commit()
+            db.refresh(item)
+            return _to_message(item)
+
+    async def DeleteItem(self, request, context):
+        with SessionLocal() as db:
+            item = db.get(Item, request.id)
+            if not item:
+                context.set_code(grpc.StatusCode.NOT_FOUND)
+                context.set_details("Item not found")
+                return items_pb2.DeleteItemResponse()
+            db.delete(item)
+            db.commit()
+            return items_pb2.DeleteItemResponse()
+
+
+def mount_grpc_web(app):
+    """Serves ItemService as gRPC-web on the app's port; other requests reach `app`."""
+    from sonora.asgi import grpcASGI
+
+    grpc_app = grpcASGI(app)
+    items_pb2_grpc.add_ItemServiceServicer_to_server(ItemService(), grpc_app)
+    return grpc_app
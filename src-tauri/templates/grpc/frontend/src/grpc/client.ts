@@ -0,0 +1,21 @@
+import { GrpcWebFetchTransport } from '@protobuf-ts/grpcweb-transport';
+import { ItemServiceClient } from './generated/items.client';
+import type { Item } from './generated/items';
+
+// Generated by `npm run proto` from ../backend/protos; it also runs before dev and build
+const API_URL = import.meta.env.VITE_API_URL || 'http://127.0.0.1:{{backend_port}}';
+
+const transport = new GrpcWebFetchTransport({ baseUrl: API_URL });
+
+export const items = new ItemServiceClient(transport);
+
+// Example calls; each returns the response message once the call completes
+export async function listItems(): Promise<Item[]> {
+  const { response } = await items.listItems({});
+  return response.items;
+}
+
+export async function createItem(name: string, description?: string): Promise<Item> {
+  const { response } = await items.createItem({ name, description });
+  return response;
+}
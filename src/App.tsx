@@ -1,7 +1,7 @@
 import { useState, useEffect, useCallback } from 'react';
 import type { ProjectConfig, ProjectStatus } from './types/project';
 import { PROJECTS as DEFAULT_PROJECTS } from './types/project';
-import { getProjects, removeProject, getRemovedDefaultIds, restoreDefaultProject } from './store/projectStore';
+import { getProjects, removeProject, getRemovedDefaultIds, restoreDefaultProject, syncProjectRegistry } from './store/projectStore';
 import ProjectCard from './components/ProjectCard';
 import NewProjectModal from './components/NewProjectModal';
 import ImportProjectModal from './components/ImportProjectModal';
@@ -14,7 +14,9 @@ function App() {
   const [showImport, setShowImport] = useState(false);
 
   const loadProjects = useCallback(() => {
-    setProjects(getProjects());
+    const loaded = getProjects();
+    setProjects(loaded);
+    syncProjectRegistry(loaded);
     const removedIds = getRemovedDefaultIds();
     setRemovedDefaults(DEFAULT_PROJECTS.filter(p => removedIds.includes(p.id)));
   }, []);
@@ -0,0 +1,24 @@
+import type { AppError } from '../types/error';
+import { describeRemediation } from '../types/error';
+
+interface Props {
+  error: AppError;
+}
+
+export default function ErrorMessage({ error }: Props) {
+  return (
+    <div className="text-red-400 text-sm bg-red-900/20 p-3 rounded-lg" data-error-code={error.code}>
+      <div>{error.message}</div>
+      {error.remediations.length > 0 && (
+        <ul className="mt-2 list-disc list-inside text-red-300/80">
+          {error.remediations.map((remediation, i) => (
+            <li key={i}>
+              {describeRemediation(remediation)}
+              {remediation.fix && <span className="text-slate-400"> (fix: {remediation.fix})</span>}
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
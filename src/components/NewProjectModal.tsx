@@ -2,6 +2,9 @@ import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import type { ProjectConfig } from '../types/project';
 import { addProject, getNextAvailablePorts, getRandomColor } from '../store/projectStore';
+import type { AppError } from '../types/error';
+import { invalidInput, toAppError } from '../types/error';
+import ErrorMessage from './ErrorMessage';
 
 interface Props {
   onClose: () => void;
@@ -15,7 +18,7 @@ export default function NewProjectModal({ onClose, onCreated }: Props) {
   const [frontendPort, setFrontendPort] = useState('');
   const [backendPort, setBackendPort] = useState('');
   const [creating, setCreating] = useState(false);
-  const [error, setError] = useState<string | null>(null);
+  const [error, setError] = useState<AppError | null>(null);
 
   // Initialize ports when component mounts
   useEffect(() => {
@@ -26,7 +29,7 @@ export default function NewProjectModal({ onClose, onCreated }: Props) {
 
   const handleCreate = async () => {
     if (!name.trim()) {
-      setError('Project name is required');
+      setError(invalidInput('name_required', 'Project name is required'));
       return;
     }
 
@@ -52,6 +55,7 @@ export default function NewProjectModal({ onClose, onCreated }: Props) {
         name,
         description: description || `${name} project`,
         color: getRandomColor(),
+        root: projectPath,
         frontend: {
           port: fePort,
           path: `${projectPath}/frontend`,
@@ -68,7 +72,7 @@ export default function NewProjectModal({ onClose, onCreated }: Props) {
       addProject(project);
       onCreated();
     } catch (err) {
-      setError(String(err));
+      setError(toAppError(err));
     } finally {
       setCreating(false);
     }
@@ -137,11 +141,7 @@ export default function NewProjectModal({ onClose, onCreated }: Props) {
             </div>
           </div>
 
-          {error && (
-            <div className="text-red-400 text-sm bg-red-900/20 p-3 rounded-lg">
-              {error}
-            </div>
-          )}
+          {error && <ErrorMessage error={error} />}
         </div>
 
         <div className="flex gap-3 mt-6">
@@ -1,6 +1,10 @@
+import { useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { open } from '@tauri-apps/plugin-shell';
 import type { ProjectConfig, ProjectStatus } from '../types/project';
+import type { AppError } from '../types/error';
+import { toAppError } from '../types/error';
+import ErrorMessage from './ErrorMessage';
 
 interface Props {
   project: ProjectConfig;
@@ -12,12 +16,14 @@ export default function ProjectCard({ project, status, onRemove }: Props) {
   const frontendOk = status?.frontend.healthy ?? false;
   const backendOk = status?.backend.healthy ?? false;
   const allHealthy = frontendOk && backendOk;
+  const [error, setError] = useState<AppError | null>(null);
 
   const openInBrowser = async () => {
     await open(`http://localhost:${project.frontend.port}`);
   };
 
   const handleFrontendToggle = async () => {
+    setError(null);
     try {
       if (frontendOk) {
         await invoke('stop_service', {
@@ -34,10 +40,12 @@ export default function ProjectCard({ project, status, onRemove }: Props) {
       }
     } catch (err) {
       console.error('Frontend toggle error:', err);
+      setError(toAppError(err));
     }
   };
 
   const handleBackendToggle = async () => {
+    setError(null);
     try {
       if (backendOk) {
         await invoke('stop_service', {
@@ -54,6 +62,7 @@ export default function ProjectCard({ project, status, onRemove }: Props) {
       }
     } catch (err) {
       console.error('Backend toggle error:', err);
+      setError(toAppError(err));
     }
   };
 
@@ -115,6 +124,12 @@ export default function ProjectCard({ project, status, onRemove }: Props) {
         </button>
       </div>
 
+      {error && (
+        <div className="mb-4">
+          <ErrorMessage error={error} />
+        </div>
+      )}
+
       <button
         onClick={openInBrowser}
         disabled={!frontendOk}
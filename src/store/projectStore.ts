@@ -1,3 +1,4 @@
+import { invoke } from '@tauri-apps/api/core';
 import type { ProjectConfig } from '../types/project';
 import { PROJECTS as DEFAULT_PROJECTS } from '../types/project';
 
@@ -90,3 +91,54 @@ export function getNextAvailablePorts(): { frontend: number; backend: number } {
 export function getRandomColor(): string {
   return COLORS[Math.floor(Math.random() * COLORS.length)];
 }
+
+// The backend only starts services, resets databases and opens devllm:// links inside
+// projects it knows about, so it gets the current list whenever it changes
+export async function syncProjectRegistry(projects: ProjectConfig[]): Promise<void> {
+  try {
+    await invoke('sync_projects', {
+      projects: projects.map(p => ({ root: p.root ?? null, dirs: [p.frontend.path, p.backend.path] })),
+    });
+  } catch (err) {
+    console.warn('Failed to sync the project registry', err);
+  }
+}
+
+// The part of the store an app data export carries: projects the user added and the
+// defaults they removed
+export interface ProjectStore {
+  projects: ProjectConfig[];
+  removedDefaults: string[];
+}
+
+export function getProjectStore(): ProjectStore {
+  const stored = localStorage.getItem(STORAGE_KEY);
+  let projects: ProjectConfig[] = [];
+  try {
+    projects = stored ? (JSON.parse(stored) as ProjectConfig[]) : [];
+  } catch {
+    projects = [];
+  }
+  return { projects, removedDefaults: getRemovedDefaults() };
+}
+
+export function setProjectStore(store: ProjectStore): void {
+  localStorage.setItem(STORAGE_KEY, JSON.stringify(store.projects ?? []));
+  localStorage.setItem(REMOVED_DEFAULTS_KEY, JSON.stringify(store.removedDefaults ?? []));
+}
+
+export async function exportAppData(path: string): Promise<void> {
+  await invoke('export_app_data', { path, projects: getProjectStore() });
+}
+
+// Restores settings and the project list from an export; callers reload their projects after
+export async function importAppData(path: string): Promise<void> {
+  const report = await invoke<{ projects: ProjectStore | null }>('import_app_data', {
+    path,
+    projects: getProjectStore(),
+  });
+  if (report.projects) {
+    setProjectStore(report.projects);
+    await syncProjectRegistry(getProjects());
+  }
+}
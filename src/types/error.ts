@@ -0,0 +1,70 @@
+// What every backend command rejects with; see src-tauri/src/error.rs
+export type ErrorKind =
+  | 'not_found'
+  | 'invalid_input'
+  | 'already_exists'
+  | 'conflict'
+  | 'permission_denied'
+  | 'timeout'
+  | 'io'
+  | 'database'
+  | 'process'
+  | 'cancelled'
+  | 'internal';
+
+export type Remediation = { fix: string | null } & (
+  | { type: 'port_in_use'; port: number; pid: number | null; process: string | null }
+  | { type: 'missing_runtime'; tool: string; required_version: string | null }
+  | { type: 'missing_dependencies'; service_path: string; ecosystem: string }
+  | { type: 'missing_venv'; backend_path: string }
+  | { type: 'missing_env_vars'; service_path: string; keys: string[] }
+);
+
+export interface AppError {
+  kind: ErrorKind;
+  code: string;
+  message: string;
+  context: Record<string, string>;
+  remediations: Remediation[];
+}
+
+function isAppError(err: unknown): err is AppError {
+  return (
+    typeof err === 'object' &&
+    err !== null &&
+    typeof (err as AppError).message === 'string' &&
+    typeof (err as AppError).code === 'string'
+  );
+}
+
+// Plugin and JS errors arrive as strings or Error objects; those become internal errors
+export function toAppError(err: unknown): AppError {
+  if (isAppError(err)) {
+    return { ...err, context: err.context ?? {}, remediations: err.remediations ?? [] };
+  }
+  const message = err instanceof Error ? err.message : String(err);
+  return { kind: 'internal', code: 'internal', message, context: {}, remediations: [] };
+}
+
+export function invalidInput(code: string, message: string): AppError {
+  return { kind: 'invalid_input', code, message, context: {}, remediations: [] };
+}
+
+export function describeRemediation(remediation: Remediation): string {
+  switch (remediation.type) {
+    case 'port_in_use':
+      return remediation.process
+        ? `Port ${remediation.port} is in use by ${remediation.process}`
+        : `Port ${remediation.port} is in use`;
+    case 'missing_runtime':
+      return remediation.required_version
+        ? `${remediation.tool} ${remediation.required_version} is not installed`
+        : `${remediation.tool} is not installed`;
+    case 'missing_dependencies':
+      return `Dependencies are not installed in ${remediation.service_path}`;
+    case 'missing_venv':
+      return `No virtual environment in ${remediation.backend_path}`;
+    case 'missing_env_vars':
+      return `Missing environment variables: ${remediation.keys.join(', ')}`;
+  }
+}
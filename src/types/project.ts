@@ -3,6 +3,9 @@ export interface ProjectConfig {
   name: string;
   description: string;
   color: string;
+  // The directory devLLM created the project in; imported projects only have their
+  // service directories
+  root?: string;
   frontend: {
     port: number;
     path: string;